@@ -0,0 +1,279 @@
+//! Shared building blocks for widget configuration UIs
+//!
+//! Numeric properties are stored in `WidgetConfig::properties` as plain JSON
+//! numbers without a unit (e.g. `"height": 20`, not `"height": "20px"`). The
+//! unit is applied when the widget renders. Use [`number_property`] to edit
+//! such properties and [`get_number`] to read them so custom widgets stay
+//! consistent with the standard ones.
+
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use crate::core::rich_text::sanitize_rich_text;
+use crate::core::widget::WidgetConfig;
+use crate::editor::browser_window;
+
+/// Read a numeric property, accepting legacy values stored as numeric strings
+pub fn get_number(config: &WidgetConfig, key: &str) -> Option<f64> {
+    match config.get_property(key)? {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Convert a number to JSON, keeping whole numbers as integers
+fn number_to_json(value: f64) -> serde_json::Value {
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        serde_json::json!(value as i64)
+    } else {
+        serde_json::json!(value)
+    }
+}
+
+/// Options for a numeric property editor
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberProperty {
+    /// Property key in `WidgetConfig::properties`
+    pub key: &'static str,
+    /// Label shown above the input
+    pub label: &'static str,
+    /// Value used when the property is missing
+    pub default: f64,
+    /// Minimum allowed value
+    pub min: Option<f64>,
+    /// Maximum allowed value
+    pub max: Option<f64>,
+    /// Step between values
+    pub step: Option<f64>,
+    /// Unit suffix displayed next to the input (e.g. "px")
+    pub unit: Option<&'static str>,
+    /// Render a range slider instead of a number input
+    pub slider: bool,
+}
+
+impl NumberProperty {
+    /// Create a new numeric property editor
+    pub fn new(key: &'static str, label: &'static str, default: f64) -> Self {
+        Self {
+            key,
+            label,
+            default,
+            min: None,
+            max: None,
+            step: None,
+            unit: None,
+            slider: false,
+        }
+    }
+
+    /// Set the allowed range
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    /// Set the step between values
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Set the unit suffix
+    pub fn unit(mut self, unit: &'static str) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Render as a range slider
+    pub fn slider(mut self) -> Self {
+        self.slider = true;
+        self
+    }
+
+    /// Clamp a value to the configured range
+    fn clamp(&self, value: f64) -> f64 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+}
+
+/// Render a labeled numeric input that always writes back a JSON number
+pub fn number_property(
+    config: &WidgetConfig,
+    on_change: Callback<WidgetConfig>,
+    options: NumberProperty,
+) -> Html {
+    let value = get_number(config, options.key).unwrap_or(options.default);
+
+    let oninput = {
+        let config = config.clone();
+        let options = options.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<f64>() {
+                let mut new_config = config.clone();
+                new_config.set_property(options.key, number_to_json(options.clamp(value)));
+                on_change.emit(new_config);
+            }
+        })
+    };
+
+    let input_type = if options.slider { "range" } else { "number" };
+    let input_style = if options.slider {
+        "flex: 1;"
+    } else {
+        "flex: 1; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+    };
+
+    html! {
+        <div style="margin-bottom: 12px;">
+            <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                { options.label }
+            </label>
+            <div style="display: flex; align-items: center; gap: 8px;">
+                <input
+                    type={input_type}
+                    value={value.to_string()}
+                    {oninput}
+                    min={options.min.map(|v| v.to_string())}
+                    max={options.max.map(|v| v.to_string())}
+                    step={options.step.map(|v| v.to_string())}
+                    style={input_style}
+                />
+                if options.slider {
+                    <span style="min-width: 40px; text-align: right; font-size: 13px; color: #374151;">
+                        { value.to_string() }
+                        { options.unit.unwrap_or("") }
+                    </span>
+                } else if let Some(unit) = options.unit {
+                    <span style="font-size: 13px; color: #6b7280;">{ unit }</span>
+                }
+            </div>
+        </div>
+    }
+}
+
+/// Properties for the RichTextField component
+#[derive(Properties, PartialEq)]
+pub struct RichTextFieldProps {
+    /// Rich text HTML shown in the field
+    pub value: AttrValue,
+    /// Receives the sanitized HTML after each edit
+    pub on_change: Callback<String>,
+}
+
+/// Run a formatting command on the current selection of a contenteditable
+fn exec_format_command(command: &str, value: Option<&str>) {
+    let document = browser_window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.dyn_into::<web_sys::HtmlDocument>().ok());
+    if let Some(document) = document {
+        let _ = document.exec_command_with_show_ui_and_value(command, false, value.unwrap_or(""));
+    }
+}
+
+/// Contenteditable field with bold, italic, underline, and link buttons
+///
+/// Edits are sanitized with [`sanitize_rich_text`] before they're reported.
+/// The field's HTML is only replaced when `value` differs from what it already
+/// shows, so typing keeps the caret in place.
+#[function_component(RichTextField)]
+pub fn rich_text_field(props: &RichTextFieldProps) -> Html {
+    let field_ref = use_node_ref();
+
+    {
+        let field_ref = field_ref.clone();
+        use_effect_with(props.value.clone(), move |value| {
+            if let Some(field) = field_ref.cast::<web_sys::HtmlElement>() {
+                if sanitize_rich_text(&field.inner_html()) != value.as_str() {
+                    field.set_inner_html(value);
+                }
+            }
+        });
+    }
+
+    // Formatting commands fire `input`, so edits are reported from here only
+    let oninput = {
+        let field_ref = field_ref.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: InputEvent| {
+            if let Some(field) = field_ref.cast::<web_sys::HtmlElement>() {
+                on_change.emit(sanitize_rich_text(&field.inner_html()));
+            }
+        })
+    };
+
+    let format_button = |label: Html, title: &'static str, command: &'static str| {
+        html! {
+            <button
+                type="button"
+                {title}
+                aria-label={title}
+                // Keep the selection in the field when the button is pressed
+                onmousedown={Callback::from(|e: MouseEvent| e.prevent_default())}
+                onclick={Callback::from(move |_: MouseEvent| {
+                    if command == "createLink" {
+                        let url = browser_window()
+                            .and_then(|window| window.prompt_with_message("Link URL:").ok().flatten())
+                            .filter(|url| !url.trim().is_empty());
+                        if let Some(url) = url {
+                            exec_format_command(command, Some(url.trim()));
+                        }
+                    } else {
+                        exec_format_command(command, None);
+                    }
+                })}
+                style="
+                    min-width: 28px;
+                    padding: 2px 6px;
+                    background: #f3f4f6;
+                    border: 1px solid #d1d5db;
+                    border-radius: 4px;
+                    cursor: pointer;
+                "
+            >
+                { label }
+            </button>
+        }
+    };
+
+    html! {
+        <div style="border: 1px solid #ddd; border-radius: 4px;">
+            <div style="display: flex; gap: 4px; padding: 4px; border-bottom: 1px solid #ddd;">
+                { format_button(html! { <b>{ "B" }</b> }, "Bold", "bold") }
+                { format_button(html! { <i>{ "I" }</i> }, "Italic", "italic") }
+                { format_button(html! { <u>{ "U" }</u> }, "Underline", "underline") }
+                { format_button(html! { { "🔗" } }, "Link", "createLink") }
+            </div>
+            <div
+                ref={field_ref}
+                contenteditable="true"
+                role="textbox"
+                aria-multiline="true"
+                {oninput}
+                style="min-height: 60px; padding: 6px; outline: none;"
+            />
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_number_accepts_legacy_strings() {
+        let config = WidgetConfig::new("test")
+            .with_property("number", serde_json::json!(3))
+            .with_property("legacy", serde_json::json!("2.5"))
+            .with_property("invalid", serde_json::json!("wide"));
+
+        assert_eq!(get_number(&config, "number"), Some(3.0));
+        assert_eq!(get_number(&config, "legacy"), Some(2.5));
+        assert_eq!(get_number(&config, "invalid"), None);
+        assert_eq!(number_to_json(4.0), serde_json::json!(4));
+        assert_eq!(number_to_json(0.5), serde_json::json!(0.5));
+    }
+}
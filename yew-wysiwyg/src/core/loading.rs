@@ -0,0 +1,122 @@
+//! Wrapper for widgets whose content has to be loaded first
+//!
+//! [`Widget::render`](super::widget::Widget::render) is synchronous, so a widget
+//! that fetches data (e.g. a "latest posts" list) returns a [`LoadingWidget`]
+//! from `render` instead. It shows a spinner until the widget's future resolves,
+//! then the loaded content, and loads again whenever its `deps` key changes
+//! (typically when the widget's config is edited on the canvas).
+
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+/// Future resolving to a widget's loaded content, or an error message to show
+pub type LoadFuture = Pin<Box<dyn Future<Output = Result<Html, String>>>>;
+
+/// Properties for the LoadingWidget component
+#[derive(Properties)]
+pub struct LoadingWidgetProps {
+    /// Start loading the content; called on mount and whenever `deps` changes
+    pub load: Rc<dyn Fn() -> LoadFuture>,
+    /// Key for what is loaded, such as the serialized widget config; a new value loads again
+    #[prop_or_default]
+    pub deps: AttrValue,
+    /// Shown while loading instead of the default spinner
+    #[prop_or_default]
+    pub fallback: Option<Html>,
+    /// Accessible label of the default spinner
+    #[prop_or(AttrValue::Static("Loading…"))]
+    pub loading_label: AttrValue,
+}
+
+impl PartialEq for LoadingWidgetProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.load, &other.load)
+            && self.deps == other.deps
+            && self.fallback == other.fallback
+            && self.loading_label == other.loading_label
+    }
+}
+
+/// Progress of a [`LoadingWidget`]
+#[derive(Clone, PartialEq)]
+enum LoadState {
+    Loading,
+    Loaded(Html),
+    Failed(String),
+}
+
+/// Show a spinner until `load`'s future resolves, then its content or error
+///
+/// A result arriving after `deps` changed again (or after unmounting) is
+/// dropped, so only the latest load is ever shown.
+#[function_component(LoadingWidget)]
+pub fn loading_widget(props: &LoadingWidgetProps) -> Html {
+    let state = use_state_eq(|| LoadState::Loading);
+
+    {
+        let state = state.clone();
+        let load = props.load.clone();
+        use_effect_with(props.deps.clone(), move |_| {
+            state.set(LoadState::Loading);
+            let stale = Rc::new(Cell::new(false));
+            {
+                let stale = stale.clone();
+                let future = load();
+                spawn_local(async move {
+                    let result = future.await;
+                    if !stale.get() {
+                        state.set(match result {
+                            Ok(content) => LoadState::Loaded(content),
+                            Err(message) => LoadState::Failed(message),
+                        });
+                    }
+                });
+            }
+            move || stale.set(true)
+        });
+    }
+
+    match &*state {
+        LoadState::Loaded(content) => content.clone(),
+        LoadState::Failed(message) => html! {
+            <div
+                class="wysiwyg-load-error"
+                role="alert"
+                style="padding: 12px; border: 1px solid #fecaca; background: #fef2f2; color: #b91c1c; border-radius: 4px; font-size: 13px;"
+            >
+                { message.clone() }
+            </div>
+        },
+        LoadState::Loading => match &props.fallback {
+            Some(fallback) => fallback.clone(),
+            None => html! {
+                <div
+                    class="wysiwyg-loading"
+                    role="status"
+                    aria-label={props.loading_label.clone()}
+                    style="display: flex; align-items: center; justify-content: center; min-height: 48px; padding: 12px;"
+                >
+                    // Animated with SMIL so the spinner works without any stylesheet
+                    <svg width="24" height="24" viewBox="0 0 24 24" aria-hidden="true">
+                        <circle cx="12" cy="12" r="9" fill="none" stroke="#e5e7eb" stroke-width="3" />
+                        <path d="M12 3 a9 9 0 0 1 9 9" fill="none" stroke="#3b82f6" stroke-width="3" stroke-linecap="round">
+                            <animateTransform
+                                attributeName="transform"
+                                type="rotate"
+                                from="0 12 12"
+                                to="360 12 12"
+                                dur="0.8s"
+                                repeatCount="indefinite"
+                            />
+                        </path>
+                    </svg>
+                </div>
+            },
+        },
+    }
+}
@@ -0,0 +1,112 @@
+//! Markdown rendering shared by text widgets
+//!
+//! Output is sanitized at the event level: raw HTML in the source is escaped
+//! and shown as text, and link/image URLs with schemes other than `http`,
+//! `https`, `mailto`, and `tel` are replaced with `#`.
+
+use pulldown_cmark::{html, CowStr, Event, Parser, Tag};
+
+pub use pulldown_cmark::Options;
+
+/// URL schemes allowed in links and images
+const ALLOWED_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// Default options: GitHub-flavored tables, strikethrough, and task lists
+pub fn default_options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS
+}
+
+/// Render Markdown to sanitized HTML using the default options
+pub fn render_markdown(input: &str) -> String {
+    render_markdown_with_options(input, default_options())
+}
+
+/// Render Markdown to sanitized HTML using custom parser options
+pub fn render_markdown_with_options(input: &str, options: Options) -> String {
+    let parser = Parser::new_ext(input, options).map(sanitize_event);
+    let mut html_output = String::new();
+    html::push_html(&mut html_output, parser);
+    html_output
+}
+
+/// Check whether a URL is safe to emit into an `href` or `src` attribute
+pub(crate) fn is_safe_url(url: &str) -> bool {
+    // Browsers ignore whitespace and control characters inside schemes
+    let normalized: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+
+    match normalized.find(':') {
+        // A colon after a path, query, or fragment delimiter is not a scheme separator
+        Some(colon) if !normalized[..colon].contains(['/', '?', '#']) => {
+            ALLOWED_SCHEMES.contains(&&normalized[..colon])
+        }
+        _ => true,
+    }
+}
+
+/// Neutralize a URL that is not safe to emit
+fn sanitize_url(url: CowStr<'_>) -> CowStr<'_> {
+    if is_safe_url(&url) {
+        url
+    } else {
+        CowStr::Borrowed("#")
+    }
+}
+
+/// Escape raw HTML and neutralize unsafe URLs
+fn sanitize_event(event: Event<'_>) -> Event<'_> {
+    match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: sanitize_url(dest_url),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: sanitize_url(dest_url),
+            title,
+            id,
+        }),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_markdown_sanitizes_output() {
+        let html = render_markdown("<script>alert(1)</script>\n\nHi <b onclick=\"x\">there</b>");
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<b "));
+        assert!(html.contains("&lt;script&gt;"));
+
+        let html = render_markdown(
+            "[a](javascript:alert(1)) [b]( JaVa\tScript:x) [c](https://example.com) [d](/page:1)",
+        );
+        assert!(!html.to_lowercase().contains("javascript"));
+        assert!(html.contains("href=\"https://example.com\""));
+        assert!(html.contains("href=\"/page:1\""));
+
+        let html = render_markdown("| a | b |\n|---|---|\n| 1 | 2 |\n\n~~old~~\n\n- [x] done");
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<del>old</del>"));
+        assert!(html.contains("type=\"checkbox\""));
+    }
+}
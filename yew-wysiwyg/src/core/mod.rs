@@ -1,5 +1,11 @@
 //! Core traits and types for the wysiwyg editor system
 
+pub mod config_ui;
+pub mod loading;
+pub mod markdown;
 pub mod registry;
+pub mod rich_text;
+pub mod style;
 pub mod theme;
 pub mod widget;
+pub mod yew_source;
@@ -57,6 +57,22 @@ impl WidgetRegistry {
         self.factories.keys().cloned().collect()
     }
 
+    /// Get registered widget types grouped by category
+    ///
+    /// Categories appear in the order they are first seen, and types keep their
+    /// registration order within each category.
+    pub fn widget_types_by_category(&self) -> IndexMap<String, Vec<String>> {
+        let mut groups: IndexMap<String, Vec<String>> = IndexMap::new();
+        for (widget_type, factory) in &self.factories {
+            let category = factory.create().category().to_string();
+            groups
+                .entry(category)
+                .or_default()
+                .push(widget_type.clone());
+        }
+        groups
+    }
+
     /// Check if a widget type is registered
     pub fn has_widget(&self, widget_type: &str) -> bool {
         self.factories.contains_key(widget_type)
@@ -102,6 +118,8 @@ impl WidgetRegistry {
         registry.register(basic::TextInput::factory()).ok();
         registry.register(basic::TextArea::factory()).ok();
         registry.register(basic::Checkbox::factory()).ok();
+        registry.register(basic::DatePicker::factory()).ok();
+        registry.register(basic::NumberInput::factory()).ok();
 
         // Register other widgets (in order)
         registry.register(basic::Divider::factory()).ok();
@@ -109,3 +127,65 @@ impl WidgetRegistry {
         registry
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::widget::{SimpleWidgetFactory, WidgetProps};
+    use yew::prelude::*;
+
+    macro_rules! test_widget {
+        ($name:ident, $widget_type:expr $(, $category:expr)?) => {
+            #[derive(Default)]
+            struct $name;
+
+            impl Widget for $name {
+                fn widget_type(&self) -> &'static str {
+                    $widget_type
+                }
+
+                fn render(&self, _props: &WidgetProps) -> Html {
+                    html! {}
+                }
+
+                $(
+                    fn category(&self) -> &'static str {
+                        $category
+                    }
+                )?
+            }
+        };
+    }
+
+    test_widget!(TextA, "text.a");
+    test_widget!(Row, "container.row");
+    test_widget!(TextB, "text.b");
+    test_widget!(Plain, "plain");
+    test_widget!(Custom, "text.custom", "form");
+
+    #[test]
+    fn test_widget_types_by_category() {
+        let mut registry = WidgetRegistry::new();
+        registry
+            .register(SimpleWidgetFactory::<TextA>::new())
+            .unwrap();
+        registry
+            .register(SimpleWidgetFactory::<Row>::new())
+            .unwrap();
+        registry
+            .register(SimpleWidgetFactory::<TextB>::new())
+            .unwrap();
+        registry
+            .register(SimpleWidgetFactory::<Plain>::new())
+            .unwrap();
+        registry
+            .register(SimpleWidgetFactory::<Custom>::new())
+            .unwrap();
+
+        let groups = registry.widget_types_by_category();
+        let categories: Vec<_> = groups.keys().map(String::as_str).collect();
+        assert_eq!(categories, ["text", "container", "other", "form"]);
+        assert_eq!(groups["text"], ["text.a", "text.b"]);
+        assert_eq!(groups["form"], ["text.custom"]);
+    }
+}
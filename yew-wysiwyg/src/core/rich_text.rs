@@ -0,0 +1,363 @@
+//! Sanitizer for the inline rich text stored by text widgets
+//!
+//! Rich text is HTML limited to bold, italic, underline, links, and line
+//! breaks. Everything else is reduced to its text: other tags are dropped,
+//! `<script>`/`<style>` elements and comments are removed with their contents,
+//! attributes other than a link's `href` are discarded, and `href`s are kept
+//! only when their scheme is allowed (see [`super::markdown`]). The output is
+//! always well-formed, with every opened tag closed.
+
+use super::markdown::is_safe_url;
+
+/// Elements removed together with everything inside them
+const DROPPED_ELEMENTS: &[&str] = &["script", "style", "template", "textarea", "title"];
+
+/// Sanitize rich text HTML, e.g. the `innerHTML` of a contenteditable field
+pub fn sanitize_rich_text(input: &str) -> String {
+    let mut output = String::new();
+    let mut open: Vec<&'static str> = Vec::new();
+    let mut rest = input;
+
+    while let Some(start) = rest.find('<') {
+        push_escaped(&mut output, &rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some((tag, len)) = parse_tag(rest) else {
+            output.push_str("&lt;");
+            rest = &rest[1..];
+            continue;
+        };
+        rest = &rest[len..];
+
+        if !tag.closing && DROPPED_ELEMENTS.contains(&tag.name.as_str()) {
+            rest = skip_element(rest, &tag.name);
+            continue;
+        }
+
+        match (tag.name.as_str(), tag.closing) {
+            ("br", false) => output.push_str("<br>"),
+            // Browsers wrap new lines of a contenteditable in blocks
+            ("div" | "p", false) if !output.is_empty() && !output.ends_with("<br>") => {
+                output.push_str("<br>")
+            }
+            (name, false) => {
+                if let Some(element) = inline_element(name) {
+                    output.push('<');
+                    output.push_str(element);
+                    if element == "a" {
+                        if let Some(href) = attribute(&tag.attributes, "href")
+                            .map(|href| decode_entities(&href))
+                            .filter(|href| href.as_deref().is_some_and(is_safe_url))
+                            .flatten()
+                        {
+                            output.push_str(" href=\"");
+                            push_escaped_all(&mut output, &href);
+                            output.push('"');
+                        }
+                    }
+                    output.push('>');
+                    open.push(element);
+                }
+            }
+            (name, true) => {
+                if let Some(position) = inline_element(name)
+                    .and_then(|element| open.iter().rposition(|open| *open == element))
+                {
+                    for element in open.drain(position..).rev() {
+                        output.push_str("</");
+                        output.push_str(element);
+                        output.push('>');
+                    }
+                }
+            }
+        }
+    }
+    push_escaped(&mut output, rest);
+
+    for element in open.into_iter().rev() {
+        output.push_str("</");
+        output.push_str(element);
+        output.push('>');
+    }
+    output
+}
+
+/// Turn plain text into rich text showing the same characters
+pub fn plain_text_to_rich(text: &str) -> String {
+    let mut output = String::new();
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            output.push_str("<br>");
+        }
+        push_escaped_all(&mut output, line);
+    }
+    output
+}
+
+/// Drop the formatting of rich text, keeping its characters and line breaks
+pub fn rich_text_to_plain(html: &str) -> String {
+    let sanitized = sanitize_rich_text(html).replace("<br>", "\n");
+    // Only the allowed tags remain, and a sanitized `<` always starts one
+    let mut text = String::new();
+    let mut rest = sanitized.as_str();
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]);
+    }
+    text.push_str(rest);
+    decode_entities(&text).unwrap_or(text)
+}
+
+/// The element an allowed inline tag is written as
+fn inline_element(name: &str) -> Option<&'static str> {
+    match name {
+        "b" | "strong" => Some("b"),
+        "i" | "em" => Some("i"),
+        "u" => Some("u"),
+        "a" => Some("a"),
+        _ => None,
+    }
+}
+
+/// A parsed start or end tag
+struct Tag {
+    name: String,
+    closing: bool,
+    attributes: String,
+}
+
+/// Parse the tag at the start of `input` (which begins with `<`), returning it and its length
+fn parse_tag(input: &str) -> Option<(Tag, usize)> {
+    let mut quote = None;
+    let end = input.char_indices().skip(1).find_map(|(i, c)| {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+        None
+    })?;
+
+    let inner = &input[1..end];
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+    let name_len = inner
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(inner.len());
+    if name_len == 0 {
+        return None;
+    }
+
+    Some((
+        Tag {
+            name: inner[..name_len].to_ascii_lowercase(),
+            closing,
+            attributes: inner[name_len..].to_string(),
+        },
+        end + 1,
+    ))
+}
+
+/// Skip past the end tag of a dropped element, or to the end of the input
+fn skip_element<'a>(input: &'a str, name: &str) -> &'a str {
+    let end_tag = format!("</{}", name);
+    match input.to_ascii_lowercase().find(&end_tag) {
+        Some(start) => input[start..]
+            .find('>')
+            .map_or("", |end| &input[start + end + 1..]),
+        None => "",
+    }
+}
+
+/// Read an attribute's raw (still entity-encoded) value from a tag's attribute text
+fn attribute(attributes: &str, wanted: &str) -> Option<String> {
+    let mut rest = attributes;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        if rest.is_empty() {
+            return None;
+        }
+        let name_len = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let name = rest[..name_len].to_ascii_lowercase();
+        rest = rest[name_len..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(after) => {
+                let after = after.trim_start();
+                let (value, remaining) = match after.chars().next() {
+                    Some(q @ ('"' | '\'')) => {
+                        let body = &after[1..];
+                        let end = body.find(q).unwrap_or(body.len());
+                        (&body[..end], body.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                        (&after[..end], &after[end..])
+                    }
+                };
+                rest = remaining;
+                value
+            }
+            None => "",
+        };
+        if name == wanted {
+            return Some(value.to_string());
+        }
+    }
+}
+
+/// Decode the character references of an attribute value
+///
+/// Returns `None` for named references other than the basic ones, so values
+/// that rely on them (e.g. `javascript&colon;`) are rejected rather than
+/// checked in a form the browser would read differently.
+fn decode_entities(value: &str) -> Option<String> {
+    let mut decoded = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        let Some(end) = rest.find(';').filter(|end| *end <= 10) else {
+            decoded.push('&');
+            continue;
+        };
+        let reference = &rest[..end];
+        let c = match reference {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => '\u{a0}',
+            _ => {
+                let code = if let Some(hex) = reference
+                    .strip_prefix("#x")
+                    .or_else(|| reference.strip_prefix("#X"))
+                {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(dec) = reference.strip_prefix('#') {
+                    dec.parse().ok()
+                } else {
+                    return None;
+                };
+                code.and_then(char::from_u32)?
+            }
+        };
+        decoded.push(c);
+        rest = &rest[end + 1..];
+    }
+    decoded.push_str(rest);
+    Some(decoded)
+}
+
+/// Check whether `&` starts a well-formed character reference
+fn is_entity(after_ampersand: &str) -> bool {
+    let Some(end) = after_ampersand.find(';').filter(|end| *end <= 10) else {
+        return false;
+    };
+    let reference = &after_ampersand[..end];
+    if let Some(hex) = reference
+        .strip_prefix("#x")
+        .or_else(|| reference.strip_prefix("#X"))
+    {
+        !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit())
+    } else if let Some(dec) = reference.strip_prefix('#') {
+        !dec.is_empty() && dec.chars().all(|c| c.is_ascii_digit())
+    } else {
+        !reference.is_empty() && reference.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+}
+
+/// Escape text, keeping the character references the browser already wrote
+fn push_escaped(output: &mut String, text: &str) {
+    for (i, c) in text.char_indices() {
+        match c {
+            '&' if is_entity(&text[i + 1..]) => output.push('&'),
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            c => output.push(c),
+        }
+    }
+}
+
+/// Escape decoded text for an attribute value
+fn push_escaped_all(output: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            c => output.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_rich_text() {
+        assert_eq!(
+            sanitize_rich_text("Say <b>hi</b> <em>now</em> <u>please</u>"),
+            "Say <b>hi</b> <i>now</i> <u>please</u>"
+        );
+        assert_eq!(
+            sanitize_rich_text("<b onclick=\"x()\" style=\"color: red\">bold</B>"),
+            "<b>bold</b>"
+        );
+        assert_eq!(
+            sanitize_rich_text("a<script>alert(1)</script>b<!-- note -->c<span>d</span>"),
+            "abcd"
+        );
+        assert_eq!(sanitize_rich_text("<b><i>open"), "<b><i>open</i></b>");
+        assert_eq!(
+            sanitize_rich_text("</b>1 < 2 &amp; 3 & 4"),
+            "1 &lt; 2 &amp; 3 &amp; 4"
+        );
+        assert_eq!(
+            sanitize_rich_text("one<div>two</div><div><br></div>"),
+            "one<br>two<br><br>"
+        );
+
+        assert_eq!(
+            sanitize_rich_text("<a href=\"https://example.com/?a=1&amp;b=2\" target=_blank>x</a>"),
+            "<a href=\"https://example.com/?a=1&amp;b=2\">x</a>"
+        );
+        for href in [
+            "javascript:alert(1)",
+            " JaVa\tScript:alert(1)",
+            "javascript&#58;alert(1)",
+            "javascript&colon;alert(1)",
+        ] {
+            assert_eq!(
+                sanitize_rich_text(&format!("<a href=\"{}\">x</a>", href)),
+                "<a>x</a>",
+                "{}",
+                href
+            );
+        }
+
+        let rich = plain_text_to_rich("1 < 2 & \"3\"\nnext");
+        assert_eq!(rich, "1 &lt; 2 &amp; &quot;3&quot;<br>next");
+        assert_eq!(rich_text_to_plain(&rich), "1 < 2 & \"3\"\nnext");
+        assert_eq!(
+            rich_text_to_plain("<b>bold</b>&nbsp;<a href=\"/x\">link</a>"),
+            "bold\u{a0}link"
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! Inline style rendering with an optional property allowlist
+//!
+//! Widgets build their `style` attribute with [`build_style`]. The allowlist
+//! (the `Editor`'s `style_allowlist` prop) is applied by the canvas before a
+//! widget renders, with [`StyleAllowlist::filter_config`], so widgets never see
+//! properties not on the list or values that could load resources or break out
+//! of the declaration. Serialization and source export never filter, except
+//! that breakpoint rules written into a stylesheet always skip declarations
+//! that fail [`is_safe_declaration`].
+
+use std::collections::BTreeSet;
+
+use super::widget::WidgetConfig;
+
+/// Properties allowed by [`StyleAllowlist::default`]: typography, spacing,
+/// colors, borders, sizing, and flex/grid layout
+const DEFAULT_ALLOWED_PROPERTIES: &[&str] = &[
+    // Typography
+    "color",
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-weight",
+    "letter-spacing",
+    "line-height",
+    "text-align",
+    "text-decoration",
+    "text-transform",
+    "white-space",
+    "word-break",
+    // Spacing
+    "margin",
+    "margin-top",
+    "margin-right",
+    "margin-bottom",
+    "margin-left",
+    "padding",
+    "padding-top",
+    "padding-right",
+    "padding-bottom",
+    "padding-left",
+    "gap",
+    "row-gap",
+    "column-gap",
+    // Colors and decoration
+    "background",
+    "background-color",
+    "opacity",
+    "box-shadow",
+    "border",
+    "border-top",
+    "border-right",
+    "border-bottom",
+    "border-left",
+    "border-color",
+    "border-style",
+    "border-width",
+    "border-radius",
+    // Sizing
+    "width",
+    "min-width",
+    "max-width",
+    "height",
+    "min-height",
+    "max-height",
+    "box-sizing",
+    "overflow",
+    // Layout
+    "display",
+    "flex",
+    "flex-direction",
+    "flex-wrap",
+    "flex-grow",
+    "flex-shrink",
+    "flex-basis",
+    "align-items",
+    "align-self",
+    "justify-content",
+    "grid-template-columns",
+    "grid-template-rows",
+    "grid-column",
+    "grid-row",
+    "vertical-align",
+    // Interaction
+    "cursor",
+    "resize",
+];
+
+/// Set of inline style properties widgets may render
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleAllowlist {
+    properties: BTreeSet<String>,
+}
+
+impl StyleAllowlist {
+    /// Create an allowlist of exactly these properties
+    pub fn new<P: Into<String>>(properties: impl IntoIterator<Item = P>) -> Self {
+        Self {
+            properties: properties
+                .into_iter()
+                .map(|property| property.into().trim().to_ascii_lowercase())
+                .collect(),
+        }
+    }
+
+    /// Also allow `property`
+    pub fn allow(mut self, property: impl Into<String>) -> Self {
+        self.properties
+            .insert(property.into().trim().to_ascii_lowercase());
+        self
+    }
+
+    /// Check whether a declaration may be rendered
+    pub fn allows(&self, property: &str, value: &str) -> bool {
+        self.properties
+            .contains(&property.trim().to_ascii_lowercase())
+            && is_safe_style_value(value)
+    }
+
+    /// Copy a config without the inline and breakpoint styles this allowlist rejects
+    pub fn filter_config(&self, config: &WidgetConfig) -> WidgetConfig {
+        let mut filtered = config.clone();
+        filtered
+            .inline_styles
+            .retain(|property, value| self.allows(property, value));
+        for styles in filtered.responsive_styles.values_mut() {
+            styles.retain(|property, value| self.allows(property, value));
+        }
+        filtered
+            .responsive_styles
+            .retain(|_, styles| !styles.is_empty());
+        filtered
+    }
+}
+
+/// Put the styles [`StyleAllowlist::filter_config`] hid from a widget back into
+/// a config it emitted, so editing a filtered widget doesn't delete them
+pub fn restore_filtered_styles(
+    original: &WidgetConfig,
+    filtered: &WidgetConfig,
+    edited: &mut WidgetConfig,
+) {
+    for (property, value) in &original.inline_styles {
+        if !filtered.inline_styles.contains_key(property)
+            && !edited.inline_styles.contains_key(property)
+        {
+            edited.inline_styles.insert(property.clone(), value.clone());
+        }
+    }
+    for (breakpoint, styles) in &original.responsive_styles {
+        let shown = filtered.responsive_styles.get(breakpoint);
+        for (property, value) in styles {
+            if shown.is_some_and(|shown| shown.contains_key(property))
+                || edited
+                    .responsive_styles
+                    .get(breakpoint)
+                    .is_some_and(|edited| edited.contains_key(property))
+            {
+                continue;
+            }
+            edited
+                .responsive_styles
+                .entry(*breakpoint)
+                .or_default()
+                .insert(property.clone(), value.clone());
+        }
+    }
+}
+
+impl Default for StyleAllowlist {
+    fn default() -> Self {
+        Self::new(DEFAULT_ALLOWED_PROPERTIES.iter().copied())
+    }
+}
+
+/// Check that a style value can't load resources, run script, or end the declaration
+pub fn is_safe_style_value(value: &str) -> bool {
+    let lower = value.to_ascii_lowercase();
+    ![
+        "url(",
+        "image(",
+        "image-set(",
+        "expression(",
+        "javascript:",
+        "@import",
+        "behavior:",
+        "-moz-binding",
+    ]
+    .iter()
+    .any(|pattern| lower.contains(pattern))
+        && !value
+            .chars()
+            .any(|c| matches!(c, ';' | '{' | '}' | '<' | '>' | '\\'))
+}
+
+/// Check that a property name is a plain CSS identifier, e.g. `padding-top` or `--accent`
+pub fn is_css_property_name(property: &str) -> bool {
+    property.chars().next().is_some_and(|c| !c.is_ascii_digit())
+        && property
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Check that a declaration can't escape its rule when written into a stylesheet
+pub fn is_safe_declaration(property: &str, value: &str) -> bool {
+    is_css_property_name(property) && is_safe_style_value(value)
+}
+
+/// Build a `style` attribute value from a config's inline styles
+pub fn build_style(config: &WidgetConfig) -> String {
+    config
+        .inline_styles
+        .iter()
+        .map(|(property, value)| format!("{}: {};", property, value))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::widget::Breakpoint;
+
+    #[test]
+    fn test_style_allowlist() {
+        let config = WidgetConfig::new("test")
+            .with_style("color", "red")
+            .with_style("position", "fixed")
+            .with_style("background", "url(javascript:alert(1))")
+            .with_style("padding", "8px; position: fixed");
+
+        assert_eq!(
+            build_style(&config),
+            "color: red; position: fixed; background: url(javascript:alert(1)); padding: 8px; position: fixed;"
+        );
+
+        let allowlist = StyleAllowlist::default();
+        assert_eq!(
+            build_style(&allowlist.filter_config(&config)),
+            "color: red;"
+        );
+        assert!(allowlist.allows("cursor", "pointer"));
+
+        let allowlist = allowlist.allow("position");
+        assert_eq!(
+            build_style(&allowlist.filter_config(&config)),
+            "color: red; position: fixed;"
+        );
+    }
+
+    #[test]
+    fn test_restore_filtered_styles() {
+        let original = WidgetConfig::new("test")
+            .with_style("color", "red")
+            .with_style("position", "fixed")
+            .with_responsive_style(Breakpoint::Md, "position", "sticky")
+            .with_responsive_style(Breakpoint::Md, "color", "blue");
+        let filtered = StyleAllowlist::default().filter_config(&original);
+        assert!(!filtered.inline_styles.contains_key("position"));
+        assert!(!filtered.responsive_styles[&Breakpoint::Md].contains_key("position"));
+
+        // The widget changed a property and removed the color it could see
+        let mut edited = filtered
+            .clone()
+            .with_property("text", serde_json::json!("Hi"));
+        edited.set_style(None, "color", None);
+        restore_filtered_styles(&original, &filtered, &mut edited);
+
+        assert_eq!(edited.inline_styles.get("color"), None);
+        assert_eq!(edited.inline_styles["position"], "fixed");
+        assert_eq!(
+            edited.responsive_styles[&Breakpoint::Md]["position"],
+            "sticky"
+        );
+        assert_eq!(edited.responsive_styles[&Breakpoint::Md]["color"], "blue");
+    }
+}
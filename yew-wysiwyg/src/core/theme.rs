@@ -84,6 +84,8 @@ impl DefaultTheme {
             config: ThemeConfig::new("default")
                 .with_variable("--wysiwyg-primary", "#3b82f6")
                 .with_variable("--wysiwyg-secondary", "#64748b")
+                .with_variable("--wysiwyg-success", "#10b981")
+                .with_variable("--wysiwyg-danger", "#ef4444")
                 .with_variable("--wysiwyg-background", "#ffffff")
                 .with_variable("--wysiwyg-text", "#1e293b")
                 .with_variable("--wysiwyg-border", "#e2e8f0")
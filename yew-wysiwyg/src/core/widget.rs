@@ -1,15 +1,182 @@
 //! Core widget trait and related types
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 use yew::prelude::*;
 
-use crate::error::Result;
+use super::style::is_safe_declaration;
+use crate::error::{Error, Result};
 
 /// Unique identifier for a widget instance
 pub type WidgetId = Uuid;
 
+/// Property key for the number of grid columns a widget spans inside a Grid container
+pub const GRID_COLUMN_SPAN: &str = "grid_column_span";
+
+/// Property key for the number of grid rows a widget spans inside a Grid container
+pub const GRID_ROW_SPAN: &str = "grid_row_span";
+
+/// Property key for a Grid container's named areas, one row of area names per line
+pub const GRID_TEMPLATE_AREAS: &str = "grid_template_areas";
+
+/// Property key for the named area a widget is placed in inside a Grid container
+pub const GRID_AREA: &str = "grid_area";
+
+/// Property key for the editor modes a widget is shown in, as a [`Visibility`]
+pub const VISIBILITY: &str = "visibility";
+
+/// Property key for an optional DOM `id` on the widget, used as an in-page anchor
+pub const ELEMENT_ID: &str = "element_id";
+
+/// Check whether a string is a valid HTML `id` (non-empty, no whitespace)
+pub fn is_valid_element_id(id: &str) -> bool {
+    !id.is_empty() && !id.chars().any(char::is_whitespace)
+}
+
+/// Check whether a string can name a grid area (letters, digits, `-` and `_`, not
+/// starting with a digit)
+pub fn is_valid_grid_area_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Parse a Grid container's template areas into rows of cell names
+///
+/// Each non-empty line is a row of whitespace-separated area names, with `.`
+/// marking an empty cell. Rows must have the same number of cells and every
+/// named area must be a rectangle, as CSS requires.
+pub fn parse_grid_template_areas(template: &str) -> Result<Vec<Vec<String>>> {
+    let rows: Vec<Vec<String>> = template
+        .lines()
+        .map(|line| line.split_whitespace().map(str::to_string).collect())
+        .filter(|row: &Vec<String>| !row.is_empty())
+        .collect();
+
+    let columns = rows.first().map_or(0, Vec::len);
+    if rows.iter().any(|row| row.len() != columns) {
+        return Err(Error::InvalidConfig(
+            "Every row of grid areas needs the same number of cells".to_string(),
+        ));
+    }
+
+    let mut bounds: BTreeMap<&str, (usize, usize, usize, usize, usize)> = BTreeMap::new();
+    for (r, row) in rows.iter().enumerate() {
+        for (c, name) in row.iter().enumerate() {
+            if name.chars().all(|ch| ch == '.') {
+                continue;
+            }
+            if !is_valid_grid_area_name(name) {
+                return Err(Error::InvalidConfig(format!(
+                    "Invalid grid area name: {}",
+                    name
+                )));
+            }
+            let entry = bounds.entry(name).or_insert((r, r, c, c, 0));
+            entry.0 = entry.0.min(r);
+            entry.1 = entry.1.max(r);
+            entry.2 = entry.2.min(c);
+            entry.3 = entry.3.max(c);
+            entry.4 += 1;
+        }
+    }
+    for (name, (top, bottom, left, right, cells)) in bounds {
+        if cells != (bottom - top + 1) * (right - left + 1) {
+            return Err(Error::InvalidConfig(format!(
+                "Grid area {} is not a rectangle",
+                name
+            )));
+        }
+    }
+
+    Ok(rows)
+}
+
+/// Format parsed template rows as a `grid-template-areas` value
+pub fn grid_template_areas_css(rows: &[Vec<String>]) -> String {
+    rows.iter()
+        .map(|row| format!("\"{}\"", row.join(" ")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Where a widget is shown, stored under the [`VISIBILITY`] property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Visibility {
+    /// Shown in edit mode, preview, and exports
+    #[default]
+    Always,
+    /// Shown in preview and exports; dimmed in edit mode so it stays selectable
+    PreviewOnly,
+    /// Shown only while editing, e.g. notes for other editors; left out of
+    /// preview and exports
+    EditOnly,
+}
+
+impl Visibility {
+    /// All visibilities, in the order the config panel lists them
+    pub const ALL: [Visibility; 3] = [
+        Visibility::Always,
+        Visibility::PreviewOnly,
+        Visibility::EditOnly,
+    ];
+
+    /// Check whether a widget with this visibility is part of the rendered page
+    /// in the given mode
+    pub fn is_visible(self, edit_mode: bool) -> bool {
+        match self {
+            Visibility::Always => true,
+            Visibility::PreviewOnly => !edit_mode,
+            Visibility::EditOnly => edit_mode,
+        }
+    }
+}
+
+/// Viewport breakpoint for responsive styles (min-width, mobile first)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Breakpoint {
+    /// Viewports at least 640px wide
+    Sm,
+    /// Viewports at least 768px wide
+    Md,
+    /// Viewports at least 1024px wide
+    Lg,
+}
+
+impl Breakpoint {
+    /// All breakpoints, from smallest to largest
+    pub const ALL: [Breakpoint; 3] = [Breakpoint::Sm, Breakpoint::Md, Breakpoint::Lg];
+
+    /// Minimum viewport width in pixels
+    pub fn min_width(&self) -> u32 {
+        match self {
+            Breakpoint::Sm => 640,
+            Breakpoint::Md => 768,
+            Breakpoint::Lg => 1024,
+        }
+    }
+
+    /// Short label for the breakpoint
+    pub fn label(&self) -> &'static str {
+        match self {
+            Breakpoint::Sm => "SM",
+            Breakpoint::Md => "MD",
+            Breakpoint::Lg => "LG",
+        }
+    }
+}
+
+/// Generated CSS class for a widget's extracted or responsive styles
+pub fn widget_class_name(id: &WidgetId) -> String {
+    format!("w-{}", &id.simple().to_string()[..8])
+}
+
 /// Properties passed to every widget
 #[derive(Clone, PartialEq)]
 pub struct WidgetProps {
@@ -33,11 +200,17 @@ pub struct WidgetConfig {
     /// Widget type identifier
     pub widget_type: String,
     /// Custom properties specific to this widget type
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub properties: HashMap<String, serde_json::Value>,
     /// Custom CSS classes
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub css_classes: Vec<String>,
-    /// Custom inline styles
-    pub inline_styles: HashMap<String, String>,
+    /// Custom inline styles, kept in insertion order so later declarations win predictably
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub inline_styles: IndexMap<String, String>,
+    /// Styles applied from a breakpoint upwards, layered over `inline_styles`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub responsive_styles: BTreeMap<Breakpoint, IndexMap<String, String>>,
 }
 
 impl WidgetConfig {
@@ -47,7 +220,8 @@ impl WidgetConfig {
             widget_type: widget_type.into(),
             properties: HashMap::new(),
             css_classes: Vec::new(),
-            inline_styles: HashMap::new(),
+            inline_styles: IndexMap::new(),
+            responsive_styles: BTreeMap::new(),
         }
     }
 
@@ -62,6 +236,35 @@ impl WidgetConfig {
         self.properties.get(key)
     }
 
+    /// Get a string property, or `default` if it is missing or not a string
+    pub fn get_str(&self, key: &str, default: &str) -> String {
+        self.get_property(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or(default)
+            .to_string()
+    }
+
+    /// Get a boolean property, or `default` if it is missing or not a boolean
+    pub fn get_bool(&self, key: &str, default: bool) -> bool {
+        self.get_property(key)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(default)
+    }
+
+    /// Get an integer property, or `default` if it is missing or not an integer
+    pub fn get_i64(&self, key: &str, default: i64) -> i64 {
+        self.get_property(key)
+            .and_then(|v| v.as_i64())
+            .unwrap_or(default)
+    }
+
+    /// Get a numeric property, or `default` if it is missing or not a number
+    pub fn get_f64(&self, key: &str, default: f64) -> f64 {
+        self.get_property(key)
+            .and_then(|v| v.as_f64())
+            .unwrap_or(default)
+    }
+
     /// Set a property value (mutable)
     pub fn set_property(&mut self, key: impl Into<String>, value: serde_json::Value) {
         self.properties.insert(key.into(), value);
@@ -78,6 +281,146 @@ impl WidgetConfig {
         self.inline_styles.insert(property.into(), value.into());
         self
     }
+
+    /// Add a style applied from the given breakpoint upwards
+    pub fn with_responsive_style(
+        mut self,
+        breakpoint: Breakpoint,
+        property: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.responsive_styles
+            .entry(breakpoint)
+            .or_default()
+            .insert(property.into(), value.into());
+        self
+    }
+
+    /// Get the styles for a breakpoint, or the base inline styles for `None`
+    pub fn styles(&self, breakpoint: Option<Breakpoint>) -> Option<&IndexMap<String, String>> {
+        match breakpoint {
+            Some(breakpoint) => self.responsive_styles.get(&breakpoint),
+            None => Some(&self.inline_styles),
+        }
+    }
+
+    /// Set a style for a breakpoint (or the base styles for `None`), removing it when `value` is `None`
+    pub fn set_style(
+        &mut self,
+        breakpoint: Option<Breakpoint>,
+        property: &str,
+        value: Option<String>,
+    ) {
+        let styles = match breakpoint {
+            Some(breakpoint) => self.responsive_styles.entry(breakpoint).or_default(),
+            None => &mut self.inline_styles,
+        };
+        match value {
+            Some(value) => {
+                styles.insert(property.to_string(), value);
+            }
+            None => {
+                styles.shift_remove(property);
+            }
+        }
+        self.responsive_styles
+            .retain(|_, styles| !styles.is_empty());
+    }
+
+    /// Get the DOM `id` set through the [`ELEMENT_ID`] property, if any
+    pub fn element_id(&self) -> Option<&str> {
+        self.get_property(ELEMENT_ID)
+            .and_then(|v| v.as_str())
+            .filter(|id| !id.is_empty())
+    }
+
+    /// Get the widget's [`Visibility`], defaulting to `Always`
+    pub fn visibility(&self) -> Visibility {
+        self.get_property(VISIBILITY)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Build the grid placement declarations (column/row spans and area) of a
+    /// widget inside a grid container; empty when it has none
+    pub fn grid_item_style(&self) -> String {
+        let mut declarations = Vec::new();
+        for (key, property) in [
+            (GRID_COLUMN_SPAN, "grid-column"),
+            (GRID_ROW_SPAN, "grid-row"),
+        ] {
+            if let Some(span) = self
+                .get_property(key)
+                .and_then(|v| v.as_u64())
+                .filter(|span| *span > 1)
+            {
+                declarations.push(format!("{}: span {};", property, span));
+            }
+        }
+        let area = self.get_str(GRID_AREA, "");
+        if is_valid_grid_area_name(&area) {
+            declarations.push(format!("grid-area: {};", area));
+        }
+        declarations.join(" ")
+    }
+
+    /// Build media-query rules for the responsive styles, targeting `.{class}`
+    ///
+    /// Declarations are marked `!important` so they take precedence over the
+    /// widget's inline styles. Since the rules go into a page-wide stylesheet,
+    /// declarations that could break out of them are always dropped.
+    pub fn responsive_css(&self, class: &str) -> String {
+        let mut css = String::new();
+        for (breakpoint, styles) in &self.responsive_styles {
+            if styles.is_empty() {
+                continue;
+            }
+            css.push_str(&format!(
+                "@media (min-width: {}px) {{\n  .{} {{\n",
+                breakpoint.min_width(),
+                class
+            ));
+            for (property, value) in styles {
+                if !is_safe_declaration(property, value) {
+                    continue;
+                }
+                css.push_str(&format!("    {}: {} !important;\n", property, value));
+            }
+            css.push_str("  }\n}\n");
+        }
+        css
+    }
+}
+
+impl From<&str> for WidgetConfig {
+    fn from(widget_type: &str) -> Self {
+        Self::new(widget_type)
+    }
+}
+
+impl From<String> for WidgetConfig {
+    fn from(widget_type: String) -> Self {
+        Self::new(widget_type)
+    }
+}
+
+/// A named place for a single child in a widget with fixed child slots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotSpec {
+    /// Name stored on the child node, unique within the widget
+    pub name: String,
+    /// Label shown on the slot in the editor
+    pub label: String,
+}
+
+impl SlotSpec {
+    /// Create a slot
+    pub fn new(name: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+        }
+    }
 }
 
 /// Trait that all widgets must implement
@@ -86,6 +429,10 @@ pub trait Widget: 'static {
     fn widget_type(&self) -> &'static str;
 
     /// Render the widget
+    ///
+    /// Widgets whose content depends on data loaded at runtime can return a
+    /// [`LoadingWidget`](super::loading::LoadingWidget), which shows a spinner
+    /// until the data arrives.
     fn render(&self, props: &WidgetProps) -> Html;
 
     /// Validate widget configuration
@@ -105,6 +452,15 @@ pub trait Widget: 'static {
         false
     }
 
+    /// Return the named child slots, if this widget takes one child per slot
+    ///
+    /// Widgets with slots must also return `true` from
+    /// [`can_have_children`](Widget::can_have_children). The canvas then
+    /// renders a labeled drop area per slot instead of a free child list.
+    fn child_slots(&self) -> Option<Vec<SlotSpec>> {
+        None
+    }
+
     /// Render the widget's configuration UI (for the editor)
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
         let _ = (config, on_change);
@@ -121,10 +477,42 @@ pub trait Widget: 'static {
         ""
     }
 
+    /// Get a link to documentation for this widget, shown in the config panel
+    fn help_url(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Get inline help for this widget, expanded from the config panel header
+    fn help_html(&self) -> Option<Html> {
+        None
+    }
+
+    /// Get the category used to group this widget in the editor
+    ///
+    /// Defaults to the widget type's prefix before the first `.` (e.g. `container`
+    /// for `container.row`), or `other` when the type has no prefix.
+    fn category(&self) -> &'static str {
+        let widget_type = self.widget_type();
+        match widget_type.split_once('.') {
+            Some((prefix, _)) if !prefix.is_empty() => prefix,
+            _ => "other",
+        }
+    }
+
     /// Get an icon or preview for this widget (HTML or CSS class name)
     fn icon(&self) -> Html {
         html! { <span>{ "📦" }</span> }
     }
+
+    /// Generate Yew `html!` markup reproducing this widget's output
+    ///
+    /// `children` holds the generated markup of the widget's children. Return
+    /// `None` if the widget has no source form; exports then emit a placeholder.
+    /// See [`crate::core::yew_source`] for helpers.
+    fn to_yew_source(&self, config: &WidgetConfig, children: &[String]) -> Option<String> {
+        let _ = (config, children);
+        None
+    }
 }
 
 /// Factory for creating widget instances
@@ -164,3 +552,84 @@ impl<W: Widget + Default> WidgetFactory for SimpleWidgetFactory<W> {
         W::default().widget_type()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_grid_template_areas() {
+        let rows = parse_grid_template_areas("header header\n\nsidebar main\n. footer").unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            grid_template_areas_css(&rows),
+            "\"header header\" \"sidebar main\" \". footer\""
+        );
+
+        // Ragged rows, split areas, and bad names are rejected
+        assert!(parse_grid_template_areas("a b\nc").is_err());
+        assert!(parse_grid_template_areas("a b a").is_err());
+        assert!(parse_grid_template_areas("a b\nb a").is_err());
+        assert!(parse_grid_template_areas("1col a").is_err());
+        assert!(parse_grid_template_areas("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_typed_property_accessors() {
+        let config = WidgetConfig::new("test")
+            .with_property("text", serde_json::json!("Hello"))
+            .with_property("enabled", serde_json::json!(true))
+            .with_property("count", serde_json::json!(3))
+            .with_property("ratio", serde_json::json!(0.5));
+
+        assert_eq!(config.get_str("text", "default"), "Hello");
+        assert_eq!(config.get_str("missing", "default"), "default");
+        assert!(config.get_bool("enabled", false));
+        assert_eq!(config.get_i64("count", 0), 3);
+        assert_eq!(config.get_f64("ratio", 1.0), 0.5);
+        assert_eq!(config.get_f64("count", 0.0), 3.0);
+        // Values of the wrong type fall back to the default
+        assert_eq!(config.get_i64("text", 7), 7);
+        assert!(!config.get_bool("count", false));
+    }
+
+    #[test]
+    fn test_responsive_styles() {
+        let mut config = WidgetConfig::new("test")
+            .with_style("padding", "8px")
+            .with_responsive_style(Breakpoint::Lg, "padding", "32px")
+            .with_responsive_style(Breakpoint::Sm, "padding", "16px");
+
+        assert_eq!(
+            config.responsive_css("w-1"),
+            "@media (min-width: 640px) {\n  .w-1 {\n    padding: 16px !important;\n  }\n}\n\
+             @media (min-width: 1024px) {\n  .w-1 {\n    padding: 32px !important;\n  }\n}\n"
+        );
+
+        // Declarations that would end the rule never reach the stylesheet
+        let hostile = WidgetConfig::new("test")
+            .with_responsive_style(
+                Breakpoint::Md,
+                "color",
+                "red } body { background: url(https://evil/x) } .x {",
+            )
+            .with_responsive_style(Breakpoint::Md, "color} body {color", "red")
+            .with_responsive_style(Breakpoint::Md, "margin", "0 auto");
+        assert_eq!(
+            hostile.responsive_css("w-1"),
+            "@media (min-width: 768px) {\n  .w-1 {\n    margin: 0 auto !important;\n  }\n}\n"
+        );
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["responsive_styles"]["lg"]["padding"], "32px");
+        let restored: WidgetConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, config);
+
+        // Clearing the last style of a breakpoint drops the breakpoint entirely
+        config.set_style(Some(Breakpoint::Sm), "padding", None);
+        assert!(config.styles(Some(Breakpoint::Sm)).is_none());
+        assert!(!serde_json::to_string(&WidgetConfig::new("test"))
+            .unwrap()
+            .contains("responsive_styles"));
+    }
+}
@@ -0,0 +1,144 @@
+//! Helpers for generating Yew `html!` source code from widget configs
+//!
+//! Used by [`Widget::to_yew_source`](crate::core::widget::Widget::to_yew_source)
+//! implementations and [`Layout::to_yew_source`](crate::serialization::Layout::to_yew_source).
+
+use super::widget::WidgetConfig;
+
+/// Indentation used for nested markup
+const INDENT: &str = "    ";
+
+/// Quote a string as a Rust string literal
+pub fn literal(value: &str) -> String {
+    format!("{:?}", value)
+}
+
+/// Text content as a `{ "..." }` block
+pub fn text(content: &str) -> String {
+    format!("{{ {} }}", literal(content))
+}
+
+/// Trusted HTML inserted without escaping, e.g. rendered Markdown
+pub fn raw_html(html: &str) -> String {
+    format!(
+        "{{ Html::from_html_unchecked(AttrValue::from({})) }}",
+        literal(html)
+    )
+}
+
+/// Indent every non-empty line of `source` by one level
+pub fn indent(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{}{}", INDENT, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `class` and `style` attributes for a config, with `base_style` placed before
+/// the inline styles; empty attributes are omitted
+pub fn config_attributes(config: &WidgetConfig, base_style: &str) -> Vec<(&'static str, String)> {
+    let mut attributes = Vec::new();
+    if !config.css_classes.is_empty() {
+        attributes.push(("class", literal(&config.css_classes.join(" "))));
+    }
+
+    let mut style = base_style.trim().to_string();
+    for (property, value) in &config.inline_styles {
+        if !style.is_empty() {
+            style.push(' ');
+        }
+        style.push_str(&format!("{}: {};", property, value));
+    }
+    if !style.is_empty() {
+        attributes.push(("style", literal(&style)));
+    }
+    attributes
+}
+
+/// Build an element; attribute values are Rust expressions (usually [`literal`]s)
+///
+/// Elements without children are self-closing.
+pub fn element(tag: &str, attributes: &[(&str, String)], children: &[String]) -> String {
+    let mut source = format!("<{}", tag);
+    for (name, value) in attributes {
+        source.push_str(&format!(" {}={{{}}}", name, value));
+    }
+
+    if children.is_empty() {
+        source.push_str(" />");
+    } else {
+        source.push_str(">\n");
+        for child in children {
+            source.push_str(&indent(child));
+            source.push('\n');
+        }
+        source.push_str(&format!("</{}>", tag));
+    }
+    source
+}
+
+/// Add an attribute to the root element of `source`, or `None` if `source` is not a
+/// single element (e.g. a fragment or a text block)
+pub fn with_root_attribute(source: &str, name: &str, value: String) -> Option<String> {
+    let rest = source.strip_prefix('<')?;
+    let tag_len = rest
+        .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+        .filter(|len| *len > 0)?;
+    let tag = &rest[..tag_len];
+    if !tag.starts_with(|c: char| c.is_ascii_alphabetic())
+        || !(source.ends_with("/>") || source.ends_with(&format!("</{}>", tag)))
+    {
+        return None;
+    }
+    Some(format!(
+        "<{} {}={{{}}}{}",
+        tag,
+        name,
+        value,
+        &rest[tag_len..]
+    ))
+}
+
+/// Wrap several nodes in a fragment
+pub fn fragment(children: &[String]) -> String {
+    let mut source = "<>\n".to_string();
+    for child in children {
+        source.push_str(&indent(child));
+        source.push('\n');
+    }
+    source.push_str("</>");
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_element_source() {
+        let config = WidgetConfig::new("test")
+            .with_class("hero")
+            .with_style("color", "red");
+        let attributes = config_attributes(&config, "display: flex;");
+        assert_eq!(
+            element("div", &attributes, &[text("Say \"hi\"")]),
+            "<div class={\"hero\"} style={\"display: flex; color: red;\"}>\n    { \"Say \\\"hi\\\"\" }\n</div>"
+        );
+        assert_eq!(element("hr", &[], &[]), "<hr />");
+        assert_eq!(
+            with_root_attribute("<hr />", "id", literal("intro")).as_deref(),
+            Some("<hr id={\"intro\"} />")
+        );
+        assert_eq!(
+            with_root_attribute(&fragment(&[text("a")]), "id", literal("intro")),
+            None
+        );
+    }
+}
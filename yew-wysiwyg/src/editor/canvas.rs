@@ -1,10 +1,19 @@
 //! Canvas component for rendering the widget layout
 
-use web_sys::HtmlElement;
+use std::collections::HashSet;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
 
+use super::dialog::use_escape_key;
+use super::is_text_entry_target;
+use super::strings::{use_editor_strings, EditorStrings};
+use super::styles::use_standalone_css;
+use super::touch_drag::{DragPayload, TouchDrag};
 use crate::core::registry::WidgetRegistry;
-use crate::core::widget::{WidgetConfig, WidgetId, WidgetProps};
+use crate::core::style::{build_style, restore_filtered_styles, StyleAllowlist};
+use crate::core::widget::{widget_class_name, WidgetConfig, WidgetId, WidgetProps};
 use crate::serialization::Layout;
 
 /// Properties for drop zone
@@ -13,6 +22,9 @@ struct DropZoneProps {
     parent_id: Option<WidgetId>,
     position: usize,
     on_drop: Callback<(String, Option<WidgetId>, usize)>,
+    on_move: Callback<(WidgetId, Option<WidgetId>, usize)>,
+    #[prop_or_default]
+    on_drop_file: Callback<(web_sys::File, Option<WidgetId>, usize)>,
     is_dragging: bool,
 }
 
@@ -20,12 +32,135 @@ struct DropZoneProps {
 #[derive(Properties, PartialEq)]
 struct EmptyContainerDropZoneProps {
     parent_id: WidgetId,
+    /// Slot the zone fills, read by touch drops (mouse drops go through the callbacks)
+    #[prop_or_default]
+    slot: Option<AttrValue>,
     on_drop: Callback<(String, Option<WidgetId>, usize)>,
+    on_move: Callback<(WidgetId, Option<WidgetId>, usize)>,
+    #[prop_or_default]
+    on_drop_file: Callback<(web_sys::File, Option<WidgetId>, usize)>,
+}
+
+/// Which edge of a container a beside-drop strip sits on
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ContainerEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Properties for the strips on a container's edges that drop beside it
+#[derive(Properties, PartialEq)]
+struct EdgeDropZoneProps {
+    edge: ContainerEdge,
+    /// The container's parent (`None` for a root container)
+    parent_id: Option<WidgetId>,
+    /// Position in the parent's children a drop lands at
+    position: usize,
+    on_drop: Callback<(String, Option<WidgetId>, usize)>,
+    on_move: Callback<(WidgetId, Option<WidgetId>, usize)>,
+    #[prop_or_default]
+    on_drop_file: Callback<(web_sys::File, Option<WidgetId>, usize)>,
+}
+
+/// Properties for the menu that adds a widget inside the selected container
+#[derive(Properties, PartialEq)]
+struct AddChildMenuProps {
+    registry: WidgetRegistry,
+    /// Receives the picked widget type and its default config
+    on_add: Callback<(String, WidgetConfig)>,
+}
+
+/// Prefix of the drag data format naming a dragged palette widget's type
+///
+/// Drag data can only be read on drop, but the list of formats is visible
+/// while hovering, so the palette adds a format with the type hex-encoded
+/// (formats are lowercased by the browser).
+const WIDGET_TYPE_FORMAT_PREFIX: &str = "application/x-wysiwyg-type-";
+
+/// Drag data format announcing `widget_type` while a palette item is dragged
+pub(crate) fn widget_type_drag_format(widget_type: &str) -> String {
+    let hex: String = widget_type
+        .bytes()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    format!("{}{}", WIDGET_TYPE_FORMAT_PREFIX, hex)
+}
+
+/// Read the widget type back from a format made by [`widget_type_drag_format`]
+fn widget_type_from_drag_format(format: &str) -> Option<String> {
+    let hex = format.strip_prefix(WIDGET_TYPE_FORMAT_PREFIX)?;
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<_>>>()?;
+    String::from_utf8(bytes).ok()
+}
+
+/// Faded render of the palette widget being dragged, shown in the hovered drop zone
+#[derive(Clone, PartialEq)]
+struct DragGhost(Option<Html>);
+
+/// Get the ghost of the dragged palette widget, if any
+#[hook]
+fn use_drag_ghost() -> Option<Html> {
+    use_context::<DragGhost>().and_then(|ghost| ghost.0)
+}
+
+/// Render a widget type with its default config as a non-interactive ghost
+fn render_ghost(
+    registry: &WidgetRegistry,
+    widget_type: &str,
+    style_allowlist: Option<Rc<StyleAllowlist>>,
+) -> Option<Html> {
+    let widget = registry.create_widget(widget_type).ok()?;
+    let config = widget.default_config();
+    let props = WidgetProps {
+        id: WidgetId::nil(),
+        edit_mode: false,
+        config: match &style_allowlist {
+            Some(allowlist) => allowlist.filter_config(&config),
+            None => config,
+        },
+        children: Vec::new(),
+        on_config_change: Callback::noop(),
+        on_delete: Callback::noop(),
+    };
+    Some(html! {
+        <div
+            class="wysiwyg-drag-ghost"
+            aria-hidden="true"
+            style="width: 100%; max-height: 160px; overflow: hidden; opacity: 0.45; pointer-events: none;"
+        >
+            { widget.render(&props) }
+        </div>
+    })
+}
+
+/// Read the id of a canvas widget being dragged by its handle
+fn dragged_widget_id(dt: &web_sys::DataTransfer) -> Option<WidgetId> {
+    dt.get_data("application/widget-id")
+        .ok()
+        .and_then(|id| WidgetId::parse_str(&id).ok())
+}
+
+/// Get the first image file dropped from outside the browser, if any
+fn dropped_image_file(dt: &web_sys::DataTransfer) -> Option<web_sys::File> {
+    let files = dt.files()?;
+    (0..files.length())
+        .filter_map(|index| files.get(index))
+        .find(|file| file.type_().starts_with("image/"))
 }
 
 /// Empty container drop zone - large, prominent drop zone for empty containers
 #[function_component(EmptyContainerDropZone)]
 fn empty_container_drop_zone(props: &EmptyContainerDropZoneProps) -> Html {
+    let strings = use_editor_strings();
+    let ghost = use_drag_ghost();
     let is_dragging_over = use_state(|| false);
 
     let ondragover = {
@@ -46,13 +181,19 @@ fn empty_container_drop_zone(props: &EmptyContainerDropZoneProps) -> Html {
     let ondrop = {
         let parent_id = props.parent_id;
         let on_drop = props.on_drop.clone();
+        let on_move = props.on_move.clone();
+        let on_drop_file = props.on_drop_file.clone();
         let is_dragging_over = is_dragging_over.clone();
         Callback::from(move |e: DragEvent| {
             e.prevent_default();
             is_dragging_over.set(false);
 
             if let Some(dt) = e.data_transfer() {
-                if let Ok(widget_type) = dt.get_data("application/widget-type") {
+                if let Some(file) = dropped_image_file(&dt) {
+                    on_drop_file.emit((file, Some(parent_id), 0));
+                } else if let Some(widget_id) = dragged_widget_id(&dt) {
+                    on_move.emit((widget_id, Some(parent_id), 0));
+                } else if let Ok(widget_type) = dt.get_data("application/widget-type") {
                     on_drop.emit((widget_type, Some(parent_id), 0));
                 }
             }
@@ -60,7 +201,7 @@ fn empty_container_drop_zone(props: &EmptyContainerDropZoneProps) -> Html {
     };
 
     let style = if *is_dragging_over {
-        "min-height: 80px; width: 100%; border: 2px dashed #3b82f6; background: #eff6ff; border-radius: 4px; margin: 8px 0; transition: all 0.2s; display: flex; align-items: center; justify-content: center; color: #3b82f6; font-size: 13px; font-weight: 500;"
+        "min-height: 80px; width: 100%; box-sizing: border-box; padding: 4px; border: 2px dashed #3b82f6; background: #eff6ff; border-radius: 4px; margin: 8px 0; transition: all 0.2s; display: flex; align-items: center; justify-content: center; color: #3b82f6; font-size: 13px; font-weight: 500;"
     } else {
         "min-height: 50px; width: 100%; border: 2px dashed #d1d5db; background: #fafafa; border-radius: 4px; margin: 8px 0; transition: all 0.2s; opacity: 1; display: flex; align-items: center; justify-content: center; color: #9ca3af; font-size: 13px;"
     };
@@ -68,12 +209,369 @@ fn empty_container_drop_zone(props: &EmptyContainerDropZoneProps) -> Html {
     html! {
         <div
             class="wysiwyg-empty-container-drop-zone"
+            data-drop-parent={props.parent_id.to_string()}
+            data-drop-position="0"
+            data-drop-slot={props.slot.clone()}
             {style}
             {ondragover}
             {ondragleave}
             {ondrop}
         >
-            { if *is_dragging_over { "Drop widget here" } else { "Drop widgets here" } }
+            if let Some(ghost) = ghost.filter(|_| *is_dragging_over) {
+                { ghost }
+            } else {
+                { if *is_dragging_over { strings.drop_here.clone() } else { strings.drop_widgets_here.clone() } }
+            }
+        </div>
+    }
+}
+
+/// Properties for the empty canvas placeholder
+#[derive(Properties, PartialEq)]
+struct EmptyCanvasDropZoneProps {
+    message: AttrValue,
+    on_drop: Callback<(String, Option<WidgetId>, usize)>,
+    #[prop_or_default]
+    on_drop_file: Callback<(web_sys::File, Option<WidgetId>, usize)>,
+    is_dragging: bool,
+}
+
+/// Placeholder filling an empty canvas that also accepts the first dropped widget
+#[function_component(EmptyCanvasDropZone)]
+fn empty_canvas_drop_zone(props: &EmptyCanvasDropZoneProps) -> Html {
+    let strings = use_editor_strings();
+    let ghost = use_drag_ghost();
+    let is_dragging_over = use_state(|| false);
+
+    let ondragover = {
+        let is_dragging_over = is_dragging_over.clone();
+        Callback::from(move |e: DragEvent| {
+            e.prevent_default();
+            is_dragging_over.set(true);
+        })
+    };
+
+    let ondragleave = {
+        let is_dragging_over = is_dragging_over.clone();
+        Callback::from(move |e: DragEvent| {
+            // Moving onto the icon or message inside the zone isn't leaving it
+            let zone = e
+                .current_target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+            let entered = e
+                .related_target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+            if let (Some(zone), Some(entered)) = (zone, entered) {
+                if zone.contains(Some(&entered)) {
+                    return;
+                }
+            }
+            is_dragging_over.set(false);
+        })
+    };
+
+    let ondrop = {
+        let on_drop = props.on_drop.clone();
+        let on_drop_file = props.on_drop_file.clone();
+        let is_dragging_over = is_dragging_over.clone();
+        Callback::from(move |e: DragEvent| {
+            e.prevent_default();
+            is_dragging_over.set(false);
+
+            if let Some(dt) = e.data_transfer() {
+                if let Some(file) = dropped_image_file(&dt) {
+                    on_drop_file.emit((file, None, 0));
+                } else if let Ok(widget_type) = dt.get_data("application/widget-type") {
+                    on_drop.emit((widget_type, None, 0));
+                }
+            }
+        })
+    };
+
+    // Highlighted for the whole drag so the first drop has an obvious target
+    let (border, background, color) = if *is_dragging_over {
+        ("#3b82f6", "#dbeafe", "#2563eb")
+    } else if props.is_dragging {
+        ("#3b82f6", "#eff6ff", "#3b82f6")
+    } else {
+        ("#d1d5db", "#fafafa", "#9ca3af")
+    };
+    let message = if props.is_dragging || *is_dragging_over {
+        strings.drop_here.clone()
+    } else {
+        props.message.clone()
+    };
+
+    html! {
+        <div
+            class="wysiwyg-empty-canvas"
+            data-drop-position="0"
+            style={format!("
+                min-height: 460px;
+                border: 2px dashed {};
+                background: {};
+                color: {};
+                border-radius: 8px;
+                display: flex;
+                flex-direction: column;
+                align-items: center;
+                justify-content: center;
+                gap: 12px;
+                transition: all 0.2s;
+            ", border, background, color)}
+            {ondragover}
+            {ondragleave}
+            {ondrop}
+        >
+            if let Some(ghost) = ghost.filter(|_| *is_dragging_over) {
+                <div style="width: 100%; max-width: 600px; padding: 0 24px; box-sizing: border-box; pointer-events: none;">
+                    { ghost }
+                </div>
+            } else {
+                <span aria-hidden="true" style="font-size: 48px; line-height: 1;">{ "🧩" }</span>
+            }
+            <span style="font-size: 16px; font-weight: 500;">{ message }</span>
+        </div>
+    }
+}
+
+/// Thin strip along a container's outer edge that drops next to the container
+/// instead of inside it, showing an insertion line while hovered
+#[function_component(EdgeDropZone)]
+fn edge_drop_zone(props: &EdgeDropZoneProps) -> Html {
+    let is_dragging_over = use_state(|| false);
+
+    let ondragover = {
+        let is_dragging_over = is_dragging_over.clone();
+        Callback::from(move |e: DragEvent| {
+            e.prevent_default();
+            is_dragging_over.set(true);
+        })
+    };
+
+    let ondragleave = {
+        let is_dragging_over = is_dragging_over.clone();
+        Callback::from(move |_: DragEvent| {
+            is_dragging_over.set(false);
+        })
+    };
+
+    let ondrop = {
+        let parent_id = props.parent_id;
+        let position = props.position;
+        let on_drop = props.on_drop.clone();
+        let on_move = props.on_move.clone();
+        let on_drop_file = props.on_drop_file.clone();
+        let is_dragging_over = is_dragging_over.clone();
+        Callback::from(move |e: DragEvent| {
+            e.prevent_default();
+            is_dragging_over.set(false);
+
+            if let Some(dt) = e.data_transfer() {
+                if let Some(file) = dropped_image_file(&dt) {
+                    on_drop_file.emit((file, parent_id, position));
+                } else if let Some(widget_id) = dragged_widget_id(&dt) {
+                    on_move.emit((widget_id, parent_id, position));
+                } else if let Ok(widget_type) = dt.get_data("application/widget-type") {
+                    on_drop.emit((widget_type, parent_id, position));
+                }
+            }
+        })
+    };
+
+    let placement = match props.edge {
+        ContainerEdge::Top => "top: -8px; left: 0; right: 0; height: 12px;",
+        ContainerEdge::Bottom => "bottom: -8px; left: 0; right: 0; height: 12px;",
+        ContainerEdge::Left => "left: -8px; top: 0; bottom: 0; width: 12px;",
+        ContainerEdge::Right => "right: -8px; top: 0; bottom: 0; width: 12px;",
+    };
+    let line = if !*is_dragging_over {
+        ""
+    } else if matches!(props.edge, ContainerEdge::Top | ContainerEdge::Bottom) {
+        "background: linear-gradient(#3b82f6, #3b82f6) center / 100% 3px no-repeat;"
+    } else {
+        "background: linear-gradient(#3b82f6, #3b82f6) center / 3px 100% no-repeat;"
+    };
+
+    html! {
+        <div
+            class="wysiwyg-edge-drop-zone"
+            data-drop-parent={props.parent_id.map(|id| id.to_string())}
+            data-drop-position={props.position.to_string()}
+            style={format!("position: absolute; z-index: 2; {} {}", placement, line)}
+            {ondragover}
+            {ondragleave}
+            {ondrop}
+        />
+    }
+}
+
+/// Properties for the handle that drags a selected widget
+#[derive(Properties, PartialEq)]
+struct DragHandleProps {
+    widget_id: WidgetId,
+}
+
+/// Handle in a selected widget's controls for dragging it with a mouse or finger
+///
+/// The handle is the only draggable element, so clicks and text selection
+/// inside the widget never start a drag.
+#[function_component(DragHandle)]
+fn drag_handle(props: &DragHandleProps) -> Html {
+    let strings = use_editor_strings();
+    let touch_drag = use_context::<TouchDrag>();
+
+    let widget_id = props.widget_id;
+    let ondragstart = Callback::from(move |e: DragEvent| {
+        if let Some(dt) = e.data_transfer() {
+            let _ = dt.set_data("application/widget-id", &widget_id.to_string());
+            dt.set_effect_allowed("move");
+        }
+    });
+    let touch =
+        touch_drag.map(|touch_drag| touch_drag.listeners(DragPayload::MoveWidget(widget_id)));
+
+    html! {
+        <span
+            class="wysiwyg-drag-handle"
+            draggable="true"
+            {ondragstart}
+            ontouchstart={touch.as_ref().map(|touch| touch.ontouchstart.clone())}
+            ontouchmove={touch.as_ref().map(|touch| touch.ontouchmove.clone())}
+            ontouchend={touch.as_ref().map(|touch| touch.ontouchend.clone())}
+            ontouchcancel={touch.as_ref().map(|touch| touch.ontouchcancel.clone())}
+            onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
+            style="
+                background: #f3f4f6;
+                padding: 4px 6px;
+                border-radius: 3px;
+                font-size: 12px;
+                user-select: none;
+                touch-action: none;
+            "
+            title={strings.drag_to_move.clone()}
+            aria-hidden="true"
+        >
+            { "⠿" }
+        </span>
+    }
+}
+
+/// "+" button in a selected container's controls with a list of widgets to add inside it
+#[function_component(AddChildMenu)]
+fn add_child_menu(props: &AddChildMenuProps) -> Html {
+    let strings = use_editor_strings();
+    let open = use_state_eq(|| false);
+
+    {
+        let open = open.clone();
+        use_escape_key(*open, Callback::from(move |_| open.set(false)));
+    }
+
+    let on_toggle = {
+        let open = open.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            open.set(!*open);
+        })
+    };
+
+    // Close once focus leaves the button and its list, e.g. on a click elsewhere
+    let onfocusout = {
+        let open = open.clone();
+        Callback::from(move |e: FocusEvent| {
+            let menu = e
+                .current_target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+            let focused = e
+                .related_target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+            if let (Some(menu), Some(focused)) = (menu, focused) {
+                if menu.contains(Some(&focused)) {
+                    return;
+                }
+            }
+            open.set(false);
+        })
+    };
+
+    html! {
+        <div style="position: relative;" {onfocusout}>
+            <button
+                onclick={on_toggle}
+                aria-haspopup="menu"
+                aria-expanded={if *open { "true" } else { "false" }}
+                aria-label={strings.add_child.clone()}
+                style="
+                    background: #10b981;
+                    color: white;
+                    border: none;
+                    padding: 4px 8px;
+                    border-radius: 3px;
+                    cursor: pointer;
+                    font-size: 12px;
+                "
+                title={strings.add_child.clone()}
+            >
+                { "+" }
+            </button>
+            if *open {
+                <div
+                    class="wysiwyg-add-child-menu"
+                    role="menu"
+                    aria-label={strings.add_child.clone()}
+                    style="
+                        position: absolute;
+                        top: calc(100% + 6px);
+                        right: 0;
+                        z-index: 20;
+                        min-width: 180px;
+                        max-height: 280px;
+                        overflow-y: auto;
+                        display: flex;
+                        flex-direction: column;
+                        padding: 4px;
+                        background: white;
+                        border: 1px solid #ddd;
+                        border-radius: 4px;
+                        box-shadow: 0 4px 6px -1px rgba(0, 0, 0, 0.15);
+                    "
+                >
+                    { for props.registry.widget_types().iter().filter_map(|widget_type| {
+                        let widget = props.registry.create_widget(widget_type).ok()?;
+                        let picked = (widget_type.clone(), widget.default_config());
+                        let on_add = props.on_add.clone();
+                        let open = open.clone();
+                        let onclick = Callback::from(move |e: MouseEvent| {
+                            e.stop_propagation();
+                            open.set(false);
+                            on_add.emit(picked.clone());
+                        });
+                        Some(html! {
+                            <button
+                                role="menuitem"
+                                {onclick}
+                                style="
+                                    display: flex;
+                                    align-items: center;
+                                    gap: 8px;
+                                    padding: 6px 8px;
+                                    background: none;
+                                    border: none;
+                                    border-radius: 3px;
+                                    cursor: pointer;
+                                    font-size: 13px;
+                                    text-align: left;
+                                    white-space: nowrap;
+                                "
+                            >
+                                <span aria-hidden="true">{ widget.icon() }</span>
+                                <span>{ widget.display_name() }</span>
+                            </button>
+                        })
+                    }) }
+                </div>
+            }
         </div>
     }
 }
@@ -81,6 +579,8 @@ fn empty_container_drop_zone(props: &EmptyContainerDropZoneProps) -> Html {
 /// Drop zone component - shows where widgets can be dropped
 #[function_component(DropZone)]
 fn drop_zone(props: &DropZoneProps) -> Html {
+    let strings = use_editor_strings();
+    let ghost = use_drag_ghost();
     let is_dragging_over = use_state(|| false);
 
     let ondragover = {
@@ -102,20 +602,29 @@ fn drop_zone(props: &DropZoneProps) -> Html {
         let parent_id = props.parent_id;
         let position = props.position;
         let on_drop = props.on_drop.clone();
+        let on_move = props.on_move.clone();
+        let on_drop_file = props.on_drop_file.clone();
         let is_dragging_over = is_dragging_over.clone();
         Callback::from(move |e: DragEvent| {
             e.prevent_default();
             is_dragging_over.set(false);
 
             if let Some(dt) = e.data_transfer() {
-                if let Ok(widget_type) = dt.get_data("application/widget-type") {
+                if let Some(file) = dropped_image_file(&dt) {
+                    on_drop_file.emit((file, parent_id, position));
+                } else if let Some(widget_id) = dragged_widget_id(&dt) {
+                    on_move.emit((widget_id, parent_id, position));
+                } else if let Ok(widget_type) = dt.get_data("application/widget-type") {
                     on_drop.emit((widget_type, parent_id, position));
                 }
             }
         })
     };
 
-    let style = if *is_dragging_over {
+    let style = if *is_dragging_over && ghost.is_some() {
+        // Hovering with a palette widget: grow to fit its ghost
+        "min-height: 50px; padding: 4px; border: 2px dashed #3b82f6; background: #eff6ff; border-radius: 4px; margin: 8px 0; transition: all 0.2s;"
+    } else if *is_dragging_over {
         // Hovering over this zone
         "height: 50px; border: 2px dashed #3b82f6; background: #eff6ff; border-radius: 4px; margin: 8px 0; display: flex; align-items: center; justify-content: center; color: #3b82f6; font-size: 13px; font-weight: 500; transition: all 0.2s;"
     } else if props.is_dragging {
@@ -129,13 +638,17 @@ fn drop_zone(props: &DropZoneProps) -> Html {
     html! {
         <div
             class="wysiwyg-drop-zone"
+            data-drop-parent={props.parent_id.map(|id| id.to_string())}
+            data-drop-position={props.position.to_string()}
             {style}
             {ondragover}
             {ondragleave}
             {ondrop}
         >
-            if *is_dragging_over {
-                { "Drop here" }
+            if let Some(ghost) = ghost.filter(|_| *is_dragging_over) {
+                { ghost }
+            } else if *is_dragging_over {
+                { strings.drop_here.clone() }
             }
         </div>
     }
@@ -148,53 +661,247 @@ pub struct CanvasProps {
     pub registry: WidgetRegistry,
     pub selected_widget: Option<WidgetId>,
     pub on_widget_select: Callback<Option<WidgetId>>,
+    /// Double-click on a widget: select it and bring its configuration into focus
+    #[prop_or_default]
+    pub on_widget_open: Callback<WidgetId>,
     pub on_widget_delete: Callback<WidgetId>,
     pub on_widget_move_up: Callback<WidgetId>,
     pub on_widget_move_down: Callback<WidgetId>,
+    pub on_widget_duplicate: Callback<WidgetId>,
     pub on_config_change: Callback<(WidgetId, WidgetConfig)>,
     pub on_drop_widget: Callback<(String, Option<WidgetId>, usize)>, // (widget_type, parent_id, position)
+    pub on_move_widget: Callback<(WidgetId, Option<WidgetId>, usize)>, // (widget_id, parent_id, position)
+    /// An image file dropped from outside the browser at (parent_id, position)
+    #[prop_or_default]
+    pub on_drop_file: Callback<(web_sys::File, Option<WidgetId>, usize)>,
+    #[prop_or_default]
+    pub on_drop_into_slot: Callback<(String, WidgetId, String)>, // (widget_type, parent_id, slot)
+    #[prop_or_default]
+    pub on_move_into_slot: Callback<(WidgetId, WidgetId, String)>, // (widget_id, parent_id, slot)
     pub edit_mode: bool,
+    /// Draw a faint dashed outline around every container in edit mode
+    #[prop_or_default]
+    pub outline_containers: bool,
+    /// Scroll the canvas while a drag hovers near its top or bottom edge
+    #[prop_or_default]
+    pub auto_scroll_on_drag: bool,
+    /// Deepest nesting allowed; containers at the limit don't accept drops
+    #[prop_or_default]
+    pub max_depth: Option<usize>,
+    /// Renderer for nodes whose widget type is not in the registry
+    #[prop_or_default]
+    pub unknown_widget_fallback: Option<Callback<(WidgetId, WidgetConfig), Html>>,
+    /// Transforms each widget's rendered output (see `EditorProps::render_middleware`)
+    #[prop_or_default]
+    pub render_middleware: Option<Callback<(WidgetId, Html), Html>>,
+    /// Inline style properties widgets may render (all allowed if `None`)
+    #[prop_or_default]
+    pub style_allowlist: Option<Rc<StyleAllowlist>>,
+    /// Message on the placeholder shown in edit mode while the layout is empty
+    #[prop_or(AttrValue::Static(DEFAULT_EMPTY_CANVAS_MESSAGE))]
+    pub empty_message: AttrValue,
+    /// Message shown in preview mode while the layout is empty (nothing if `None`)
+    #[prop_or_default]
+    pub empty_preview_message: Option<AttrValue>,
+    /// Add a widget inside the selected container; shows an "add child" button in
+    /// its controls when set
+    #[prop_or_default]
+    pub on_add_widget: Option<Callback<(String, WidgetConfig)>>,
 }
 
+/// Distance in pixels from the canvas's top or bottom edge where a drag scrolls it
+const AUTO_SCROLL_EDGE: f64 = 40.0;
+
+/// Pixels scrolled per `dragover` event with the pointer right at the edge
+const AUTO_SCROLL_MAX_STEP: f64 = 20.0;
+
+/// Scroll the canvas towards an edge the drag pointer is near, faster closer to it
+///
+/// `dragover` keeps firing while a drag hovers, so each event scrolls one step
+/// and scrolling stops as soon as the pointer leaves the edge zone or drops.
+fn auto_scroll_canvas(canvas: &Element, client_y: f64) {
+    let rect = canvas.get_bounding_client_rect();
+    let from_top = client_y - rect.top();
+    let from_bottom = rect.bottom() - client_y;
+    let direction = if from_top < AUTO_SCROLL_EDGE {
+        -(AUTO_SCROLL_EDGE - from_top.max(0.0))
+    } else if from_bottom < AUTO_SCROLL_EDGE {
+        AUTO_SCROLL_EDGE - from_bottom.max(0.0)
+    } else {
+        return;
+    };
+    let step = (direction / AUTO_SCROLL_EDGE * AUTO_SCROLL_MAX_STEP).round() as i32;
+    canvas.set_scroll_top(canvas.scroll_top() + step);
+}
+
+/// Default message on the empty canvas placeholder
+pub(crate) const DEFAULT_EMPTY_CANVAS_MESSAGE: &str = "Drag a widget here to begin";
+
 /// Canvas component - renders the editable layout
 #[function_component(Canvas)]
 pub fn canvas(props: &CanvasProps) -> Html {
+    let strings = use_editor_strings();
+    let standalone_css = use_standalone_css();
     let canvas_ref = use_node_ref();
+    let selected_ref = use_node_ref();
+    // Innermost widget under the pointer, highlighted in edit mode
+    let hovered = use_state_eq(|| None::<WidgetId>);
     let is_dragging = use_state(|| false);
+    // Type of the palette widget being dragged, for the drop zones' ghost preview
+    let dragged_type = use_state_eq(|| None::<String>);
+    let ghost = use_memo(
+        (
+            (*dragged_type).clone(),
+            props.registry.clone(),
+            props.style_allowlist.clone(),
+        ),
+        |(dragged_type, registry, style_allowlist)| {
+            DragGhost(dragged_type.as_deref().and_then(|widget_type| {
+                render_ghost(registry, widget_type, style_allowlist.clone())
+            }))
+        },
+    );
+    // Touch drags don't fire drag events, so the editor tracks them
+    let touch_drag = use_context::<TouchDrag>();
+    let dragging = *is_dragging || touch_drag.is_some_and(|touch_drag| touch_drag.is_dragging());
+    // Containers whose children are hidden on the canvas (editor-only, not serialized)
+    let collapsed = use_state(HashSet::<WidgetId>::new);
 
-    let on_canvas_click = {
-        let on_widget_select = props.on_widget_select.clone();
-        Callback::from(move |e: MouseEvent| {
-            // Deselect when clicking on the canvas background
-            if let Some(target) = e.target_dyn_into::<HtmlElement>() {
-                if target.class_list().contains("wysiwyg-canvas") {
-                    on_widget_select.emit(None);
+    // Expand collapsed ancestors so the selected widget is visible
+    {
+        let collapsed = collapsed.clone();
+        let mut ancestors = Vec::new();
+        let mut current = props
+            .selected_widget
+            .and_then(|id| props.layout.get_widget(&id))
+            .and_then(|node| node.parent);
+        while let Some(parent_id) = current {
+            ancestors.push(parent_id);
+            current = props
+                .layout
+                .get_widget(&parent_id)
+                .and_then(|node| node.parent);
+        }
+        use_effect_with(props.selected_widget, move |_| {
+            if ancestors.iter().any(|id| collapsed.contains(id)) {
+                let mut next = (*collapsed).clone();
+                for id in &ancestors {
+                    next.remove(id);
+                }
+                collapsed.set(next);
+            }
+            || ()
+        });
+    }
+
+    // Set when Tab moves the selection, so focus follows it to the new widget
+    let focus_selection = use_mut_ref(|| false);
+
+    // Bring the selected widget into view when the selection changes
+    {
+        let selected_ref = selected_ref.clone();
+        let focus_selection = focus_selection.clone();
+        use_effect_with(props.selected_widget, move |selected| {
+            let focus = std::mem::replace(&mut *focus_selection.borrow_mut(), false);
+            if selected.is_some() {
+                if let Some(element) = selected_ref.cast::<HtmlElement>() {
+                    let options = web_sys::ScrollIntoViewOptions::new();
+                    options.set_behavior(web_sys::ScrollBehavior::Smooth);
+                    options.set_block(web_sys::ScrollLogicalPosition::Nearest);
+                    element.scroll_into_view_with_scroll_into_view_options(&options);
+                    if focus {
+                        let _ = element.focus();
+                    }
                 }
             }
+            || ()
+        });
+    }
+
+    // Tab and Shift+Tab move the selection through widgets in document order.
+    // Past the last (or before the first) widget the key is left alone so focus
+    // can leave the canvas. Fields inside widgets keep the browser's tab order.
+    let on_keydown = {
+        let on_widget_select = props.on_widget_select.clone();
+        let layout = props.layout.clone();
+        let selected_widget = props.selected_widget;
+        let edit_mode = props.edit_mode;
+        Callback::from(move |e: KeyboardEvent| {
+            if !edit_mode
+                || e.key() != "Tab"
+                || e.ctrl_key()
+                || e.alt_key()
+                || e.meta_key()
+                || is_text_entry_target(e.target())
+            {
+                return;
+            }
+            let order = layout.widgets_in_order();
+            let current =
+                selected_widget.and_then(|id| order.iter().position(|other| *other == id));
+            let next = match (current, e.shift_key()) {
+                (Some(index), false) => index + 1,
+                (Some(index), true) => match index.checked_sub(1) {
+                    Some(previous) => previous,
+                    None => return,
+                },
+                (None, false) => 0,
+                (None, true) => return,
+            };
+            let Some(next_id) = order.get(next) else {
+                return;
+            };
+            e.prevent_default();
+            *focus_selection.borrow_mut() = true;
+            on_widget_select.emit(Some(*next_id));
         })
     };
 
+    let on_canvas_click = {
+        let on_widget_select = props.on_widget_select.clone();
+        // Widgets stop their clicks from propagating, so anything reaching the
+        // canvas landed on its background, the page, or a root drop zone
+        Callback::from(move |_: MouseEvent| on_widget_select.emit(None))
+    };
+
     let on_dragenter = {
         let is_dragging = is_dragging.clone();
+        let dragged_type = dragged_type.clone();
         Callback::from(move |e: DragEvent| {
             e.prevent_default();
             is_dragging.set(true);
+            let widget_type = e.data_transfer().and_then(|dt| {
+                dt.types()
+                    .iter()
+                    .filter_map(|format| format.as_string())
+                    .find_map(|format| widget_type_from_drag_format(&format))
+            });
+            dragged_type.set(widget_type);
         })
     };
 
     let on_dragover = {
+        let canvas_ref = canvas_ref.clone();
+        let auto_scroll = props.auto_scroll_on_drag;
         Callback::from(move |e: DragEvent| {
             e.prevent_default();
+            if auto_scroll {
+                if let Some(canvas) = canvas_ref.cast::<Element>() {
+                    auto_scroll_canvas(&canvas, e.client_y() as f64);
+                }
+            }
         })
     };
 
     let on_dragleave = {
         let is_dragging = is_dragging.clone();
+        let dragged_type = dragged_type.clone();
         Callback::from(move |e: DragEvent| {
             // Only set to false if we're leaving the canvas entirely
             if let Some(target) = e.target_dyn_into::<HtmlElement>() {
                 if target.class_list().contains("wysiwyg-canvas") {
                     is_dragging.set(false);
+                    dragged_type.set(None);
                 }
             }
         })
@@ -202,9 +909,11 @@ pub fn canvas(props: &CanvasProps) -> Html {
 
     let on_drop = {
         let is_dragging = is_dragging.clone();
+        let dragged_type = dragged_type.clone();
         Callback::from(move |e: DragEvent| {
             e.prevent_default();
             is_dragging.set(false);
+            dragged_type.set(None);
         })
     };
 
@@ -213,6 +922,7 @@ pub fn canvas(props: &CanvasProps) -> Html {
             ref={canvas_ref}
             class="wysiwyg-canvas"
             onclick={on_canvas_click}
+            onkeydown={on_keydown}
             ondragenter={on_dragenter}
             ondragover={on_dragover}
             ondragleave={on_dragleave}
@@ -225,6 +935,8 @@ pub fn canvas(props: &CanvasProps) -> Html {
                 position: relative;
             "
         >
+            { standalone_css }
+            <ContextProvider<DragGhost> context={(*ghost).clone()}>
             <div style="
                 max-width: 1200px;
                 margin: 0 auto;
@@ -242,86 +954,444 @@ pub fn canvas(props: &CanvasProps) -> Html {
                         if props.edit_mode {
                             elements.push(html! {
                                 <DropZone
+                                    key={format!("drop-{id}")}
                                     parent_id={None}
                                     position={idx}
                                     on_drop={props.on_drop_widget.clone()}
-                                    is_dragging={*is_dragging}
+                                    on_move={props.on_move_widget.clone()}
+                                    on_drop_file={props.on_drop_file.clone()}
+                                    is_dragging={dragging}
                                 />
                             });
                         }
 
                         // The widget itself
-                        elements.push(render_widget_node(
-                            id,
-                            &props.layout,
-                            &props.registry,
-                            props.selected_widget,
-                            props.on_widget_select.clone(),
-                            props.on_widget_delete.clone(),
-                            props.on_widget_move_up.clone(),
-                            props.on_widget_move_down.clone(),
-                            props.on_config_change.clone(),
-                            props.on_drop_widget.clone(),
-                            *is_dragging,
-                            props.edit_mode,
-                        ));
+                        elements.push(render_widget_node(id, props, &strings, dragging, &selected_ref, &hovered, &collapsed));
 
                         elements
                     })
                 }
-                // Drop zone after all widgets (or as the only zone if empty) - only in edit mode
-                if props.edit_mode {
+                if props.layout.root_widgets().is_empty() {
+                    if props.edit_mode {
+                        <EmptyCanvasDropZone
+                            message={props.empty_message.clone()}
+                            on_drop={props.on_drop_widget.clone()}
+                            on_drop_file={props.on_drop_file.clone()}
+                            is_dragging={dragging}
+                        />
+                    } else if let Some(message) = props.empty_preview_message.clone() {
+                        <p style="margin: 0; text-align: center; color: #9ca3af;">{ message }</p>
+                    }
+                } else if props.edit_mode {
+                    // Drop zone after all widgets - only in edit mode
                     <DropZone
                         parent_id={None}
                         position={props.layout.root_widgets().len()}
                         on_drop={props.on_drop_widget.clone()}
-                        is_dragging={*is_dragging}
+                        on_move={props.on_move_widget.clone()}
+                        on_drop_file={props.on_drop_file.clone()}
+                        is_dragging={dragging}
                     />
                 }
             </div>
+            </ContextProvider<DragGhost>>
+        </div>
+    }
+}
+
+/// Properties for the LayoutView component
+#[derive(Properties, PartialEq)]
+pub struct LayoutViewProps {
+    pub layout: Layout,
+    pub registry: WidgetRegistry,
+    /// Renderer for nodes whose widget type is not in the registry
+    #[prop_or_default]
+    pub unknown_widget_fallback: Option<Callback<(WidgetId, WidgetConfig), Html>>,
+    /// Transforms each widget's rendered output (see `EditorProps::render_middleware`)
+    #[prop_or_default]
+    pub render_middleware: Option<Callback<(WidgetId, Html), Html>>,
+    /// Inline style properties widgets may render (all allowed if `None`)
+    #[prop_or_default]
+    pub style_allowlist: Option<Rc<StyleAllowlist>>,
+}
+
+/// Read-only view of a layout, rendered as in preview mode without any editor chrome
+#[function_component(LayoutView)]
+pub fn layout_view(props: &LayoutViewProps) -> Html {
+    let strings = use_editor_strings();
+    // Only consulted in edit mode, but the shared renderer takes them
+    let hovered = use_state_eq(|| None::<WidgetId>);
+    let collapsed = use_state(HashSet::<WidgetId>::new);
+
+    let canvas_props = CanvasProps {
+        layout: props.layout.clone(),
+        registry: props.registry.clone(),
+        selected_widget: None,
+        on_widget_select: Callback::noop(),
+        on_widget_open: Callback::noop(),
+        on_widget_delete: Callback::noop(),
+        on_widget_move_up: Callback::noop(),
+        on_widget_move_down: Callback::noop(),
+        on_widget_duplicate: Callback::noop(),
+        on_config_change: Callback::noop(),
+        on_drop_widget: Callback::noop(),
+        on_move_widget: Callback::noop(),
+        on_drop_file: Callback::noop(),
+        on_drop_into_slot: Callback::noop(),
+        on_move_into_slot: Callback::noop(),
+        edit_mode: false,
+        outline_containers: false,
+        auto_scroll_on_drag: false,
+        max_depth: None,
+        unknown_widget_fallback: props.unknown_widget_fallback.clone(),
+        render_middleware: props.render_middleware.clone(),
+        style_allowlist: props.style_allowlist.clone(),
+        empty_message: AttrValue::Static(DEFAULT_EMPTY_CANVAS_MESSAGE),
+        empty_preview_message: None,
+        on_add_widget: None,
+    };
+    let selected_ref = NodeRef::default();
+
+    html! {
+        <div class="wysiwyg-layout-view">
+            {
+                for props.layout.root_widgets().iter().map(|id| {
+                    render_widget_node(id, &canvas_props, &strings, false, &selected_ref, &hovered, &collapsed)
+                })
+            }
         </div>
     }
 }
 
-#[allow(clippy::too_many_arguments)]
+/// Check whether a click landed on a container's own children area rather than on a child
+///
+/// Nested widgets stop their clicks, so a match inside the clicked wrapper
+/// belongs to that container.
+fn is_container_chrome(e: &MouseEvent) -> bool {
+    let (Some(target), Some(wrapper)) = (
+        e.target_dyn_into::<Element>(),
+        e.current_target()
+            .and_then(|target| target.dyn_into::<Element>().ok()),
+    ) else {
+        return false;
+    };
+    target
+        .closest(".wysiwyg-widget-children, .wysiwyg-collapsed-children")
+        .ok()
+        .flatten()
+        .is_some_and(|chrome| wrapper.contains(Some(&chrome)))
+}
+
 fn render_widget_node(
     id: &WidgetId,
-    layout: &Layout,
-    registry: &WidgetRegistry,
-    selected_widget: Option<WidgetId>,
-    on_widget_select: Callback<Option<WidgetId>>,
-    on_widget_delete: Callback<WidgetId>,
-    on_widget_move_up: Callback<WidgetId>,
-    on_widget_move_down: Callback<WidgetId>,
-    on_config_change: Callback<(WidgetId, WidgetConfig)>,
-    on_drop_widget: Callback<(String, Option<WidgetId>, usize)>,
+    props: &CanvasProps,
+    strings: &EditorStrings,
     is_dragging: bool,
-    edit_mode: bool,
+    selected_ref: &NodeRef,
+    hovered: &UseStateHandle<Option<WidgetId>>,
+    collapsed: &UseStateHandle<HashSet<WidgetId>>,
 ) -> Html {
+    // Keyed by id so reordering siblings moves the existing DOM (and any drag
+    // handle mid-drag) instead of patching a neighbour's node in place
+    let content = render_widget_content(
+        id,
+        props,
+        strings,
+        is_dragging,
+        selected_ref,
+        hovered,
+        collapsed,
+    );
+    html! { <key={id.to_string()}>{ content }</> }
+}
+
+/// Put an anchor id on the root element of a widget's output, as the source export does
+///
+/// Returns `false` when the output has no single element at its root (e.g. text, a
+/// component, or several siblings); the caller then puts the id on a wrapper.
+fn set_root_id(html: &mut Html, element_id: &str) -> bool {
+    match html {
+        Html::VTag(tag) => {
+            tag.add_attribute("id", element_id.to_string());
+            true
+        }
+        Html::VList(list) => {
+            // Skip the scoped responsive stylesheet and empty `if` branches
+            let mut roots = list.iter_mut().filter(|child| match child {
+                Html::VTag(tag) => tag.tag() != "style",
+                Html::VList(list) => !list.is_empty(),
+                _ => true,
+            });
+            match (roots.next(), roots.next()) {
+                (Some(root), None) => set_root_id(root, element_id),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Pass a widget's output through the canvas's `render_middleware`, if any
+fn apply_render_middleware(props: &CanvasProps, id: &WidgetId, content: Html) -> Html {
+    match &props.render_middleware {
+        Some(middleware) => middleware.emit((*id, content)),
+        None => content,
+    }
+}
+
+fn render_widget_content(
+    id: &WidgetId,
+    props: &CanvasProps,
+    strings: &EditorStrings,
+    is_dragging: bool,
+    selected_ref: &NodeRef,
+    hovered: &UseStateHandle<Option<WidgetId>>,
+    collapsed: &UseStateHandle<HashSet<WidgetId>>,
+) -> Html {
+    let layout = &props.layout;
+    let registry = &props.registry;
+    let selected_widget = props.selected_widget;
+    let on_widget_select = props.on_widget_select.clone();
+    let on_widget_delete = props.on_widget_delete.clone();
+    let on_widget_move_up = props.on_widget_move_up.clone();
+    let on_widget_move_down = props.on_widget_move_down.clone();
+    let on_config_change = props.on_config_change.clone();
+    let on_drop_widget = props.on_drop_widget.clone();
+    let on_move_widget = props.on_move_widget.clone();
+    let on_drop_file = props.on_drop_file.clone();
+    let edit_mode = props.edit_mode;
+
     let node = match layout.get_widget(id) {
         Some(node) => node,
         None => return html! {},
     };
 
+    // Edit-only widgets aren't part of the page. Preview-only ones are still
+    // drawn while editing, dimmed, so they can be selected.
+    let visibility = node.config.visibility();
+    if !edit_mode && !visibility.is_visible(false) {
+        return html! {};
+    }
+
     let widget = match registry.create_widget(&node.config.widget_type) {
         Ok(w) => w,
         Err(_) => {
-            return html! {
-                <div style="color: red; border: 2px solid red; padding: 10px;">
-                    { format!("Unknown widget type: {}", node.config.widget_type) }
-                </div>
+            // The node is left untouched in the layout so its config survives export
+            let content = match &props.unknown_widget_fallback {
+                Some(fallback) => fallback.emit((*id, node.config.clone())),
+                None => html! {
+                    <div style="color: red; border: 2px solid red; padding: 10px;">
+                        { format!("{}: {}", strings.unknown_widget_type, node.config.widget_type) }
+                    </div>
+                },
             };
+            return apply_render_middleware(props, id, content);
         }
     };
 
     let is_selected = selected_widget == Some(*id);
+    let is_locked = node.is_locked();
+    // Containers at the depth limit can't take another level of children
+    let at_max_depth = props
+        .max_depth
+        .is_some_and(|max_depth| layout.depth_of(id) >= max_depth);
+    // Locked containers don't accept dropped widgets
+    let accepts_drops = edit_mode && !is_locked && !at_max_depth;
 
     let id_copy = *id;
+    let is_container = widget.can_have_children();
+    // Slotted containers place children by slot, so they're only filled by dropping
+    let can_add_child =
+        is_container && !is_locked && !at_max_depth && widget.child_slots().is_none();
     let on_click = {
         let on_widget_select = on_widget_select.clone();
         Callback::from(move |e: MouseEvent| {
             e.stop_propagation();
-            on_widget_select.emit(Some(id_copy));
+            // Empty space in a container clears an existing selection; with
+            // nothing selected it selects the container, so it stays clickable
+            if is_container && selected_widget.is_some() && is_container_chrome(&e) {
+                on_widget_select.emit(None);
+            } else {
+                on_widget_select.emit(Some(id_copy));
+            }
+        })
+    };
+
+    // A double-click also fires two clicks, which have already selected the widget
+    let id_copy = *id;
+    let on_dblclick = {
+        let on_widget_open = props.on_widget_open.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            on_widget_open.emit(id_copy);
+        })
+    };
+
+    let id_copy = *id;
+    let on_keydown = {
+        let on_widget_select = on_widget_select.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            // Only react when the wrapper itself has focus, not an element inside it
+            if e.target() != e.current_target() {
+                return;
+            }
+            if e.key() == "Enter" || e.key() == " " {
+                e.prevent_default();
+                e.stop_propagation();
+                on_widget_select.emit(Some(id_copy));
+            }
+        })
+    };
+
+    let id_copy = *id;
+    let on_focusin = {
+        let on_widget_select = on_widget_select.clone();
+        Callback::from(move |e: FocusEvent| {
+            // The innermost widget owns the focused field; focusing the wrapper
+            // itself (e.g. tabbing) leaves selection to Enter or a click
+            e.stop_propagation();
+            if selected_widget != Some(id_copy)
+                && e.target() != e.current_target()
+                && is_text_entry_target(e.target())
+            {
+                on_widget_select.emit(Some(id_copy));
+            }
+        })
+    };
+
+    let id_copy = *id;
+    let on_mouseover = {
+        let hovered = hovered.clone();
+        Callback::from(move |e: MouseEvent| {
+            // Only the innermost widget under the pointer is highlighted
+            e.stop_propagation();
+            hovered.set(Some(id_copy));
+        })
+    };
+
+    let on_mouseout = {
+        let hovered = hovered.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            hovered.set(None);
+        })
+    };
+
+    let is_collapsed = edit_mode && is_container && collapsed.contains(id);
+
+    // While dragging, strips on a container's outer edges drop beside it in its
+    // parent, so "next to" doesn't have to compete with the drop zones inside.
+    // Slotted containers have no siblings to drop between.
+    let parent_accepts_drops = node
+        .parent
+        .is_none_or(|parent_id| !layout.is_locked(&parent_id));
+    let edge_drop_zones =
+        (is_container && is_dragging && edit_mode && parent_accepts_drops && node.slot().is_none())
+            .then(|| {
+                let siblings = match node
+                    .parent
+                    .and_then(|parent_id| layout.get_widget(&parent_id))
+                {
+                    Some(parent) => parent.children.as_slice(),
+                    None => layout.root_widgets(),
+                };
+                let index = siblings.iter().position(|sibling| sibling == id)?;
+                let horizontal = node
+                    .parent
+                    .and_then(|parent_id| layout.get_widget(&parent_id))
+                    .and_then(|parent| parent.config.inline_styles.get("flex-direction"))
+                    .is_some_and(|direction| direction.trim() == "row");
+                let (before, after) = if horizontal {
+                    (ContainerEdge::Left, ContainerEdge::Right)
+                } else {
+                    (ContainerEdge::Top, ContainerEdge::Bottom)
+                };
+                Some(html! {
+                    <>
+                        <EdgeDropZone
+                            edge={before}
+                            parent_id={node.parent}
+                            position={index}
+                            on_drop={on_drop_widget.clone()}
+                            on_move={on_move_widget.clone()}
+                            on_drop_file={on_drop_file.clone()}
+                        />
+                        <EdgeDropZone
+                            edge={after}
+                            parent_id={node.parent}
+                            position={index + 1}
+                            on_drop={on_drop_widget.clone()}
+                            on_move={on_move_widget.clone()}
+                            on_drop_file={on_drop_file.clone()}
+                        />
+                    </>
+                })
+            })
+            .flatten();
+
+    // Widgets with slots hold one child per slot, with a labeled drop area while
+    // the slot is empty. Children filling no slot (e.g. from an import) are listed
+    // after the slots so they can still be selected and moved out.
+    let slotted_children = widget
+        .child_slots()
+        .filter(|_| is_container)
+        .map(|slots| {
+            let filled: Vec<_> = slots
+                .iter()
+                .map(|slot| layout.slot_child(id, &slot.name))
+                .collect();
+            let unslotted = node
+                .children
+                .iter()
+                .filter(|child_id| !filled.contains(&Some(**child_id)));
+            html! {
+                <>
+                    {
+                        for slots.iter().zip(&filled).map(|(slot, child)| {
+                            let parent_id = *id;
+                            let name = slot.name.clone();
+                            let on_drop = props
+                                .on_drop_into_slot
+                                .reform(move |(widget_type, _, _)| (widget_type, parent_id, name.clone()));
+                            let name = slot.name.clone();
+                            let on_move = props
+                                .on_move_into_slot
+                                .reform(move |(widget_id, _, _)| (widget_id, parent_id, name.clone()));
+                            html! {
+                                <div class="wysiwyg-slot" data-slot={slot.name.clone()}>
+                                    if edit_mode {
+                                        <div class="wysiwyg-slot-label" style="font-size: 11px; color: #9ca3af; margin: 4px 0 2px;">
+                                            { slot.label.clone() }
+                                        </div>
+                                    }
+                                    if let Some(child_id) = child {
+                                        { render_widget_node(child_id, props, strings, is_dragging, selected_ref, hovered, collapsed) }
+                                    } else if accepts_drops {
+                                        <EmptyContainerDropZone {parent_id} slot={slot.name.clone()} {on_drop} {on_move} />
+                                    }
+                                </div>
+                            }
+                        })
+                    }
+                    {
+                        for unslotted.map(|child_id| {
+                            render_widget_node(child_id, props, strings, is_dragging, selected_ref, hovered, collapsed)
+                        })
+                    }
+                </>
+            }
+        });
+
+    let id_copy = *id;
+    let on_toggle_collapse_click = {
+        let collapsed = collapsed.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            let mut next = (*collapsed).clone();
+            if !next.remove(&id_copy) {
+                next.insert(id_copy);
+            }
+            collapsed.set(next);
         })
     };
 
@@ -352,30 +1422,51 @@ fn render_widget_node(
         })
     };
 
+    let id_copy = *id;
+    let on_duplicate_click = {
+        let on_widget_duplicate = props.on_widget_duplicate.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            on_widget_duplicate.emit(id_copy);
+        })
+    };
+
+    // The allowlist is applied here rather than by each widget, so custom widgets
+    // can't bypass it. Styles it hides are restored into configs the widget emits.
+    let mut config = match &props.style_allowlist {
+        Some(allowlist) => allowlist.filter_config(&node.config),
+        None => node.config.clone(),
+    };
+
     let id_copy = *id;
     let on_config_change_clone = on_config_change.clone();
     let config_change = {
-        Callback::from(move |config: WidgetConfig| {
-            on_config_change_clone.emit((id_copy, config));
+        let original = node.config.clone();
+        let filtered = config.clone();
+        Callback::from(move |mut edited: WidgetConfig| {
+            restore_filtered_styles(&original, &filtered, &mut edited);
+            on_config_change_clone.emit((id_copy, edited));
         })
     };
 
-    let props = WidgetProps {
+    // Responsive styles are applied through a generated class and a scoped stylesheet
+    let responsive_css = (!config.responsive_styles.is_empty()).then(|| {
+        let class = widget_class_name(id);
+        let css = config.responsive_css(&class);
+        config.css_classes.push(class);
+        css
+    });
+
+    let widget_props = WidgetProps {
         id: *id,
         edit_mode,
-        config: node.config.clone(),
+        config: config.clone(),
         children: node.children.clone(),
         on_config_change: config_change,
         on_delete: on_widget_delete.clone(),
     };
 
-    let widget_html = widget.render(&props);
-
-    let wrapper_style = if is_selected && edit_mode {
-        "position: relative; outline: 2px solid #3b82f6; outline-offset: 2px; margin: 4px 0;"
-    } else {
-        "position: relative; margin: 4px 0;"
-    };
+    let widget_html = widget.render(&widget_props);
 
     // Special handling for Link widget - children must be inside <a> tag
     let is_link_widget = node.config.widget_type == "basic.link";
@@ -398,24 +1489,49 @@ fn render_widget_node(
             .unwrap_or("_self")
             .to_string();
 
-        let mut style = String::new();
-        for (k, v) in &node.config.inline_styles {
-            style.push_str(&format!("{}: {}; ", k, v));
-        }
+        let style = build_style(&config);
 
-        let class = node.config.css_classes.join(" ");
+        let class = config.css_classes.join(" ");
 
         (href, target, style, class)
     } else {
         (String::new(), String::new(), String::new(), String::new())
     };
 
-    html! {
+    // Editor chrome (spacing, drop zones) only exists in edit mode
+    let children_style = if edit_mode {
+        "min-height: 40px; display: block;"
+    } else {
+        "display: block;"
+    };
+
+    let collapsed_placeholder = html! {
         <div
-            class="wysiwyg-widget-wrapper"
-            style={wrapper_style}
-            onclick={on_click}
+            class="wysiwyg-collapsed-children"
+            style="
+                padding: 8px 12px;
+                border: 1px dashed #d1d5db;
+                border-radius: 4px;
+                background: #f9fafb;
+                color: #6b7280;
+                font-size: 13px;
+            "
         >
+            {
+                format!(
+                    "{} {} (collapsed)",
+                    node.children.len(),
+                    if node.children.len() == 1 { "child" } else { "children" }
+                )
+            }
+        </div>
+    };
+
+    let content = html! {
+        <>
+            if let Some(css) = responsive_css {
+                <style>{ css }</style>
+            }
             if is_link_widget && widget.can_have_children() {
                 // For Link widgets, use <span> in edit mode, <a> in preview mode
                 {
@@ -423,13 +1539,21 @@ fn render_widget_node(
                         // Edit mode: use <span> so it's not clickable
                         html! {
                             <span class={link_class} style={link_style}>
-                                <div class="wysiwyg-widget-children" style="min-height: 40px; display: block;">
+                                <div class="wysiwyg-widget-children" style={children_style}>
                                     {
-                                        if node.children.is_empty() {
+                                        if is_collapsed {
+                                            vec![collapsed_placeholder]
+                                        } else if !accepts_drops {
+                                            node.children.iter().map(|child_id| {
+                                                render_widget_node(child_id, props, strings, is_dragging, selected_ref, hovered, collapsed)
+                                            }).collect::<Vec<_>>()
+                                        } else if node.children.is_empty() {
                                             vec![html! {
                                                 <EmptyContainerDropZone
                                                     parent_id={*id}
                                                     on_drop={on_drop_widget.clone()}
+                                                    on_move={on_move_widget.clone()}
+                                                    on_drop_file={on_drop_file.clone()}
                                                 />
                                             }]
                                         } else {
@@ -437,33 +1561,26 @@ fn render_widget_node(
                                                 vec![
                                                     html! {
                                                         <DropZone
+                                                            key={format!("drop-{child_id}")}
                                                             parent_id={Some(*id)}
                                                             position={idx}
                                                             on_drop={on_drop_widget.clone()}
+                                                            on_move={on_move_widget.clone()}
+                                                            on_drop_file={on_drop_file.clone()}
                                                             is_dragging={is_dragging}
                                                         />
                                                     },
-                                                    render_widget_node(
-                                                        child_id,
-                                                        layout,
-                                                        registry,
-                                                        selected_widget,
-                                                        on_widget_select.clone(),
-                                                        on_widget_delete.clone(),
-                                                        on_widget_move_up.clone(),
-                                                        on_widget_move_down.clone(),
-                                                        on_config_change.clone(),
-                                                        on_drop_widget.clone(),
-                                                        is_dragging,
-                                                        edit_mode,
-                                                    ),
+                                                    render_widget_node(child_id, props, strings, is_dragging, selected_ref, hovered, collapsed),
                                                 ]
                                             }).chain(vec![
                                                 html! {
                                                     <DropZone
+                                                        key="drop-end"
                                                         parent_id={Some(*id)}
                                                         position={node.children.len()}
                                                         on_drop={on_drop_widget.clone()}
+                                                        on_move={on_move_widget.clone()}
+                                                        on_drop_file={on_drop_file.clone()}
                                                         is_dragging={is_dragging}
                                                     />
                                                 }
@@ -477,23 +1594,10 @@ fn render_widget_node(
                         // Preview mode: use <a> for actual link functionality
                         html! {
                             <a href={link_href} target={link_target} class={link_class} style={link_style}>
-                                <div class="wysiwyg-widget-children" style="min-height: 40px; display: block;">
+                                <div class="wysiwyg-widget-children" style={children_style}>
                                     {
                                         node.children.iter().map(|child_id| {
-                                            render_widget_node(
-                                                child_id,
-                                                layout,
-                                                registry,
-                                                selected_widget,
-                                                on_widget_select.clone(),
-                                                on_widget_delete.clone(),
-                                                on_widget_move_up.clone(),
-                                                on_widget_move_down.clone(),
-                                                on_config_change.clone(),
-                                                on_drop_widget.clone(),
-                                                is_dragging,
-                                                edit_mode,
-                                            )
+                                            render_widget_node(child_id, props, strings, is_dragging, selected_ref, hovered, collapsed)
                                         }).collect::<Vec<_>>()
                                     }
                                 </div>
@@ -506,18 +1610,36 @@ fn render_widget_node(
                 { widget_html }
 
                 // Render children if it's a container
-                if widget.can_have_children() {
-                    <div class="wysiwyg-widget-children" style="min-height: 40px;">
+                if is_collapsed {
+                    { collapsed_placeholder }
+                } else if let Some(slotted_children) = slotted_children {
+                    <div class="wysiwyg-widget-children" style={children_style}>
+                        { slotted_children }
+                    </div>
+                } else if is_container {
+                    <div class="wysiwyg-widget-children" style={children_style}>
                     {
                         if node.children.is_empty() {
                             // For empty containers, show a single prominent drop zone (only in edit mode)
-                            if edit_mode {
+                            if accepts_drops {
                                 vec![html! {
                                     <EmptyContainerDropZone
                                         parent_id={*id}
                                         on_drop={on_drop_widget.clone()}
+                                        on_move={on_move_widget.clone()}
+                                        on_drop_file={on_drop_file.clone()}
                                     />
                                 }]
+                            } else if edit_mode && at_max_depth && !is_locked {
+                                vec![html! {
+                                    <div
+                                        class="wysiwyg-drop-disabled"
+                                        aria-disabled="true"
+                                        style="min-height: 50px; width: 100%; border: 2px dashed #e5e7eb; background: #f9fafb; border-radius: 4px; margin: 8px 0; display: flex; align-items: center; justify-content: center; color: #9ca3af; font-size: 13px; cursor: not-allowed;"
+                                    >
+                                        { strings.max_depth_reached.clone() }
+                                    </div>
+                                }]
                             } else {
                                 vec![]
                             }
@@ -527,42 +1649,35 @@ fn render_widget_node(
                                 let mut elements = vec![];
 
                                 // Drop zone before child (only in edit mode)
-                                if edit_mode {
+                                if accepts_drops {
                                     elements.push(html! {
                                         <DropZone
+                                            key={format!("drop-{child_id}")}
                                             parent_id={Some(*id)}
                                             position={idx}
                                             on_drop={on_drop_widget.clone()}
+                                            on_move={on_move_widget.clone()}
+                                            on_drop_file={on_drop_file.clone()}
                                             is_dragging={is_dragging}
                                         />
                                     });
                                 }
 
                                 // The child widget
-                                elements.push(render_widget_node(
-                                    child_id,
-                                    layout,
-                                    registry,
-                                    selected_widget,
-                                    on_widget_select.clone(),
-                                    on_widget_delete.clone(),
-                                    on_widget_move_up.clone(),
-                                    on_widget_move_down.clone(),
-                                    on_config_change.clone(),
-                                    on_drop_widget.clone(),
-                                    is_dragging,
-                                    edit_mode,
-                                ));
+                                elements.push(render_widget_node(child_id, props, strings, is_dragging, selected_ref, hovered, collapsed));
 
                                 elements
                             }).chain(
                                 // Drop zone after all children (only in edit mode)
-                                if edit_mode {
+                                if accepts_drops {
                                     vec![html! {
                                         <DropZone
+                                            key="drop-end"
                                             parent_id={Some(*id)}
                                             position={node.children.len()}
                                             on_drop={on_drop_widget.clone()}
+                                            on_move={on_move_widget.clone()}
+                                            on_drop_file={on_drop_file.clone()}
                                             is_dragging={is_dragging}
                                         />
                                     }]
@@ -575,9 +1690,106 @@ fn render_widget_node(
                 </div>
                 }
             }
+        </>
+    };
+    let mut content = apply_render_middleware(props, id, content);
+
+    // Only kept when the output has no root element to carry it
+    let element_id = node
+        .config
+        .element_id()
+        .filter(|element_id| !set_root_id(&mut content, element_id))
+        .map(str::to_string);
+
+    if !edit_mode {
+        // Static render: the widget output only, plus a wrapper for grid placement
+        // (or an anchor id without a root element) if needed
+        let grid_style = node.config.grid_item_style();
+        return if grid_style.is_empty() && element_id.is_none() {
+            content
+        } else {
+            html! {
+                <div id={element_id} style={(!grid_style.is_empty()).then_some(grid_style)}>
+                    { content }
+                </div>
+            }
+        };
+    }
+
+    let is_hovered = **hovered == Some(*id);
+    let mut wrapper_style = if is_selected {
+        "position: relative; outline: 2px solid #3b82f6; outline-offset: 2px; margin: 4px 0;"
+            .to_string()
+    } else if is_hovered {
+        "position: relative; outline: 1px dashed #93c5fd; outline-offset: 2px; margin: 4px 0;"
+            .to_string()
+    } else if is_container && props.outline_containers {
+        "position: relative; outline: 1px dashed #d1d5db; outline-offset: 2px; margin: 4px 0;"
+            .to_string()
+    } else {
+        "position: relative; margin: 4px 0;".to_string()
+    };
+    let grid_style = node.config.grid_item_style();
+    if !grid_style.is_empty() {
+        wrapper_style.push(' ');
+        wrapper_style.push_str(&grid_style);
+    }
+    if !visibility.is_visible(true) {
+        wrapper_style.push_str(" opacity: 0.5;");
+    }
+
+    html! {
+        <div
+            ref={if is_selected { selected_ref.clone() } else { NodeRef::default() }}
+            id={element_id}
+            class="wysiwyg-widget-wrapper"
+            style={wrapper_style}
+            role="button"
+            tabindex="0"
+            aria-label={widget.display_name()}
+            aria-selected={if is_selected { "true" } else { "false" }}
+            onclick={on_click}
+            ondblclick={on_dblclick}
+            onkeydown={on_keydown}
+            onfocusin={on_focusin}
+            onmouseover={on_mouseover}
+            onmouseout={on_mouseout}
+        >
+            { content }
+
+            if let Some(edge_drop_zones) = edge_drop_zones {
+                { edge_drop_zones }
+            }
 
-            // Control buttons when selected (only in edit mode)
-            if is_selected && edit_mode {
+            // Lock badge on locked widgets
+            if is_locked {
+                <div
+                    class="wysiwyg-lock-badge"
+                    title={strings.locked.clone()}
+                    aria-hidden="true"
+                    style="
+                        position: absolute;
+                        top: -10px;
+                        left: -10px;
+                        width: 20px;
+                        height: 20px;
+                        display: flex;
+                        align-items: center;
+                        justify-content: center;
+                        background: white;
+                        border: 1px solid #ddd;
+                        border-radius: 50%;
+                        font-size: 11px;
+                        box-shadow: 0 1px 2px rgba(0,0,0,0.1);
+                        pointer-events: none;
+                    "
+                >
+                    { "🔒" }
+                </div>
+            }
+
+            // Control buttons when selected (only collapse is offered for locked widgets)
+            if is_selected && (!is_locked || is_container) {
                 <div style="
                     position: absolute;
                     top: -30px;
@@ -590,53 +1802,117 @@ fn render_widget_node(
                     padding: 4px;
                     box-shadow: 0 2px 4px rgba(0,0,0,0.1);
                 ">
-                    <button
-                        onclick={on_move_up_click}
-                        style="
-                            background: #3b82f6;
-                            color: white;
-                            border: none;
-                            padding: 4px 8px;
-                            border-radius: 3px;
-                            cursor: pointer;
-                            font-size: 12px;
-                        "
-                        title="Move up"
-                    >
-                        { "↑" }
-                    </button>
-                    <button
-                        onclick={on_move_down_click}
-                        style="
-                            background: #3b82f6;
-                            color: white;
-                            border: none;
-                            padding: 4px 8px;
-                            border-radius: 3px;
-                            cursor: pointer;
-                            font-size: 12px;
-                        "
-                        title="Move down"
-                    >
-                        { "↓" }
-                    </button>
-                    <button
-                        onclick={on_delete_click}
-                        style="
-                            background: #ef4444;
-                            color: white;
-                            border: none;
-                            padding: 4px 8px;
-                            border-radius: 3px;
-                            cursor: pointer;
-                            font-size: 12px;
-                        "
-                        title="Delete"
-                    >
-                        { "Delete" }
-                    </button>
+                    if is_container {
+                        <button
+                            onclick={on_toggle_collapse_click}
+                            aria-expanded={if is_collapsed { "false" } else { "true" }}
+                            style="
+                                background: #f3f4f6;
+                                color: #374151;
+                                border: none;
+                                padding: 4px 8px;
+                                border-radius: 3px;
+                                cursor: pointer;
+                                font-size: 12px;
+                            "
+                            title={if is_collapsed { strings.expand.clone() } else { strings.collapse.clone() }}
+                        >
+                            { if is_collapsed { "▸" } else { "▾" } }
+                        </button>
+                    }
+                    if let Some(on_add) = props.on_add_widget.clone().filter(|_| can_add_child) {
+                        <AddChildMenu registry={registry.clone()} {on_add} />
+                    }
+                    if !is_locked {
+                        <DragHandle widget_id={*id} />
+                        <button
+                            onclick={on_move_up_click}
+                            style="
+                                background: #3b82f6;
+                                color: white;
+                                border: none;
+                                padding: 4px 8px;
+                                border-radius: 3px;
+                                cursor: pointer;
+                                font-size: 12px;
+                            "
+                            title={strings.move_up.clone()}
+                        >
+                            { "↑" }
+                        </button>
+                        <button
+                            onclick={on_move_down_click}
+                            style="
+                                background: #3b82f6;
+                                color: white;
+                                border: none;
+                                padding: 4px 8px;
+                                border-radius: 3px;
+                                cursor: pointer;
+                                font-size: 12px;
+                            "
+                            title={strings.move_down.clone()}
+                        >
+                            { "↓" }
+                        </button>
+                        <button
+                            onclick={on_duplicate_click}
+                            style="
+                                background: #3b82f6;
+                                color: white;
+                                border: none;
+                                padding: 4px 8px;
+                                border-radius: 3px;
+                                cursor: pointer;
+                                font-size: 12px;
+                            "
+                            title={strings.duplicate.clone()}
+                        >
+                            { "⧉" }
+                        </button>
+                        <button
+                            onclick={on_delete_click}
+                            style="
+                                background: #ef4444;
+                                color: white;
+                                border: none;
+                                padding: 4px 8px;
+                                border-radius: 3px;
+                                cursor: pointer;
+                                font-size: 12px;
+                            "
+                            title={strings.delete.clone()}
+                        >
+                            { strings.delete.clone() }
+                        </button>
+                    }
                 </div>
             }
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widget_type_drag_format() {
+        for widget_type in ["text", "container.row", "Acme::Chart", "émoji-✓"] {
+            let format = widget_type_drag_format(widget_type);
+            assert_eq!(format, format.to_ascii_lowercase());
+            assert_eq!(
+                widget_type_from_drag_format(&format).as_deref(),
+                Some(widget_type)
+            );
+        }
+        assert_eq!(
+            widget_type_from_drag_format("application/widget-type"),
+            None
+        );
+        assert_eq!(
+            widget_type_from_drag_format("application/x-wysiwyg-type-7"),
+            None
+        );
+    }
+}
@@ -1,10 +1,35 @@
 //! Configuration panel for editing widget properties
 
+use indexmap::IndexMap;
+use std::collections::{BTreeSet, HashMap};
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
 
+use super::dialog::ConfirmDialog;
+use super::strings::{use_editor_strings, with_name, EditorStrings};
+use super::toast::ToastLevel;
 use crate::core::registry::WidgetRegistry;
-use crate::core::widget::{WidgetConfig, WidgetId};
-use crate::serialization::Layout;
+use crate::core::widget::{
+    is_valid_element_id, is_valid_grid_area_name, parse_grid_template_areas, Breakpoint,
+    Visibility, WidgetConfig, WidgetId, ELEMENT_ID, GRID_AREA, GRID_COLUMN_SPAN, GRID_ROW_SPAN,
+    GRID_TEMPLATE_AREAS, VISIBILITY,
+};
+use crate::serialization::{Layout, LayoutNode, LOCKED_KEY, SLOT_KEY};
+
+/// Style of the "?" help control in the config panel header
+const HELP_BUTTON_STYLE: &str = "
+    padding: 4px 8px;
+    background: #f3f4f6;
+    color: #374151;
+    border: 1px solid #e5e7eb;
+    border-radius: 4px;
+    cursor: pointer;
+    font-size: 12px;
+    text-decoration: none;
+";
+
+/// `flex` value that lets a Row child grow into the remaining space
+const ROW_ITEM_FILL: &str = "1 1 0%";
 
 /// Build breadcrumb path from root to selected widget
 fn build_breadcrumb_path(
@@ -35,6 +60,976 @@ fn build_breadcrumb_path(
     path
 }
 
+/// Render the generic layout controls for a widget placed inside a Grid container
+fn render_grid_item_ui(
+    config: &WidgetConfig,
+    grid: &WidgetConfig,
+    strings: &EditorStrings,
+    on_change: Callback<WidgetConfig>,
+) -> Html {
+    let span_input = |key: &'static str, label: &AttrValue| {
+        let span = config
+            .get_property(key)
+            .and_then(|v| v.as_u64())
+            .unwrap_or(1);
+
+        let config_clone = config.clone();
+        let on_change = on_change.clone();
+        let oninput = Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut new_config = config_clone.clone();
+            match input.value().parse::<u64>() {
+                Ok(span) if span > 1 => {
+                    new_config.set_property(key, serde_json::json!(span));
+                }
+                _ => {
+                    new_config.properties.remove(key);
+                }
+            }
+            on_change.emit(new_config);
+        });
+
+        html! {
+            <div style="margin-bottom: 12px;">
+                <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                    { label.clone() }
+                </label>
+                <input
+                    type="number"
+                    value={span.to_string()}
+                    {oninput}
+                    min="1"
+                    max="12"
+                    style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                />
+            </div>
+        }
+    };
+
+    let area = config.get_str(GRID_AREA, "");
+    // Names the grid's template defines, or `None` while the template doesn't parse
+    let grid_areas: Option<BTreeSet<String>> =
+        parse_grid_template_areas(&grid.get_str(GRID_TEMPLATE_AREAS, ""))
+            .ok()
+            .map(|rows| {
+                rows.into_iter()
+                    .flatten()
+                    .filter(|name| is_valid_grid_area_name(name))
+                    .collect()
+            });
+    let area_warning = if area.is_empty() {
+        None
+    } else if !is_valid_grid_area_name(&area) {
+        Some(with_name(&strings.grid_area_invalid_template, &area))
+    } else if grid_areas.is_some_and(|areas| !areas.contains(&area)) {
+        Some(with_name(&strings.grid_area_missing_template, &area))
+    } else {
+        None
+    };
+
+    let config_clone = config.clone();
+    let on_change_clone = on_change.clone();
+    let on_area_input = Callback::from(move |e: InputEvent| {
+        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+        let value = input.value();
+        let mut new_config = config_clone.clone();
+        if value.trim().is_empty() {
+            new_config.properties.remove(GRID_AREA);
+        } else {
+            new_config.set_property(GRID_AREA, serde_json::json!(value.trim()));
+        }
+        on_change_clone.emit(new_config);
+    });
+
+    html! {
+        <div>
+            { span_input(GRID_COLUMN_SPAN, &strings.column_span) }
+            { span_input(GRID_ROW_SPAN, &strings.row_span) }
+            <div style="margin-bottom: 12px;">
+                <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                    { strings.grid_area.clone() }
+                </label>
+                <input
+                    type="text"
+                    value={area}
+                    oninput={on_area_input}
+                    style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                />
+                if let Some(warning) = area_warning {
+                    <small style="display: block; margin-top: 4px; color: #b45309;">
+                        { warning }
+                    </small>
+                }
+            </div>
+        </div>
+    }
+}
+
+/// Render flex sizing controls for a widget placed inside a Row container
+fn render_row_item_ui(
+    config: &WidgetConfig,
+    strings: &EditorStrings,
+    on_change: Callback<WidgetConfig>,
+) -> Html {
+    let flex = config.inline_styles.get("flex").map(String::as_str);
+    let fill = flex == Some(ROW_ITEM_FILL);
+    let width = flex
+        .and_then(|flex| flex.strip_prefix("0 0 "))
+        .unwrap_or("")
+        .to_string();
+
+    let config_clone = config.clone();
+    let on_change_clone = on_change.clone();
+    let on_width_input = Callback::from(move |e: InputEvent| {
+        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+        let value = input.value();
+        let value = value.trim();
+        let mut new_config = config_clone.clone();
+        new_config.set_style(
+            None,
+            "flex",
+            (!value.is_empty()).then(|| format!("0 0 {}", value)),
+        );
+        on_change_clone.emit(new_config);
+    });
+
+    let config_clone = config.clone();
+    let on_fill_change = Callback::from(move |e: Event| {
+        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+        let mut new_config = config_clone.clone();
+        new_config.set_style(
+            None,
+            "flex",
+            input.checked().then(|| ROW_ITEM_FILL.to_string()),
+        );
+        on_change.emit(new_config);
+    });
+
+    html! {
+        <div>
+            <div style="margin-bottom: 12px;">
+                <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                    { strings.row_item_width.clone() }
+                </label>
+                <input
+                    type="text"
+                    value={width}
+                    oninput={on_width_input}
+                    disabled={fill}
+                    placeholder={strings.row_item_width_placeholder.clone()}
+                    style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                />
+            </div>
+            <label style="display: flex; align-items: center; gap: 6px; font-size: 14px;">
+                <input type="checkbox" checked={fill} onchange={on_fill_change} />
+                { strings.fill_remaining_space.clone() }
+            </label>
+        </div>
+    }
+}
+
+/// Set or clear a style for a breakpoint (`None` for the base styles), clearing it when blank
+fn with_inline_style(
+    config: &WidgetConfig,
+    breakpoint: Option<Breakpoint>,
+    key: &str,
+    value: &str,
+) -> WidgetConfig {
+    let mut new_config = config.clone();
+    let value = value.trim();
+    new_config.set_style(
+        breakpoint,
+        key,
+        (!value.is_empty()).then(|| value.to_string()),
+    );
+    new_config
+}
+
+/// Render the base/breakpoint tabs for the Appearance section
+fn render_breakpoint_selector(
+    selected: Option<Breakpoint>,
+    config: &WidgetConfig,
+    strings: &EditorStrings,
+    on_select: Callback<Option<Breakpoint>>,
+) -> Html {
+    let tab = |breakpoint: Option<Breakpoint>| {
+        let active = selected == breakpoint;
+        let label = match breakpoint {
+            Some(breakpoint) => format!("≥{}px", breakpoint.min_width()),
+            None => strings.breakpoint_base.to_string(),
+        };
+        let title = match breakpoint {
+            Some(breakpoint) => {
+                with_name(&strings.breakpoint_and_wider_template, breakpoint.label())
+            }
+            None => strings.breakpoint_all_sizes.to_string(),
+        };
+        let has_styles = breakpoint.is_some() && config.styles(breakpoint).is_some();
+        let on_select = on_select.clone();
+
+        html! {
+            <button
+                onclick={Callback::from(move |_: MouseEvent| on_select.emit(breakpoint))}
+                aria-pressed={if active { "true" } else { "false" }}
+                {title}
+                style={format!(
+                    "flex: 1; padding: 4px 6px; border: none; cursor: pointer; font-size: 11px; {}",
+                    if active {
+                        "background: #3b82f6; color: white;"
+                    } else {
+                        "background: #f3f4f6; color: #374151;"
+                    }
+                )}
+            >
+                { label }
+                if has_styles {
+                    { " •" }
+                }
+            </button>
+        }
+    };
+
+    html! {
+        <div
+            role="group"
+            aria-label={strings.breakpoint.clone()}
+            style="display: flex; margin-bottom: 12px; border: 1px solid #e5e7eb; border-radius: 4px; overflow: hidden;"
+        >
+            { tab(None) }
+            { for Breakpoint::ALL.iter().map(|breakpoint| tab(Some(*breakpoint))) }
+        </div>
+    }
+}
+
+/// Render the shared Appearance controls, which edit `config.inline_styles` for any widget
+///
+/// With a breakpoint selected, the controls edit that breakpoint's `responsive_styles` instead.
+fn render_appearance_ui(
+    config: &WidgetConfig,
+    breakpoint: Option<Breakpoint>,
+    strings: &EditorStrings,
+    on_change: Callback<WidgetConfig>,
+) -> Html {
+    let empty = Default::default();
+    let styles = config.styles(breakpoint).unwrap_or(&empty);
+
+    let label_style = "display: block; margin-bottom: 4px; font-weight: 500;";
+    let input_style = "width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px; box-sizing: border-box;";
+
+    let style_input = |key: &'static str, label: &AttrValue, placeholder: &'static str| {
+        let value = styles.get(key).cloned().unwrap_or_default();
+        let config_clone = config.clone();
+        let on_change = on_change.clone();
+        let onchange = Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let value = input.value();
+            let mut new_config = with_inline_style(&config_clone, breakpoint, key, &value);
+            // A border width has no effect without a border style
+            if key == "border-width"
+                && !value.trim().is_empty()
+                && !matches!(
+                    new_config.styles(breakpoint),
+                    Some(styles) if styles.contains_key("border-style")
+                )
+                && !new_config.inline_styles.contains_key("border-style")
+            {
+                new_config = with_inline_style(&new_config, breakpoint, "border-style", "solid");
+            }
+            on_change.emit(new_config);
+        });
+
+        html! {
+            <div style="min-width: 0;">
+                <label style="display: block; margin-bottom: 2px; font-size: 11px; color: #6b7280;">
+                    { label.clone() }
+                </label>
+                <input
+                    type="text"
+                    {value}
+                    {placeholder}
+                    {onchange}
+                    style={input_style}
+                />
+            </div>
+        }
+    };
+
+    let color_input = |key: &'static str, label: &AttrValue| {
+        let value = styles.get(key).cloned();
+        // Color inputs only accept #rrggbb values
+        let swatch = value
+            .as_deref()
+            .filter(|v| v.len() == 7 && v.starts_with('#'))
+            .unwrap_or("#000000")
+            .to_string();
+
+        let oninput = {
+            let config = config.clone();
+            let on_change = on_change.clone();
+            Callback::from(move |e: InputEvent| {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                on_change.emit(with_inline_style(&config, breakpoint, key, &input.value()));
+            })
+        };
+        let onclear = {
+            let config = config.clone();
+            let on_change = on_change.clone();
+            Callback::from(move |_: MouseEvent| {
+                on_change.emit(with_inline_style(&config, breakpoint, key, ""));
+            })
+        };
+
+        html! {
+            <div style="margin-bottom: 12px;">
+                <label style={label_style}>{ label.clone() }</label>
+                <div style="display: flex; align-items: center; gap: 8px;">
+                    <input
+                        type="color"
+                        value={swatch}
+                        {oninput}
+                        style="width: 40px; height: 28px; padding: 0; border: 1px solid #ddd; border-radius: 4px;"
+                    />
+                    <span style="flex: 1; font-size: 12px; font-family: monospace; color: #6b7280;">
+                        { value.clone().unwrap_or_else(|| strings.no_style_value.to_string()) }
+                    </span>
+                    if value.is_some() {
+                        <button
+                            onclick={onclear}
+                            title={with_name(&strings.clear_style_template, label.trim_end_matches(':'))}
+                            style="background: none; border: none; cursor: pointer; color: #6b7280; font-size: 16px;"
+                        >
+                            { "×" }
+                        </button>
+                    }
+                </div>
+            </div>
+        }
+    };
+
+    let sides = |prefix: &'static str, label: &AttrValue, keys: [&'static str; 4]| {
+        html! {
+            <div style="margin-bottom: 12px;">
+                <label style={label_style}>{ label.clone() }</label>
+                <div
+                    class={format!("wysiwyg-appearance-{}", prefix)}
+                    style="display: grid; grid-template-columns: repeat(4, 1fr); gap: 4px;"
+                >
+                    { style_input(keys[0], &strings.side_top, "0") }
+                    { style_input(keys[1], &strings.side_right, "0") }
+                    { style_input(keys[2], &strings.side_bottom, "0") }
+                    { style_input(keys[3], &strings.side_left, "0") }
+                </div>
+            </div>
+        }
+    };
+
+    html! {
+        <div>
+            { color_input("background-color", &strings.background) }
+            { color_input("color", &strings.text_color) }
+            {
+                sides(
+                    "padding",
+                    &strings.padding,
+                    ["padding-top", "padding-right", "padding-bottom", "padding-left"],
+                )
+            }
+            {
+                sides(
+                    "margin",
+                    &strings.margin,
+                    ["margin-top", "margin-right", "margin-bottom", "margin-left"],
+                )
+            }
+            <div style="margin-bottom: 12px;">
+                <label style={label_style}>{ strings.border.clone() }</label>
+                <div style="display: grid; grid-template-columns: 1fr 1fr; gap: 4px;">
+                    { style_input("border-width", &strings.border_width, "0") }
+                    { style_input("border-radius", &strings.border_radius, "0") }
+                </div>
+            </div>
+            { color_input("border-color", &strings.border_color) }
+        </div>
+    }
+}
+
+/// Render the CSS class editor: checkboxes for `available_classes` plus a freeform input
+fn render_classes_ui(
+    config: &WidgetConfig,
+    available_classes: &[String],
+    strings: &EditorStrings,
+    on_change: Callback<WidgetConfig>,
+) -> Html {
+    let onchange = {
+        let config = config.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut classes: Vec<String> = Vec::new();
+            for class in input.value().split_whitespace() {
+                if !classes.iter().any(|c| c == class) {
+                    classes.push(class.to_string());
+                }
+            }
+            let mut new_config = config.clone();
+            new_config.css_classes = classes;
+            if new_config != config {
+                on_change.emit(new_config);
+            }
+        })
+    };
+
+    let class_checkbox = |class: &String| {
+        let checked = config.css_classes.contains(class);
+        let onchange = {
+            let config = config.clone();
+            let class = class.clone();
+            let on_change = on_change.clone();
+            Callback::from(move |e: Event| {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let mut new_config = config.clone();
+                new_config.css_classes.retain(|c| *c != class);
+                if input.checked() {
+                    new_config.css_classes.push(class.clone());
+                }
+                on_change.emit(new_config);
+            })
+        };
+
+        html! {
+            <label style="display: flex; align-items: center; gap: 6px; font-size: 13px;">
+                <input type="checkbox" {checked} {onchange} />
+                <code>{ class }</code>
+            </label>
+        }
+    };
+
+    html! {
+        <div style="margin-top: 12px;">
+            <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                { strings.css_classes.clone() }
+            </label>
+            if !available_classes.is_empty() {
+                <div style="display: flex; flex-direction: column; gap: 4px; margin-bottom: 8px;">
+                    { for available_classes.iter().map(class_checkbox) }
+                </div>
+            }
+            <input
+                type="text"
+                value={config.css_classes.join(" ")}
+                {onchange}
+                placeholder={strings.css_classes_placeholder.clone()}
+                style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+            />
+        </div>
+    }
+}
+
+/// Render the select choosing whether a widget shows in edit mode, preview, or both
+fn render_visibility_ui(
+    config: &WidgetConfig,
+    strings: &EditorStrings,
+    on_change: Callback<WidgetConfig>,
+) -> Html {
+    let current = config.visibility();
+
+    let config_clone = config.clone();
+    let onchange = Callback::from(move |e: Event| {
+        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+        let Some(visibility) = select
+            .value()
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| Visibility::ALL.get(index).copied())
+        else {
+            return;
+        };
+        let mut new_config = config_clone.clone();
+        if visibility == Visibility::Always {
+            new_config.properties.remove(VISIBILITY);
+        } else {
+            new_config.set_property(VISIBILITY, serde_json::json!(visibility));
+        }
+        on_change.emit(new_config);
+    });
+
+    html! {
+        <div style="margin-bottom: 12px;">
+            <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                { strings.visibility.clone() }
+            </label>
+            <select
+                {onchange}
+                style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+            >
+                { for Visibility::ALL.iter().enumerate().map(|(index, visibility)| {
+                    let label = match visibility {
+                        Visibility::Always => strings.visibility_always.clone(),
+                        Visibility::PreviewOnly => strings.visibility_preview_only.clone(),
+                        Visibility::EditOnly => strings.visibility_edit_only.clone(),
+                    };
+                    html! {
+                        <option value={index.to_string()} selected={*visibility == current}>
+                            { label }
+                        </option>
+                    }
+                }) }
+            </select>
+        </div>
+    }
+}
+
+/// Properties for the ElementIdField component
+#[derive(Properties, PartialEq)]
+struct ElementIdFieldProps {
+    widget_id: WidgetId,
+    config: WidgetConfig,
+    layout: Layout,
+    on_change: Callback<WidgetConfig>,
+}
+
+/// Anchor ID input; only valid, unique IDs are written to the config
+#[function_component(ElementIdField)]
+fn element_id_field(props: &ElementIdFieldProps) -> Html {
+    let strings = use_editor_strings();
+    let draft = use_state(|| props.config.element_id().unwrap_or("").to_string());
+
+    let error = if draft.is_empty() {
+        None
+    } else if !is_valid_element_id(&draft) {
+        Some(strings.anchor_id_invalid.clone())
+    } else if !props
+        .layout
+        .is_element_id_available(&draft, &props.widget_id)
+    {
+        Some(strings.anchor_id_taken.clone())
+    } else {
+        None
+    };
+
+    let oninput = {
+        let draft = draft.clone();
+        let config = props.config.clone();
+        let layout = props.layout.clone();
+        let widget_id = props.widget_id;
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let value = input.value().trim().to_string();
+            draft.set(value.clone());
+
+            let mut new_config = config.clone();
+            if value.is_empty() {
+                new_config.properties.remove(ELEMENT_ID);
+            } else if is_valid_element_id(&value)
+                && layout.is_element_id_available(&value, &widget_id)
+            {
+                new_config.set_property(ELEMENT_ID, serde_json::json!(value));
+            } else {
+                return;
+            }
+            if new_config != config {
+                on_change.emit(new_config);
+            }
+        })
+    };
+
+    html! {
+        <div>
+            <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                { strings.anchor_id.clone() }
+            </label>
+            <input
+                type="text"
+                value={(*draft).clone()}
+                {oninput}
+                placeholder={strings.anchor_id_placeholder.clone()}
+                aria-invalid={if error.is_some() { "true" } else { "false" }}
+                style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+            />
+            if let Some(error) = error {
+                <p style="margin: 4px 0 0 0; font-size: 12px; color: #dc2626;">{ error }</p>
+            } else if !draft.is_empty() {
+                <p style="margin: 4px 0 0 0; font-size: 12px; color: #6b7280;">
+                    { with_name(&strings.anchor_link_template, &draft) }
+                </p>
+            }
+        </div>
+    }
+}
+
+/// Properties for the MetadataField component
+#[derive(Properties, PartialEq)]
+struct MetadataFieldProps {
+    metadata: HashMap<String, serde_json::Value>,
+    on_change: Callback<HashMap<String, serde_json::Value>>,
+}
+
+/// Check whether a metadata key is managed by the editor rather than the JSON field
+fn is_managed_metadata_key(key: &str) -> bool {
+    key == LOCKED_KEY || key == SLOT_KEY
+}
+
+/// JSON editor for a node's custom metadata, applied when the field loses focus
+///
+/// The lock flag and slot are left out, since the lock button and slot drops
+/// manage them.
+#[function_component(MetadataField)]
+fn metadata_field(props: &MetadataFieldProps) -> Html {
+    let strings = use_editor_strings();
+    let draft = use_state(|| {
+        let metadata: serde_json::Map<String, serde_json::Value> = props
+            .metadata
+            .iter()
+            .filter(|(key, _)| !is_managed_metadata_key(key))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        serde_json::to_string_pretty(&metadata).unwrap_or_default()
+    });
+    let error = use_state(|| None::<String>);
+
+    let oninput = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            draft.set(input.value());
+        })
+    };
+
+    let onchange = {
+        let draft = draft.clone();
+        let error = error.clone();
+        let metadata = props.metadata.clone();
+        let on_change = props.on_change.clone();
+        let invalid_json_object = strings.invalid_json_object.clone();
+        Callback::from(move |_: Event| {
+            let text = draft.trim();
+            let parsed = if text.is_empty() {
+                Ok(HashMap::new())
+            } else {
+                serde_json::from_str::<HashMap<String, serde_json::Value>>(text)
+            };
+            match parsed {
+                Ok(new_metadata) => {
+                    error.set(None);
+                    let unchanged = new_metadata.len()
+                        == metadata
+                            .keys()
+                            .filter(|key| !is_managed_metadata_key(key))
+                            .count()
+                        && new_metadata
+                            .iter()
+                            .all(|(key, value)| metadata.get(key) == Some(value));
+                    if !unchanged {
+                        on_change.emit(new_metadata);
+                    }
+                }
+                Err(e) => error.set(Some(format!("{}: {}", invalid_json_object, e))),
+            }
+        })
+    };
+
+    html! {
+        <div>
+            <p style="margin: 0 0 8px 0; font-size: 12px; color: #6b7280;">
+                { strings.metadata_hint.clone() }
+            </p>
+            <textarea
+                value={(*draft).clone()}
+                {oninput}
+                {onchange}
+                rows="6"
+                spellcheck="false"
+                aria-label={strings.metadata.clone()}
+                aria-invalid={if error.is_some() { "true" } else { "false" }}
+                style="
+                    width: 100%;
+                    padding: 6px;
+                    border: 1px solid #ddd;
+                    border-radius: 4px;
+                    font-family: monospace;
+                    font-size: 12px;
+                    resize: vertical;
+                "
+            />
+            if let Some(error) = &*error {
+                <p style="margin: 4px 0 0 0; font-size: 12px; color: #dc2626;">{ error }</p>
+            }
+        </div>
+    }
+}
+
+/// Properties for the WidgetIdBadge component
+#[derive(Properties, PartialEq)]
+struct WidgetIdBadgeProps {
+    id: WidgetId,
+    on_toast: Callback<(String, ToastLevel)>,
+}
+
+/// Short form of a widget ID that copies (and reveals) the full ID on click
+#[function_component(WidgetIdBadge)]
+fn widget_id_badge(props: &WidgetIdBadgeProps) -> Html {
+    let strings = use_editor_strings();
+    let revealed = use_state(|| false);
+
+    // Collapse again when a different widget is selected
+    {
+        let revealed = revealed.clone();
+        use_effect_with(props.id, move |_| {
+            revealed.set(false);
+            || ()
+        });
+    }
+
+    let full_id = props.id.to_string();
+    let onclick = {
+        let revealed = revealed.clone();
+        let full_id = full_id.clone();
+        let on_toast = props.on_toast.clone();
+        let copied_message = strings.widget_id_copied.to_string();
+        Callback::from(move |_: MouseEvent| {
+            revealed.set(true);
+            let on_toast = on_toast.clone();
+            let copied_message = copied_message.clone();
+            super::copy_to_clipboard(
+                &full_id,
+                Callback::from(move |copied: bool| {
+                    if copied {
+                        on_toast.emit((copied_message.clone(), ToastLevel::Info));
+                    }
+                }),
+            );
+        })
+    };
+
+    html! {
+        <div style="display: flex; align-items: center; gap: 6px; flex-wrap: wrap;">
+            <span>{ strings.widget_id.clone() }</span>
+            <span title={full_id.clone()} style="word-break: break-all;">
+                if *revealed {
+                    { full_id.clone() }
+                } else {
+                    { &full_id[..8] }
+                }
+            </span>
+            <button
+                {onclick}
+                title={strings.copy_full_id.clone()}
+                style="
+                    padding: 2px 6px;
+                    background: #e5e7eb;
+                    color: #374151;
+                    border: none;
+                    border-radius: 3px;
+                    cursor: pointer;
+                    font-size: 11px;
+                "
+            >
+                { if *revealed { strings.copied.clone() } else { strings.copy.clone() } }
+            </button>
+        </div>
+    }
+}
+
+/// Render the "Wrap in container" action
+fn render_wrap_ui(
+    registry: &WidgetRegistry,
+    widget_id: WidgetId,
+    strings: &EditorStrings,
+    on_wrap: Callback<(WidgetId, String)>,
+) -> Html {
+    let containers: Vec<(String, String)> = registry
+        .widget_types()
+        .into_iter()
+        .filter_map(|widget_type| {
+            let widget = registry.create_widget(&widget_type).ok()?;
+            widget
+                .can_have_children()
+                .then(|| (widget_type, widget.display_name().to_string()))
+        })
+        .collect();
+
+    if containers.is_empty() {
+        return html! {};
+    }
+
+    let select_ref = NodeRef::default();
+    let onclick = {
+        let select_ref = select_ref.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(select) = select_ref.cast::<web_sys::HtmlSelectElement>() {
+                on_wrap.emit((widget_id, select.value()));
+            }
+        })
+    };
+
+    html! {
+        <div style="display: flex; gap: 8px;">
+            <select
+                ref={select_ref}
+                aria-label={strings.container_type.clone()}
+                style="flex: 1; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+            >
+                { for containers.iter().map(|(widget_type, name)| html! {
+                    <option value={widget_type.clone()}>{ name }</option>
+                }) }
+            </select>
+            <button
+                {onclick}
+                style="
+                    padding: 6px 12px;
+                    background: #f3f4f6;
+                    color: #374151;
+                    border: 1px solid #e5e7eb;
+                    border-radius: 4px;
+                    cursor: pointer;
+                    font-size: 13px;
+                "
+            >
+                { strings.wrap.clone() }
+            </button>
+        </div>
+    }
+}
+
+/// Render the "Convert to…" dropdown listing the types a widget can become
+fn render_convert_ui(
+    registry: &WidgetRegistry,
+    widget_id: WidgetId,
+    node: &LayoutNode,
+    strings: &EditorStrings,
+    on_convert: Callback<(WidgetId, String)>,
+) -> Html {
+    let current_type = &node.config.widget_type;
+    let has_children = !node.children.is_empty();
+    let targets: Vec<(String, String)> = registry
+        .widget_types()
+        .into_iter()
+        .filter(|widget_type| widget_type != current_type)
+        .filter_map(|widget_type| {
+            let widget = registry.create_widget(&widget_type).ok()?;
+            (!has_children || widget.can_have_children())
+                .then(|| (widget_type, widget.display_name().to_string()))
+        })
+        .collect();
+
+    if targets.is_empty() {
+        return html! {};
+    }
+
+    let onchange = Callback::from(move |e: Event| {
+        if let Some(select) = e
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::HtmlSelectElement>().ok())
+        {
+            let new_type = select.value();
+            if !new_type.is_empty() {
+                on_convert.emit((widget_id, new_type));
+            }
+        }
+    });
+
+    html! {
+        <select
+            key={current_type.clone()}
+            {onchange}
+            aria-label={strings.convert_to.clone()}
+            title={strings.convert_tooltip.clone()}
+            style="margin-top: 8px; width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+        >
+            <option value="" selected=true disabled=true>{ strings.convert_to.clone() }</option>
+            { for targets.iter().map(|(widget_type, name)| html! {
+                <option value={widget_type.clone()}>{ name }</option>
+            }) }
+        </select>
+    }
+}
+
+/// Inline styles and CSS classes copied from a widget, ready to paste onto another
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CopiedStyles {
+    pub inline_styles: IndexMap<String, String>,
+    pub css_classes: Vec<String>,
+}
+
+impl CopiedStyles {
+    /// Copy the styles and classes of a widget configuration
+    pub fn from_config(config: &WidgetConfig) -> Self {
+        Self {
+            inline_styles: config.inline_styles.clone(),
+            css_classes: config.css_classes.clone(),
+        }
+    }
+
+    /// Merge into `config`: copied styles replace same-named ones, missing classes are added
+    pub fn apply_to(&self, config: &mut WidgetConfig) {
+        for (property, value) in &self.inline_styles {
+            config.inline_styles.insert(property.clone(), value.clone());
+        }
+        for class in &self.css_classes {
+            if !config.css_classes.contains(class) {
+                config.css_classes.push(class.clone());
+            }
+        }
+    }
+}
+
+/// Render the copy/paste styles buttons
+fn render_style_clipboard_ui(
+    config: &WidgetConfig,
+    copied_styles: Option<&CopiedStyles>,
+    is_locked: bool,
+    strings: &EditorStrings,
+    on_copy: Callback<CopiedStyles>,
+    on_change: Callback<WidgetConfig>,
+) -> Html {
+    let on_copy_click = {
+        let config = config.clone();
+        Callback::from(move |_: MouseEvent| on_copy.emit(CopiedStyles::from_config(&config)))
+    };
+    let on_paste_click = {
+        let config = config.clone();
+        let copied_styles = copied_styles.cloned();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(copied) = &copied_styles {
+                let mut new_config = config.clone();
+                copied.apply_to(&mut new_config);
+                on_change.emit(new_config);
+            }
+        })
+    };
+    let button_style = "
+        flex: 1;
+        padding: 6px 12px;
+        background: #f3f4f6;
+        color: #374151;
+        border: 1px solid #e5e7eb;
+        border-radius: 4px;
+        cursor: pointer;
+        font-size: 13px;
+    ";
+
+    html! {
+        <div class="wysiwyg-style-clipboard" style="display: flex; gap: 8px; margin-top: 12px;">
+            <button
+                onclick={on_copy_click}
+                title={strings.copy_styles_tooltip.clone()}
+                style={button_style}
+            >
+                { strings.copy_styles.clone() }
+            </button>
+            <button
+                onclick={on_paste_click}
+                disabled={is_locked || copied_styles.is_none()}
+                title={strings.paste_styles_tooltip.clone()}
+                style={button_style}
+            >
+                { strings.paste_styles.clone() }
+            </button>
+        </div>
+    }
+}
+
 /// Properties for the ConfigPanel component
 #[derive(Properties, PartialEq)]
 pub struct ConfigPanelProps {
@@ -43,16 +1038,123 @@ pub struct ConfigPanelProps {
     pub selected_widget: Option<WidgetId>,
     pub on_config_change: Callback<(WidgetId, WidgetConfig)>,
     pub on_widget_select: Callback<Option<WidgetId>>,
+    pub on_lock_change: Callback<(WidgetId, bool)>,
+    pub on_wrap: Callback<(WidgetId, String)>,
+    pub on_unwrap: Callback<WidgetId>,
+    pub on_convert: Callback<(WidgetId, String)>,
+    pub on_metadata_change: Callback<(WidgetId, HashMap<String, serde_json::Value>)>,
+    #[prop_or_default]
+    pub on_toast: Callback<(String, ToastLevel)>,
+    /// Incremented to move focus to the first property field of the selected widget
+    #[prop_or_default]
+    pub focus_request: u32,
+    /// Shows a collapse button at the top of the panel when set
+    #[prop_or_default]
+    pub on_collapse: Option<Callback<()>>,
+    /// Panel width in pixels
+    #[prop_or(300)]
+    pub width: u32,
+    /// Design-system classes offered as checkboxes in the class editor
+    #[prop_or_default]
+    pub available_classes: Vec<String>,
+    /// Styles on the editor's style clipboard, offered by "Paste styles"
+    #[prop_or_default]
+    pub copied_styles: Option<CopiedStyles>,
+    /// Shows the copy/paste styles buttons when set; receives the copied styles
+    #[prop_or_default]
+    pub on_copy_styles: Option<Callback<CopiedStyles>>,
 }
 
 /// Configuration panel component - shows widget properties
 #[function_component(ConfigPanel)]
 pub fn config_panel(props: &ConfigPanelProps) -> Html {
+    let strings = use_editor_strings();
+    let panel_ref = use_node_ref();
+
+    // Focus the first property field when asked, falling back to showing the panel
+    {
+        let panel_ref = panel_ref.clone();
+        use_effect_with(props.focus_request, move |request| {
+            if *request > 0 {
+                if let Some(panel) = panel_ref.cast::<web_sys::Element>() {
+                    let field = panel
+                        .query_selector(
+                            ".wysiwyg-config-properties input:enabled, \
+                             .wysiwyg-config-properties select:enabled, \
+                             .wysiwyg-config-properties textarea:enabled",
+                        )
+                        .ok()
+                        .flatten();
+                    let target = field.clone().unwrap_or(panel);
+                    let options = web_sys::ScrollIntoViewOptions::new();
+                    options.set_behavior(web_sys::ScrollBehavior::Smooth);
+                    options.set_block(web_sys::ScrollLogicalPosition::Nearest);
+                    target.scroll_into_view_with_scroll_into_view_options(&options);
+                    if let Some(field) =
+                        field.and_then(|f| f.dyn_into::<web_sys::HtmlElement>().ok())
+                    {
+                        let _ = field.focus();
+                    }
+                }
+            }
+            || ()
+        });
+    }
+
+    // Breakpoint being edited in the Appearance section (`None` for base styles)
+    let breakpoint = use_state(|| None::<Breakpoint>);
+    let on_breakpoint_select = {
+        let breakpoint = breakpoint.clone();
+        Callback::from(move |selected: Option<Breakpoint>| breakpoint.set(selected))
+    };
+
+    // Whether the selected widget's inline help is expanded
+    let help_open = use_state(|| false);
+    {
+        let help_open = help_open.clone();
+        use_effect_with(props.selected_widget, move |_| {
+            help_open.set(false);
+            || ()
+        });
+    }
+    let on_toggle_help = {
+        let help_open = help_open.clone();
+        Callback::from(move |_: MouseEvent| help_open.set(!*help_open))
+    };
+
+    // Widget waiting for confirmation before its config is reset
+    let pending_reset = use_state(|| None::<WidgetId>);
+
+    let on_reset_confirm = {
+        let pending_reset = pending_reset.clone();
+        let layout = props.layout.clone();
+        let registry = props.registry.clone();
+        let on_config_change = props.on_config_change.clone();
+        Callback::from(move |_| {
+            if let Some(id) = *pending_reset {
+                if let Some(node) = layout.get_widget(&id) {
+                    if let Ok(widget) = registry.create_widget(&node.config.widget_type) {
+                        let mut config = widget.default_config();
+                        config.widget_type = node.config.widget_type.clone();
+                        on_config_change.emit((id, config));
+                    }
+                }
+            }
+            pending_reset.set(None);
+        })
+    };
+
+    let on_reset_cancel = {
+        let pending_reset = pending_reset.clone();
+        Callback::from(move |_| pending_reset.set(None))
+    };
+
     html! {
         <div
+            ref={panel_ref}
             class="wysiwyg-config-panel"
-            style="
-                width: 300px;
+            style={format!("
+                width: {}px;
                 background: #ffffff;
                 border-left: 1px solid #e5e7eb;
                 padding: 16px;
@@ -60,8 +1162,21 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                 display: flex;
                 flex-direction: column;
                 gap: 16px;
-            "
+            ", props.width)}
         >
+            if let Some(on_collapse) = &props.on_collapse {
+                <div style="display: flex; margin-bottom: -8px;">
+                    <button
+                        class="wysiwyg-panel-toggle"
+                        onclick={on_collapse.reform(|_: MouseEvent| ())}
+                        title={strings.hide_config_panel.clone()}
+                        aria-label={strings.hide_config_panel.clone()}
+                        aria-expanded="true"
+                    >
+                        { "»" }
+                    </button>
+                </div>
+            }
             {
                 if let Some(widget_id) = props.selected_widget {
                     if let Some(node) = props.layout.get_widget(&widget_id) {
@@ -75,9 +1190,45 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                 })
                             };
 
+                            let on_metadata_change = props
+                                .on_metadata_change
+                                .reform(move |metadata| (widget_id_copy, metadata));
+
+                            let help_url = widget.help_url();
+                            let help_html = widget.help_html();
+
+                            let is_locked = node.is_locked();
+                            let on_toggle_lock = {
+                                let on_lock_change = props.on_lock_change.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    on_lock_change.emit((widget_id_copy, !is_locked));
+                                })
+                            };
+
+                            let on_reset = {
+                                let pending_reset = pending_reset.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    pending_reset.set(Some(widget_id_copy));
+                                })
+                            };
+
                             // Build breadcrumb path
                             let breadcrumb_path = build_breadcrumb_path(&props.layout, &props.registry, &widget_id);
 
+                            // Grid placement controls only apply to children of a Grid container
+                            let grid_parent = node
+                                .parent
+                                .and_then(|parent_id| props.layout.get_widget(&parent_id))
+                                .filter(|parent| parent.config.widget_type == "container.grid")
+                                .map(|parent| parent.config.clone());
+                            let in_grid = grid_parent.is_some();
+                            // Flex sizing controls only apply to children of a Row container
+                            let in_row = node
+                                .parent
+                                .and_then(|parent_id| props.layout.get_widget(&parent_id))
+                                .map(|parent| parent.config.widget_type == "container.row")
+                                .unwrap_or(false);
+
                             html! {
                                 <>
                                     // Breadcrumb navigation
@@ -110,15 +1261,6 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                                                             e.stop_propagation();
                                                                             on_select.emit(Some(id_copy));
                                                                         })}
-                                                                        style="
-                                                                            background: none;
-                                                                            border: none;
-                                                                            color: #3b82f6;
-                                                                            cursor: pointer;
-                                                                            padding: 4px 8px;
-                                                                            border-radius: 4px;
-                                                                            font-size: 13px;
-                                                                        "
                                                                     >
                                                                         { name }
                                                                     </button>
@@ -141,14 +1283,77 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                     }
 
                                     <div>
-                                        <h3 style="
-                                            margin: 0 0 8px 0;
-                                            font-size: 16px;
-                                            font-weight: 600;
-                                            color: #111827;
+                                        <div style="
+                                            display: flex;
+                                            align-items: center;
+                                            justify-content: space-between;
+                                            gap: 8px;
+                                            margin-bottom: 8px;
                                         ">
-                                            { widget.display_name() }
-                                        </h3>
+                                            <h3 style="
+                                                margin: 0;
+                                                font-size: 16px;
+                                                font-weight: 600;
+                                                color: #111827;
+                                            ">
+                                                { widget.display_name() }
+                                            </h3>
+                                            <div style="display: flex; gap: 4px;">
+                                                if help_html.is_some() {
+                                                    <button
+                                                        onclick={on_toggle_help}
+                                                        aria-expanded={if *help_open { "true" } else { "false" }}
+                                                        aria-label={strings.help.clone()}
+                                                        title={strings.help.clone()}
+                                                        style={HELP_BUTTON_STYLE}
+                                                    >
+                                                        { "?" }
+                                                    </button>
+                                                } else if let Some(url) = help_url {
+                                                    <a
+                                                        href={url}
+                                                        target="_blank"
+                                                        rel="noopener noreferrer"
+                                                        aria-label={strings.help.clone()}
+                                                        title={strings.help.clone()}
+                                                        style={HELP_BUTTON_STYLE}
+                                                    >
+                                                        { "?" }
+                                                    </a>
+                                                }
+                                                <button
+                                                    onclick={on_reset}
+                                                    disabled={is_locked}
+                                                    title={strings.reset_tooltip.clone()}
+                                                    style="
+                                                        padding: 4px 8px;
+                                                        background: #f3f4f6;
+                                                        color: #374151;
+                                                        border: 1px solid #e5e7eb;
+                                                        border-radius: 4px;
+                                                        cursor: pointer;
+                                                        font-size: 12px;
+                                                    "
+                                                >
+                                                    { strings.reset.clone() }
+                                                </button>
+                                                <button
+                                                    onclick={on_toggle_lock}
+                                                    aria-pressed={if is_locked { "true" } else { "false" }}
+                                                    style="
+                                                        padding: 4px 8px;
+                                                        background: #f3f4f6;
+                                                        color: #374151;
+                                                        border: 1px solid #e5e7eb;
+                                                        border-radius: 4px;
+                                                        cursor: pointer;
+                                                        font-size: 12px;
+                                                    "
+                                                >
+                                                    { if is_locked { strings.unlock.clone() } else { strings.lock.clone() } }
+                                                </button>
+                                            </div>
+                                        </div>
                                         <p style="
                                             margin: 0;
                                             font-size: 13px;
@@ -156,6 +1361,34 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                         ">
                                             { widget.description() }
                                         </p>
+                                        if *help_open {
+                                            if let Some(help) = help_html {
+                                                <div
+                                                    class="wysiwyg-widget-help"
+                                                    style="
+                                                        margin-top: 8px;
+                                                        padding: 8px 12px;
+                                                        background: #f9fafb;
+                                                        border: 1px solid #e5e7eb;
+                                                        border-radius: 4px;
+                                                        font-size: 13px;
+                                                        color: #374151;
+                                                    "
+                                                >
+                                                    { help }
+                                                    if let Some(url) = help_url {
+                                                        <a
+                                                            href={url}
+                                                            target="_blank"
+                                                            rel="noopener noreferrer"
+                                                            style="display: inline-block; margin-top: 8px; color: #3b82f6;"
+                                                        >
+                                                            { strings.help_docs_link.clone() }
+                                                        </a>
+                                                    }
+                                                </div>
+                                            }
+                                        }
                                     </div>
 
                                     <div style="
@@ -168,11 +1401,164 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                             font-weight: 600;
                                             color: #374151;
                                         ">
-                                            { "Properties" }
+                                            { strings.properties.clone() }
                                         </h4>
-                                        { widget.render_config_ui(&config, on_change) }
+                                        // Locked widgets can't be edited until they are unlocked
+                                        <fieldset
+                                            class="wysiwyg-config-properties"
+                                            disabled={is_locked}
+                                            style="border: none; margin: 0; padding: 0; min-width: 0;"
+                                        >
+                                            { widget.render_config_ui(&config, on_change.clone()) }
+                                        </fieldset>
                                     </div>
 
+                                    <details
+                                        class="wysiwyg-appearance"
+                                        style="
+                                            border-top: 1px solid #e5e7eb;
+                                            padding-top: 16px;
+                                        "
+                                    >
+                                        <summary style="
+                                            cursor: pointer;
+                                            font-size: 14px;
+                                            font-weight: 600;
+                                            color: #374151;
+                                            margin-bottom: 12px;
+                                        ">
+                                            { strings.appearance.clone() }
+                                        </summary>
+                                        <fieldset
+                                            disabled={is_locked}
+                                            style="border: none; margin: 0; padding: 0; min-width: 0;"
+                                        >
+                                            { render_breakpoint_selector(*breakpoint, &config, &strings, on_breakpoint_select.clone()) }
+                                            { render_appearance_ui(&config, *breakpoint, &strings, on_change.clone()) }
+                                        </fieldset>
+                                        if let Some(on_copy_styles) = &props.on_copy_styles {
+                                            { render_style_clipboard_ui(
+                                                &config,
+                                                props.copied_styles.as_ref(),
+                                                is_locked,
+                                                &strings,
+                                                on_copy_styles.clone(),
+                                                on_change.clone(),
+                                            ) }
+                                        }
+                                    </details>
+
+                                    <div style="
+                                        border-top: 1px solid #e5e7eb;
+                                        padding-top: 16px;
+                                    ">
+                                        <h4 style="
+                                            margin: 0 0 12px 0;
+                                            font-size: 14px;
+                                            font-weight: 600;
+                                            color: #374151;
+                                        ">
+                                            { strings.advanced.clone() }
+                                        </h4>
+                                        <fieldset
+                                            disabled={is_locked}
+                                            style="border: none; margin: 0; padding: 0; min-width: 0;"
+                                        >
+                                            { render_visibility_ui(&config, &strings, on_change.clone()) }
+                                            <ElementIdField
+                                                key={widget_id.to_string()}
+                                                widget_id={widget_id}
+                                                config={config.clone()}
+                                                layout={props.layout.clone()}
+                                                on_change={on_change.clone()}
+                                            />
+                                            { render_classes_ui(&config, &props.available_classes, &strings, on_change.clone()) }
+                                        </fieldset>
+                                        <details class="wysiwyg-metadata" style="margin-top: 12px;">
+                                            <summary style="
+                                                cursor: pointer;
+                                                font-weight: 500;
+                                                margin-bottom: 8px;
+                                            ">
+                                                { strings.metadata.clone() }
+                                            </summary>
+                                            <fieldset
+                                                disabled={is_locked}
+                                                style="border: none; margin: 0; padding: 0; min-width: 0;"
+                                            >
+                                                <MetadataField
+                                                    key={widget_id.to_string()}
+                                                    metadata={node.metadata.clone()}
+                                                    on_change={on_metadata_change}
+                                                />
+                                            </fieldset>
+                                        </details>
+                                    </div>
+
+                                    if in_grid || in_row {
+                                        <div style="
+                                            border-top: 1px solid #e5e7eb;
+                                            padding-top: 16px;
+                                        ">
+                                            <h4 style="
+                                                margin: 0 0 12px 0;
+                                                font-size: 14px;
+                                                font-weight: 600;
+                                                color: #374151;
+                                            ">
+                                                { strings.layout.clone() }
+                                            </h4>
+                                            <fieldset
+                                                disabled={is_locked}
+                                                style="border: none; margin: 0; padding: 0; min-width: 0;"
+                                            >
+                                                if let Some(grid) = &grid_parent {
+                                                    { render_grid_item_ui(&config, grid, &strings, on_change.clone()) }
+                                                }
+                                                if in_row {
+                                                    { render_row_item_ui(&config, &strings, on_change) }
+                                                }
+                                            </fieldset>
+                                        </div>
+                                    }
+
+                                    if !is_locked {
+                                        <div style="
+                                            border-top: 1px solid #e5e7eb;
+                                            padding-top: 16px;
+                                        ">
+                                            <h4 style="
+                                                margin: 0 0 12px 0;
+                                                font-size: 14px;
+                                                font-weight: 600;
+                                                color: #374151;
+                                            ">
+                                                { strings.structure.clone() }
+                                            </h4>
+                                            { render_wrap_ui(&props.registry, widget_id, &strings, props.on_wrap.clone()) }
+                                            { render_convert_ui(&props.registry, widget_id, node, &strings, props.on_convert.clone()) }
+                                            if widget.can_have_children() {
+                                                <button
+                                                    onclick={props.on_unwrap.reform(move |_: MouseEvent| widget_id_copy)}
+                                                    title={strings.unwrap_container_tooltip.clone()}
+                                                    style="
+                                                        margin-top: 8px;
+                                                        width: 100%;
+                                                        padding: 6px 12px;
+                                                        background: #f3f4f6;
+                                                        color: #374151;
+                                                        border: 1px solid #e5e7eb;
+                                                        border-radius: 4px;
+                                                        cursor: pointer;
+                                                        font-size: 13px;
+                                                    "
+                                                >
+                                                    { strings.unwrap_container.clone() }
+                                                </button>
+                                            }
+                                        </div>
+                                    }
+
                                     <div style="
                                         border-top: 1px solid #e5e7eb;
                                         padding-top: 16px;
@@ -183,7 +1569,7 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                             font-weight: 600;
                                             color: #374151;
                                         ">
-                                            { "Widget Info" }
+                                            { strings.widget_info.clone() }
                                         </h4>
                                         <div style="
                                             font-size: 12px;
@@ -193,8 +1579,8 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                             padding: 8px;
                                             border-radius: 4px;
                                         ">
-                                            <div>{ format!("Type: {}", node.config.widget_type) }</div>
-                                            <div>{ format!("ID: {}", widget_id) }</div>
+                                            <div>{ format!("{} {}", strings.widget_type, node.config.widget_type) }</div>
+                                            <WidgetIdBadge id={widget_id} on_toast={props.on_toast.clone()} />
                                         </div>
                                     </div>
                                 </>
@@ -206,7 +1592,7 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                     text-align: center;
                                     color: #dc2626;
                                 ">
-                                    { "Unknown widget type" }
+                                    { strings.unknown_widget_type.clone() }
                                 </div>
                             }
                         }
@@ -217,7 +1603,7 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                 text-align: center;
                                 color: #dc2626;
                             ">
-                                { "Widget not found" }
+                                { strings.widget_not_found.clone() }
                             </div>
                         }
                     }
@@ -237,19 +1623,28 @@ pub fn config_panel(props: &ConfigPanelProps) -> Html {
                                 font-weight: 600;
                                 color: #6b7280;
                             ">
-                                { "No Widget Selected" }
+                                { strings.no_widget_selected.clone() }
                             </h3>
                             <p style="
                                 margin: 0;
                                 font-size: 14px;
                                 color: #9ca3af;
                             ">
-                                { "Select a widget to edit its properties" }
+                                { strings.no_widget_selected_hint.clone() }
                             </p>
                         </div>
                     }
                 }
             }
+            if pending_reset.is_some() {
+                <ConfirmDialog
+                    title={strings.reset_confirm_title.clone()}
+                    message={strings.reset_confirm_message.clone()}
+                    confirm_label={strings.reset_confirm_label.clone()}
+                    on_confirm={on_reset_confirm}
+                    on_cancel={on_reset_cancel}
+                />
+            }
         </div>
     }
 }
@@ -0,0 +1,247 @@
+//! Modal dialog helpers shared by editor components
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use super::browser_window;
+use super::strings::use_editor_strings;
+
+/// Selector matching elements that can receive keyboard focus inside a dialog
+const FOCUSABLE_SELECTOR: &str =
+    "button:not([disabled]), textarea, input, select, a[href], [tabindex]:not([tabindex='-1'])";
+
+/// Collect the focusable elements inside a container, in DOM order
+fn focusable_elements(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+/// Get the element that currently has keyboard focus
+fn active_element() -> Option<web_sys::Element> {
+    browser_window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element())
+}
+
+/// Move focus to the first focusable element inside the dialog
+fn focus_first(dialog_ref: &NodeRef) {
+    if let Some(dialog) = dialog_ref.cast::<web_sys::Element>() {
+        if let Some(first) = focusable_elements(&dialog).first() {
+            let _ = first.focus();
+        }
+    }
+}
+
+/// Keep Tab/Shift+Tab cycling within the dialog referenced by `dialog_ref`
+pub(crate) fn trap_focus(e: &KeyboardEvent, dialog_ref: &NodeRef) {
+    if e.key() != "Tab" {
+        return;
+    }
+    let Some(dialog) = dialog_ref.cast::<web_sys::Element>() else {
+        return;
+    };
+    let focusable = focusable_elements(&dialog);
+    let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+        e.prevent_default();
+        return;
+    };
+
+    let active = active_element();
+    let active_is = |el: &web_sys::HtmlElement| {
+        active
+            .as_ref()
+            .map(|a| a == el.unchecked_ref::<web_sys::Element>())
+            .unwrap_or(false)
+    };
+    let focus_inside = active
+        .as_ref()
+        .map(|a| dialog.contains(Some(a.unchecked_ref())))
+        .unwrap_or(false);
+
+    if e.shift_key() && (active_is(first) || !focus_inside) {
+        e.prevent_default();
+        let _ = last.focus();
+    } else if !e.shift_key() && (active_is(last) || !focus_inside) {
+        e.prevent_default();
+        let _ = first.focus();
+    }
+}
+
+/// Move focus into a dialog while it is open and back to its opener once it closes
+#[hook]
+pub(crate) fn use_dialog_focus(open: bool, dialog_ref: NodeRef, opener_ref: NodeRef) {
+    use_effect_with(open, move |open| {
+        let open = *open;
+        if open {
+            focus_first(&dialog_ref);
+        }
+
+        // Runs when the dialog closes
+        move || {
+            if open {
+                if let Some(opener) = opener_ref.cast::<web_sys::HtmlElement>() {
+                    let _ = opener.focus();
+                }
+            }
+        }
+    });
+}
+
+/// Invoke `on_escape` when Escape is pressed while `active` is true
+///
+/// The listener runs in the capture phase and swallows the event so the
+/// editor's global keyboard shortcuts don't act on it as well.
+#[hook]
+pub(crate) fn use_escape_key(active: bool, on_escape: Callback<()>) {
+    use_effect_with(active, move |active| {
+        let callback = active.then(|| {
+            Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
+                if e.key() == "Escape" {
+                    e.prevent_default();
+                    e.stop_propagation();
+                    on_escape.emit(());
+                }
+            }) as Box<dyn FnMut(_)>)
+        });
+
+        // Listen in the capture phase so we run before bubble-phase window listeners
+        if let (Some(window), Some(callback)) = (browser_window(), callback.as_ref()) {
+            let _ = window.add_event_listener_with_callback_and_bool(
+                "keydown",
+                callback.as_ref().unchecked_ref(),
+                true,
+            );
+        }
+
+        // Cleanup
+        move || {
+            if let (Some(window), Some(callback)) = (browser_window(), callback.as_ref()) {
+                let _ = window.remove_event_listener_with_callback_and_bool(
+                    "keydown",
+                    callback.as_ref().unchecked_ref(),
+                    true,
+                );
+            }
+            drop(callback);
+        }
+    });
+}
+
+/// Properties for the ConfirmDialog component
+#[derive(Properties, PartialEq)]
+pub struct ConfirmDialogProps {
+    pub title: AttrValue,
+    pub message: AttrValue,
+    pub confirm_label: AttrValue,
+    pub on_confirm: Callback<()>,
+    pub on_cancel: Callback<()>,
+}
+
+/// Confirmation dialog for destructive actions
+///
+/// Focus moves into the dialog when it mounts and returns to the previously
+/// focused element when it unmounts. Escape and the backdrop cancel.
+#[function_component(ConfirmDialog)]
+pub fn confirm_dialog(props: &ConfirmDialogProps) -> Html {
+    let strings = use_editor_strings();
+    let dialog_ref = use_node_ref();
+
+    use_escape_key(true, props.on_cancel.clone());
+
+    {
+        let dialog_ref = dialog_ref.clone();
+        use_effect_with((), move |_| {
+            let previous =
+                active_element().and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+            focus_first(&dialog_ref);
+
+            move || {
+                if let Some(previous) = previous {
+                    let _ = previous.focus();
+                }
+            }
+        });
+    }
+
+    let on_cancel = props.on_cancel.reform(|_: MouseEvent| ());
+    let on_confirm = props.on_confirm.reform(|_: MouseEvent| ());
+
+    html! {
+        <div
+            style="
+                position: fixed;
+                top: 0;
+                left: 0;
+                right: 0;
+                bottom: 0;
+                background: rgba(0, 0, 0, 0.5);
+                display: flex;
+                align-items: center;
+                justify-content: center;
+                z-index: 1000;
+            "
+            onclick={on_cancel.clone()}
+        >
+            <div
+                style="
+                    background: white;
+                    border-radius: 8px;
+                    padding: 24px;
+                    max-width: 400px;
+                    width: 90%;
+                    box-shadow: 0 20px 25px -5px rgba(0, 0, 0, 0.1);
+                "
+                ref={dialog_ref.clone()}
+                role="alertdialog"
+                aria-modal="true"
+                onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
+                onkeydown={Callback::from(move |e: KeyboardEvent| trap_focus(&e, &dialog_ref))}
+            >
+                <h3 style="margin: 0 0 16px 0; font-size: 18px; font-weight: 600; color: #111827;">
+                    { props.title.clone() }
+                </h3>
+                <p style="margin: 0 0 24px 0; color: #6b7280; line-height: 1.5;">
+                    { props.message.clone() }
+                </p>
+                <div style="display: flex; gap: 12px; justify-content: flex-end;">
+                    <button
+                        onclick={on_cancel}
+                        style="
+                            padding: 8px 16px;
+                            background: #f3f4f6;
+                            color: #374151;
+                            border: none;
+                            border-radius: 4px;
+                            cursor: pointer;
+                            font-size: 14px;
+                            font-weight: 500;
+                        "
+                    >
+                        { strings.cancel.clone() }
+                    </button>
+                    <button
+                        onclick={on_confirm}
+                        style="
+                            padding: 8px 16px;
+                            background: #ef4444;
+                            color: white;
+                            border: none;
+                            border-radius: 4px;
+                            cursor: pointer;
+                            font-size: 14px;
+                            font-weight: 500;
+                        "
+                    >
+                        { props.confirm_label.clone() }
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,74 @@
+//! Timeline of undo history steps
+
+use yew::prelude::*;
+
+use super::strings::{use_editor_strings, with_count};
+
+/// Properties for the HistoryTimeline component
+#[derive(Properties, PartialEq)]
+pub struct HistoryTimelineProps {
+    /// Widget count of each history entry, oldest first
+    pub widget_counts: Vec<usize>,
+    /// Index of the entry currently shown
+    pub current: usize,
+    /// Receives the index of the clicked step
+    pub on_jump: Callback<usize>,
+}
+
+/// Horizontal list of history steps with the current one highlighted
+///
+/// Steps after the current one are the ones redo would bring back; they stay
+/// clickable until the next edit discards them.
+#[function_component(HistoryTimeline)]
+pub fn history_timeline(props: &HistoryTimelineProps) -> Html {
+    let strings = use_editor_strings();
+
+    html! {
+        <nav
+            class="wysiwyg-history-timeline"
+            aria-label={strings.history.clone()}
+            style="
+                display: flex;
+                align-items: center;
+                gap: 4px;
+                padding: 6px 16px;
+                background: white;
+                border-bottom: 1px solid #e5e7eb;
+                overflow-x: auto;
+            "
+        >
+            <span style="font-size: 12px; color: #6b7280; margin-right: 4px;">
+                { strings.history.clone() }
+            </span>
+            { for props.widget_counts.iter().enumerate().map(|(index, count)| {
+                let current = index == props.current;
+                let (background, color) = if current {
+                    ("#3b82f6", "white")
+                } else if index > props.current {
+                    ("white", "#9ca3af")
+                } else {
+                    ("#f3f4f6", "#374151")
+                };
+                html! {
+                    <button
+                        onclick={props.on_jump.reform(move |_: MouseEvent| index)}
+                        aria-current={current.then_some("step")}
+                        title={with_count(&strings.widget_count_template, *count)}
+                        style={format!("
+                            min-width: 28px;
+                            padding: 2px 6px;
+                            background: {};
+                            color: {};
+                            border: 1px solid #d1d5db;
+                            border-radius: 4px;
+                            cursor: pointer;
+                            font-size: 12px;
+                        ", background, color)}
+                    >
+                        { index + 1 }
+                    </button>
+                }
+            }) }
+        </nav>
+    }
+}
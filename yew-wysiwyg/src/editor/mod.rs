@@ -2,30 +2,66 @@
 
 mod canvas;
 mod config_panel;
+mod dialog;
+mod history;
+mod outline;
 mod palette;
+mod strings;
+mod styles;
+mod toast;
 mod toolbar;
+mod touch_drag;
 
+use std::collections::HashMap;
 use std::rc::Rc;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use yew::platform::spawn_local;
 use yew::prelude::*;
 
 use crate::core::registry::WidgetRegistry;
+use crate::core::style::StyleAllowlist;
 use crate::core::theme::{DefaultTheme, Theme};
-use crate::core::widget::{WidgetConfig, WidgetId};
+use crate::core::widget::{SlotSpec, WidgetConfig, WidgetId};
 use crate::serialization::Layout;
 
-pub use canvas::Canvas;
-pub use config_panel::ConfigPanel;
+use dialog::ConfirmDialog;
+use history::HistoryTimeline;
+use strings::{with_count, with_name};
+use styles::{EditorCssInjected, EDITOR_CSS};
+use toast::{show_toast, ToastAction, ToastQueue, ToastStack};
+use touch_drag::{use_touch_drag, DragPayload, DropTarget, TouchDrag};
+
+pub use canvas::{Canvas, LayoutView};
+pub use config_panel::{ConfigPanel, CopiedStyles};
+pub use outline::OutlinePanel;
 pub use palette::WidgetPalette;
+pub use strings::EditorStrings;
+pub use toast::ToastLevel;
 pub use toolbar::Toolbar;
 
 /// Local storage key for auto-saving layouts
 const AUTOSAVE_KEY: &str = "yew-wysiwyg-autosave";
 
+/// How long edits must settle before the layout is written to local storage
+const STORAGE_SAVE_DELAY_MS: i32 = 500;
+
+/// Get the browser window, or `None` off-browser (native tests, SSR)
+///
+/// Calling into `web_sys` on a non-wasm target panics, so editor code reaches the
+/// window through this helper instead of `web_sys::window()`.
+pub(crate) fn browser_window() -> Option<web_sys::Window> {
+    if cfg!(target_arch = "wasm32") {
+        web_sys::window()
+    } else {
+        None
+    }
+}
+
 /// Load layout from local storage
 fn load_from_storage() -> Option<Layout> {
-    let window = web_sys::window()?;
+    let window = browser_window()?;
     let storage = window.local_storage().ok()??;
     let json = storage.get_item(AUTOSAVE_KEY).ok()??;
     Layout::from_json(&json).ok()
@@ -33,7 +69,7 @@ fn load_from_storage() -> Option<Layout> {
 
 /// Save layout to local storage
 fn save_to_storage(layout: &Layout) {
-    if let Some(window) = web_sys::window() {
+    if let Some(window) = browser_window() {
         if let Ok(Some(storage)) = window.local_storage() {
             if let Ok(json) = layout.to_json() {
                 let _ = storage.set_item(AUTOSAVE_KEY, &json);
@@ -44,13 +80,82 @@ fn save_to_storage(layout: &Layout) {
 
 /// Clear layout from local storage
 fn clear_storage() {
-    if let Some(window) = web_sys::window() {
+    if let Some(window) = browser_window() {
         if let Ok(Some(storage)) = window.local_storage() {
             let _ = storage.remove_item(AUTOSAVE_KEY);
         }
     }
 }
 
+/// Copy text to the system clipboard; `on_done` receives whether it worked once
+/// the browser has settled the write
+pub(crate) fn copy_to_clipboard(text: &str, on_done: Callback<bool>) {
+    let Some(window) = browser_window() else {
+        on_done.emit(false);
+        return;
+    };
+    let write = JsFuture::from(window.navigator().clipboard().write_text(text));
+    spawn_local(async move { on_done.emit(write.await.is_ok()) });
+}
+
+/// Narrow strip shown in place of a collapsed side panel, with a button to expand it
+fn render_collapsed_panel(
+    label: &AttrValue,
+    icon: &str,
+    border: &str,
+    on_expand: Callback<()>,
+) -> Html {
+    html! {
+        <div
+            style={format!("
+                width: 32px;
+                flex-shrink: 0;
+                display: flex;
+                justify-content: center;
+                padding-top: 16px;
+                background: #ffffff;
+                {}: 1px solid #e5e7eb;
+            ", border)}
+        >
+            <button
+                class="wysiwyg-panel-toggle"
+                onclick={on_expand.reform(|_: MouseEvent| ())}
+                title={label.clone()}
+                aria-label={label.clone()}
+                aria-expanded="false"
+                style="height: fit-content;"
+            >
+                { icon }
+            </button>
+        </div>
+    }
+}
+
+/// Whether an event target is a text field, where native paste must be kept
+fn is_text_entry_target(target: Option<web_sys::EventTarget>) -> bool {
+    let Some(element) = target.and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok()) else {
+        return false;
+    };
+    matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+        || element.is_content_editable()
+}
+
+/// Look up the child slots of a widget in the layout
+fn widget_slots(
+    layout: &Layout,
+    registry: &WidgetRegistry,
+    id: &WidgetId,
+) -> Option<Vec<SlotSpec>> {
+    let node = layout.get_widget(id)?;
+    registry
+        .create_widget(&node.config.widget_type)
+        .ok()?
+        .child_slots()
+}
+
+/// Change to a layout that may fail, applied by the editor's `latest_edit`
+type LayoutEdit = Box<dyn FnOnce(&mut Layout) -> crate::Result<()>>;
+
 /// Properties for the Editor component
 #[derive(Properties)]
 pub struct EditorProps {
@@ -66,14 +171,96 @@ pub struct EditorProps {
     #[prop_or_default]
     pub theme: Option<Rc<dyn Theme>>,
 
+    /// Themes to switch between from a toolbar dropdown, starting with the first
+    ///
+    /// Switching re-applies the theme's CSS variables without remounting the
+    /// editor. Takes precedence over `theme` when not empty.
+    #[prop_or_default]
+    pub themes: Vec<Rc<dyn Theme>>,
+
+    /// Layout to display, making the editor controlled
+    ///
+    /// When set, the editor renders this layout and never changes it itself;
+    /// every edit (including undo, redo, and clear) is reported through
+    /// `on_layout_change` for the parent to apply. `initial_layout` and
+    /// localStorage are ignored in this mode.
+    #[prop_or_default]
+    pub value: Option<Layout>,
+
     /// Callback when layout changes
     #[prop_or_default]
     pub on_layout_change: Option<Callback<Layout>>,
 
+    /// Selected widget, for controlling the selection from the host
+    ///
+    /// When set, the editor shows this selection (`Some(None)` for none) and
+    /// reports selection changes through `on_selection_change` without applying
+    /// them, like `value` does for the layout. An id that isn't in the layout
+    /// selects nothing. When `None`, the editor manages the selection itself.
+    #[prop_or_default]
+    pub selected: Option<Option<WidgetId>>,
+
+    /// Callback fired with the selected widget whenever the selection changes
+    #[prop_or_default]
+    pub on_selection_change: Option<Callback<Option<WidgetId>>>,
+
+    /// Debounced callback fired once edits have settled
+    ///
+    /// Unlike `on_layout_change`, which fires on every change, this fires at most
+    /// once per `autosave_delay_ms` of inactivity. Suited to saving to a server.
+    #[prop_or_default]
+    pub on_autosave: Option<Callback<Layout>>,
+
+    /// Delay in milliseconds after the last change before `on_autosave` fires
+    #[prop_or(1000)]
+    pub autosave_delay_ms: u32,
+
+    /// Callback receiving a snapshot of the layout from the toolbar's "Save version" button
+    ///
+    /// The button is only shown when this is set. Unlike undo history, snapshots
+    /// are kept by the host, which can add a label and timestamp. To restore a
+    /// version, pass its layout back in as a new import: as `value` in
+    /// controlled mode, or as JSON through the Import/Export dialog.
+    #[prop_or_default]
+    pub on_snapshot: Option<Callback<Layout>>,
+
+    /// Callback fired when the layout gains or loses unsaved changes
+    ///
+    /// Any edit, undo, or redo makes the editor dirty. Bumping
+    /// `saved_revision`, or a controlled `value` replaced by a layout the editor
+    /// didn't produce, marks it clean again.
+    #[prop_or_default]
+    pub on_dirty_change: Option<Callback<bool>>,
+
+    /// Change this (e.g. increment it) after saving to mark the current layout as saved
+    #[prop_or_default]
+    pub saved_revision: u32,
+
+    /// Ask the browser to confirm leaving the page while there are unsaved changes
+    #[prop_or_default]
+    pub warn_on_unsaved_changes: bool,
+
+    /// Whether to load from and auto-save to the browser's localStorage
+    ///
+    /// Disable this for server-persisted apps or when rendering outside a browser.
+    #[prop_or(true)]
+    pub use_local_storage: bool,
+
+    /// Largest image file, in bytes, that can be dropped onto the canvas
+    ///
+    /// Dropped images become Image widgets with the file embedded as a data
+    /// URL, so they are stored in the layout JSON.
+    #[prop_or(2 * 1024 * 1024)]
+    pub max_image_drop_bytes: u32,
+
     /// Whether to show the widget palette
     #[prop_or(true)]
     pub show_palette: bool,
 
+    /// Width of the widget palette in pixels
+    #[prop_or(250)]
+    pub palette_width: u32,
+
     /// Whether to show the toolbar
     #[prop_or(true)]
     pub show_toolbar: bool,
@@ -81,30 +268,162 @@ pub struct EditorProps {
     /// Whether to show the configuration panel
     #[prop_or(true)]
     pub show_config_panel: bool,
+
+    /// Width of the configuration panel in pixels
+    #[prop_or(300)]
+    pub config_panel_width: u32,
+
+    /// Whether to show the outline (layout tree) panel
+    #[prop_or_default]
+    pub show_outline: bool,
+
+    /// Whether to show the history timeline below the toolbar in edit mode
+    ///
+    /// Lists every undo step (up to the 50 kept) so any of them can be restored
+    /// with one click.
+    #[prop_or_default]
+    pub show_history_timeline: bool,
+
+    /// Whether container outlines start out shown in edit mode
+    ///
+    /// Draws a faint dashed outline around every container so empty and
+    /// transparent containers are visible. Toggled from the toolbar.
+    #[prop_or_default]
+    pub outline_containers: bool,
+
+    /// Scroll the canvas while a dragged widget hovers near its top or bottom edge
+    #[prop_or_default]
+    pub auto_scroll_on_drag: bool,
+
+    /// Deepest nesting allowed, counting root widgets as depth 1 (unlimited if `None`)
+    ///
+    /// Drops, moves, and wraps that would nest widgets deeper are refused, and
+    /// containers at the limit show a disabled drop area.
+    #[prop_or_default]
+    pub max_depth: Option<usize>,
+
+    /// Inline style properties widgets may render (all allowed if `None`)
+    ///
+    /// Use [`StyleAllowlist::default`] for common typography, spacing, and color
+    /// properties. Declarations off the list, or with values that load resources
+    /// such as `url(...)`, are dropped when widgets render on the canvas; saved
+    /// JSON and exported source keep them. Hosts rendering layouts with
+    /// [`LayoutView`] pass the allowlist to its `style_allowlist` prop.
+    #[prop_or_default]
+    pub style_allowlist: Option<StyleAllowlist>,
+
+    /// Ask for confirmation before deleting a container that has children
+    #[prop_or_default]
+    pub confirm_delete: bool,
+
+    /// Renderer for widgets whose type is not in the registry
+    ///
+    /// When absent, unknown widgets are shown as an error box. Either way the
+    /// node's configuration is kept in the layout and survives export.
+    #[prop_or_default]
+    pub unknown_widget_fallback: Option<Callback<(WidgetId, WidgetConfig), Html>>,
+
+    /// Post-processes every widget's rendered output, e.g. to add data attributes
+    /// or wrap widgets in animation containers
+    ///
+    /// Receives the widget's ID and its output (including its children), and
+    /// returns the HTML to show in its place. In edit mode the editor's selection
+    /// chrome still wraps the result. When absent, output is used unchanged.
+    #[prop_or_default]
+    pub render_middleware: Option<Callback<(WidgetId, Html), Html>>,
+
+    /// CSS classes from the host's design system, offered as checkboxes in the
+    /// config panel's class editor alongside freeform entry
+    #[prop_or_default]
+    pub available_classes: Vec<String>,
+
+    /// Message on the placeholder shown on an empty canvas in edit mode
+    #[prop_or(AttrValue::Static(canvas::DEFAULT_EMPTY_CANVAS_MESSAGE))]
+    pub empty_canvas_message: AttrValue,
+
+    /// Message shown on an empty canvas in preview mode (nothing if `None`)
+    #[prop_or_default]
+    pub empty_preview_message: Option<AttrValue>,
+
+    /// Text of the editor chrome, for translating the editor (English by default)
+    #[prop_or_default]
+    pub strings: EditorStrings,
 }
 
 impl PartialEq for EditorProps {
     fn eq(&self, other: &Self) -> bool {
         self.initial_layout == other.initial_layout
+            && self.value == other.value
             && self.registry == other.registry
             && self.show_palette == other.show_palette
+            && self.palette_width == other.palette_width
             && self.show_toolbar == other.show_toolbar
             && self.show_config_panel == other.show_config_panel
-        // Note: We skip comparing theme and callbacks as they can't be compared easily
+            && self.config_panel_width == other.config_panel_width
+            && self.show_outline == other.show_outline
+            && self.show_history_timeline == other.show_history_timeline
+            && self.outline_containers == other.outline_containers
+            && self.auto_scroll_on_drag == other.auto_scroll_on_drag
+            && self.selected == other.selected
+            && self.autosave_delay_ms == other.autosave_delay_ms
+            && self.saved_revision == other.saved_revision
+            && self.warn_on_unsaved_changes == other.warn_on_unsaved_changes
+            && self.use_local_storage == other.use_local_storage
+            && self.confirm_delete == other.confirm_delete
+            && self.max_depth == other.max_depth
+            && self.max_image_drop_bytes == other.max_image_drop_bytes
+            && self.style_allowlist == other.style_allowlist
+            && self.unknown_widget_fallback == other.unknown_widget_fallback
+            && self.render_middleware == other.render_middleware
+            && self.available_classes == other.available_classes
+            && self.empty_canvas_message == other.empty_canvas_message
+            && self.empty_preview_message == other.empty_preview_message
+            && self.strings == other.strings
+            // The toolbar's theme picker lists these
+            && self.themes.len() == other.themes.len()
+            && self
+                .themes
+                .iter()
+                .zip(&other.themes)
+                .all(|(a, b)| a.name() == b.name() && a.config() == b.config())
+            // Whether it's set shows or hides the Save version button
+            && self.on_snapshot.is_some() == other.on_snapshot.is_some()
+        // Note: We skip comparing theme and event callbacks so inline closures don't
+        // re-render the editor on every parent render. The render callbacks above
+        // change the canvas output, so they're still compared.
     }
 }
 
 /// Main editor component
 #[function_component(Editor)]
 pub fn editor(props: &EditorProps) -> Html {
+    // Controlled editors render `value` and leave applying changes to the parent
+    let controlled = props.value.is_some();
+
     // Initialize state - try loading from localStorage if no initial layout provided
-    let layout = use_state(|| {
+    let layout_state = use_state(|| {
         props
-            .initial_layout
+            .value
             .clone()
-            .or_else(load_from_storage)
+            .or_else(|| props.initial_layout.clone())
+            .or_else(|| {
+                if props.use_local_storage && !controlled {
+                    load_from_storage()
+                } else {
+                    None
+                }
+            })
             .unwrap_or_default()
     });
+    // Edits go through the layout API, which enforces the nesting limit
+    let layout = Rc::new({
+        let mut layout = props
+            .value
+            .clone()
+            .unwrap_or_else(|| (*layout_state).clone());
+        layout.set_max_depth(props.max_depth);
+        layout
+    });
 
     let registry = use_memo(props.registry.clone(), |registry_prop| {
         registry_prop.clone().unwrap_or_else(|| {
@@ -119,24 +438,75 @@ pub fn editor(props: &EditorProps) -> Html {
         })
     });
 
+    // Shared with the editor's subcomponents through context
+    let strings = use_memo(props.strings.clone(), |strings| strings.clone());
+
+    // Shared rather than cloned into every widget's props
+    let style_allowlist = use_memo(props.style_allowlist.clone(), |allowlist| {
+        allowlist.clone().map(Rc::new)
+    });
+
+    // Index into `themes` of the theme picked from the toolbar
+    let theme_index = use_state(|| 0usize);
     let theme = props
-        .theme
-        .clone()
+        .themes
+        .get(*theme_index)
+        .or(props.themes.first())
+        .or(props.theme.as_ref())
+        .cloned()
         .unwrap_or_else(|| Rc::new(DefaultTheme::new()) as Rc<dyn Theme>);
 
-    let selected_widget = use_state(|| None::<WidgetId>);
+    // Controlled editors show the host's selection and leave applying changes to it
+    let selected_state = use_state(|| None::<WidgetId>);
+    let selected_widget = props
+        .selected
+        .map(|selected| selected.filter(|id| layout.get_widget(id).is_some()))
+        .unwrap_or(*selected_state);
+    let set_selected = {
+        let selected_state = selected_state.clone();
+        let controlled_selection = props.selected.is_some();
+        let on_selection_change = props.on_selection_change.clone();
+        Callback::from(move |id: Option<WidgetId>| {
+            if !controlled_selection {
+                selected_state.set(id);
+            } else if id != selected_widget {
+                if let Some(callback) = &on_selection_change {
+                    callback.emit(id);
+                }
+            }
+        })
+    };
     let edit_mode = use_state(|| true);
+    let outline_containers = use_state(|| props.outline_containers);
+
+    // Feedback toasts shown in the corner of the editor
+    let toast_queue = use_reducer(ToastQueue::default);
+    let toasts = toast_queue.dispatcher();
+    let on_toast = {
+        let toasts = toasts.clone();
+        Callback::from(move |(message, level): (String, ToastLevel)| {
+            show_toast(&toasts, message, level);
+        })
+    };
+    let on_toast_dismiss = {
+        let toasts = toasts.clone();
+        Callback::from(move |id: u32| toasts.dispatch(ToastAction::Dismiss(id)))
+    };
 
     // History management for undo/redo
-    let history = use_state(|| vec![props.initial_layout.clone().unwrap_or_default()]);
+    let history = use_state(|| vec![(*layout_state).clone()]);
     let history_index = use_state(|| 0usize);
+    // Whether there are edits since the layout was loaded or last marked saved
+    let dirty = use_state_eq(|| false);
 
     // Helper function to add a layout to history
     let push_to_history = {
         let history = history.clone();
         let history_index = history_index.clone();
-        let layout = layout.clone();
+        let layout_state = layout_state.clone();
+        let dirty = dirty.clone();
         move |new_layout: Layout| {
+            dirty.set(true);
             let mut hist = (*history).clone();
             let idx = *history_index;
 
@@ -154,40 +524,72 @@ pub fn editor(props: &EditorProps) -> Html {
             }
 
             history.set(hist);
-            layout.set(new_layout);
+            if !controlled {
+                layout_state.set(new_layout);
+            }
         }
     };
 
-    // Undo/Redo callbacks
-    let on_undo = {
+    // Restore the history entry at an index; undo, redo, and the timeline go through this
+    let on_history_jump = {
         let history = history.clone();
         let history_index = history_index.clone();
-        let layout = layout.clone();
-        Callback::from(move |_| {
-            let idx = *history_index;
-            if idx > 0 {
-                let new_idx = idx - 1;
+        let layout_state = layout_state.clone();
+        let dirty = dirty.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(move |new_idx: usize| {
+            if new_idx == *history_index {
+                return;
+            }
+            if let Some(target_layout) = (*history).get(new_idx) {
+                dirty.set(true);
                 history_index.set(new_idx);
-                if let Some(prev_layout) = (*history).get(new_idx) {
-                    layout.set(prev_layout.clone());
+                if !controlled {
+                    layout_state.set(target_layout.clone());
+                }
+                if let Some(callback) = &on_layout_change {
+                    callback.emit(target_layout.clone());
                 }
             }
         })
     };
 
+    // Undo/Redo callbacks
+    let on_undo = {
+        let history_index = history_index.clone();
+        let on_history_jump = on_history_jump.clone();
+        Callback::from(move |_| {
+            if let Some(new_idx) = history_index.checked_sub(1) {
+                on_history_jump.emit(new_idx);
+            }
+        })
+    };
+
     let on_redo = {
-        let history = history.clone();
         let history_index = history_index.clone();
+        let on_history_jump = on_history_jump.clone();
+        Callback::from(move |_| on_history_jump.emit(*history_index + 1))
+    };
+
+    // Applies an edit to the layout of the latest render, for work that finishes
+    // after later renders (such as reading a dropped file) and so can't use the
+    // layout and history captured when it started
+    let latest_edit = use_mut_ref(Callback::<LayoutEdit>::noop);
+    *latest_edit.borrow_mut() = {
+        let push_to_history = push_to_history.clone();
         let layout = layout.clone();
-        Callback::from(move |_| {
-            let idx = *history_index;
-            let hist = (*history).clone();
-            if idx < hist.len() - 1 {
-                let new_idx = idx + 1;
-                history_index.set(new_idx);
-                if let Some(next_layout) = hist.get(new_idx) {
-                    layout.set(next_layout.clone());
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(move |edit: LayoutEdit| {
+            let mut new_layout = (*layout).clone();
+            match edit(&mut new_layout) {
+                Ok(()) => {
+                    push_to_history(new_layout.clone());
+                    if let Some(callback) = &on_layout_change {
+                        callback.emit(new_layout);
+                    }
                 }
+                Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
             }
         })
     };
@@ -199,21 +601,25 @@ pub fn editor(props: &EditorProps) -> Html {
     let on_add_widget = {
         let push_to_history = push_to_history.clone();
         let layout = layout.clone();
-        let selected_widget = selected_widget.clone();
         let registry = registry.clone();
+        let toasts = toasts.clone();
         let on_layout_change = props.on_layout_change.clone();
         Callback::from(move |(_widget_type, config): (String, WidgetConfig)| {
             let mut new_layout = (*layout).clone();
             let id = WidgetId::new_v4();
 
             // Check if a container widget is selected
-            let add_as_child = if let Some(parent_id) = *selected_widget {
-                // Check if parent can have children
-                if let Some(parent_node) = new_layout.get_widget(&parent_id) {
+            let add_as_child = if let Some(parent_id) = selected_widget {
+                // Check if parent can have children. Slotted containers are only
+                // filled by dropping into a slot, so they count as leaves here.
+                if let Some(parent_node) = new_layout
+                    .get_widget(&parent_id)
+                    .filter(|node| !node.is_locked())
+                {
                     if let Ok(parent_widget) =
                         registry.create_widget(&parent_node.config.widget_type)
                     {
-                        parent_widget.can_have_children()
+                        parent_widget.can_have_children() && parent_widget.child_slots().is_none()
                     } else {
                         false
                     }
@@ -226,12 +632,15 @@ pub fn editor(props: &EditorProps) -> Html {
 
             if add_as_child {
                 // Add as child of selected container
-                if let Some(parent_id) = *selected_widget {
-                    if new_layout.add_child_widget(parent_id, id, config).is_ok() {
-                        push_to_history(new_layout.clone());
-                        if let Some(callback) = &on_layout_change {
-                            callback.emit(new_layout);
+                if let Some(parent_id) = selected_widget {
+                    match new_layout.add_child_widget(parent_id, id, config) {
+                        Ok(()) => {
+                            push_to_history(new_layout.clone());
+                            if let Some(callback) = &on_layout_change {
+                                callback.emit(new_layout);
+                            }
                         }
+                        Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
                     }
                 }
             } else {
@@ -246,38 +655,135 @@ pub fn editor(props: &EditorProps) -> Html {
     };
 
     let on_widget_select = {
-        let selected_widget = selected_widget.clone();
+        let set_selected = set_selected.clone();
         Callback::from(move |id: Option<WidgetId>| {
-            selected_widget.set(id);
+            set_selected.emit(id);
         })
     };
 
-    let on_widget_delete = {
+    // Bumped to have the config panel focus the selected widget's first field
+    let config_focus_request = use_state(|| 0u32);
+
+    // Side panels collapsed to a narrow strip, giving the canvas their space
+    let palette_collapsed = use_state(|| false);
+    let config_panel_collapsed = use_state(|| false);
+
+    let set_palette_collapsed = |collapsed: bool| {
+        let palette_collapsed = palette_collapsed.clone();
+        Callback::from(move |_: ()| palette_collapsed.set(collapsed))
+    };
+    let set_config_panel_collapsed = |collapsed: bool| {
+        let config_panel_collapsed = config_panel_collapsed.clone();
+        Callback::from(move |_: ()| config_panel_collapsed.set(collapsed))
+    };
+
+    let on_widget_open = {
+        let set_selected = set_selected.clone();
+        let config_focus_request = config_focus_request.clone();
+        let config_panel_collapsed = config_panel_collapsed.clone();
+        Callback::from(move |id: WidgetId| {
+            set_selected.emit(Some(id));
+            config_panel_collapsed.set(false);
+            config_focus_request.set(config_focus_request.wrapping_add(1).max(1));
+        })
+    };
+
+    // Container pending deletion and the number of descendants it would remove
+    let pending_delete = use_state(|| None::<(WidgetId, usize)>);
+
+    let delete_widget = {
         let push_to_history = push_to_history.clone();
         let layout = layout.clone();
-        let selected_widget = selected_widget.clone();
+        let set_selected = set_selected.clone();
+        let toasts = toasts.clone();
         let on_layout_change = props.on_layout_change.clone();
         Callback::from(move |id: WidgetId| {
             let mut new_layout = (*layout).clone();
-            if new_layout.remove_widget(&id).is_ok() {
-                push_to_history(new_layout.clone());
-                selected_widget.set(None);
+            match new_layout.remove_widget(&id) {
+                Ok(()) => {
+                    push_to_history(new_layout.clone());
+                    set_selected.emit(None);
 
-                if let Some(callback) = &on_layout_change {
-                    callback.emit(new_layout);
+                    if let Some(callback) = &on_layout_change {
+                        callback.emit(new_layout);
+                    }
                 }
+                Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
             }
         })
     };
 
+    let on_widget_delete = {
+        let delete_widget = delete_widget.clone();
+        let layout = layout.clone();
+        let pending_delete = pending_delete.clone();
+        let confirm_delete = props.confirm_delete;
+        Callback::from(move |id: WidgetId| {
+            let descendant_count = layout.descendants(&id).len();
+            if confirm_delete && descendant_count > 0 {
+                pending_delete.set(Some((id, descendant_count)));
+            } else {
+                delete_widget.emit(id);
+            }
+        })
+    };
+
+    let on_delete_confirm = {
+        let pending_delete = pending_delete.clone();
+        Callback::from(move |_| {
+            if let Some((id, _)) = *pending_delete {
+                delete_widget.emit(id);
+            }
+            pending_delete.set(None);
+        })
+    };
+
+    let on_delete_cancel = {
+        let pending_delete = pending_delete.clone();
+        Callback::from(move |_| {
+            pending_delete.set(None);
+        })
+    };
+
     let on_config_change = {
         let push_to_history = push_to_history.clone();
         let layout = layout.clone();
+        let toasts = toasts.clone();
         let on_layout_change = props.on_layout_change.clone();
         Callback::from(move |(id, config): (WidgetId, WidgetConfig)| {
             let mut new_layout = (*layout).clone();
-            if let Some(node) = new_layout.to_serialized_mut().get_node_mut(&id) {
-                node.config = config;
+            match new_layout.set_widget_config(&id, config) {
+                Ok(()) => {
+                    push_to_history(new_layout.clone());
+
+                    if let Some(callback) = &on_layout_change {
+                        callback.emit(new_layout);
+                    }
+                }
+                Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
+            }
+        })
+    };
+
+    // Style clipboard for copying formatting between widgets
+    let copied_styles = use_state(|| None::<CopiedStyles>);
+    let on_copy_styles = {
+        let copied_styles = copied_styles.clone();
+        let toasts = toasts.clone();
+        let message = strings.styles_copied.to_string();
+        Callback::from(move |styles: CopiedStyles| {
+            copied_styles.set(Some(styles));
+            show_toast(&toasts, message.clone(), ToastLevel::Info);
+        })
+    };
+
+    let on_lock_change = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(move |(id, locked): (WidgetId, bool)| {
+            let mut new_layout = (*layout).clone();
+            if new_layout.set_locked(&id, locked).is_ok() {
                 push_to_history(new_layout.clone());
 
                 if let Some(callback) = &on_layout_change {
@@ -287,6 +793,127 @@ pub fn editor(props: &EditorProps) -> Html {
         })
     };
 
+    let on_metadata_change = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(
+            move |(id, metadata): (WidgetId, HashMap<String, serde_json::Value>)| {
+                let mut new_layout = (*layout).clone();
+                match new_layout.set_node_metadata(&id, metadata) {
+                    Ok(()) => {
+                        push_to_history(new_layout.clone());
+
+                        if let Some(callback) = &on_layout_change {
+                            callback.emit(new_layout);
+                        }
+                    }
+                    Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
+                }
+            },
+        )
+    };
+
+    let on_wrap = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let registry = registry.clone();
+        let set_selected = set_selected.clone();
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(move |(id, container_type): (WidgetId, String)| {
+            let container = match registry.create_widget(&container_type) {
+                Ok(container) => container,
+                Err(e) => {
+                    show_toast(&toasts, e.to_string(), ToastLevel::Error);
+                    return;
+                }
+            };
+            let mut new_layout = (*layout).clone();
+            match new_layout.wrap_widget(&id, container.default_config()) {
+                Ok(container_id) => {
+                    push_to_history(new_layout.clone());
+                    set_selected.emit(Some(container_id));
+
+                    if let Some(callback) = &on_layout_change {
+                        callback.emit(new_layout);
+                    }
+                }
+                Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
+            }
+        })
+    };
+
+    let on_unwrap = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let registry = registry.clone();
+        let set_selected = set_selected.clone();
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(move |id: WidgetId| {
+            let first_child = layout
+                .get_widget(&id)
+                .and_then(|node| node.children.first().copied());
+            let mut new_layout = (*layout).clone();
+            match new_layout.unwrap_container(&id, &registry) {
+                Ok(()) => {
+                    push_to_history(new_layout.clone());
+                    set_selected.emit(first_child);
+
+                    if let Some(callback) = &on_layout_change {
+                        callback.emit(new_layout);
+                    }
+                }
+                Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
+            }
+        })
+    };
+
+    let on_convert = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let registry = registry.clone();
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(move |(id, new_type): (WidgetId, String)| {
+            let mut new_layout = (*layout).clone();
+            match new_layout.convert_widget(&id, &new_type, &registry) {
+                Ok(()) => {
+                    push_to_history(new_layout.clone());
+
+                    if let Some(callback) = &on_layout_change {
+                        callback.emit(new_layout);
+                    }
+                }
+                Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
+            }
+        })
+    };
+
+    let on_widget_duplicate = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let set_selected = set_selected.clone();
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(move |id: WidgetId| {
+            let mut new_layout = (*layout).clone();
+            match new_layout.duplicate_widget(&id) {
+                Ok(copy_id) => {
+                    push_to_history(new_layout.clone());
+                    set_selected.emit(Some(copy_id));
+
+                    if let Some(callback) = &on_layout_change {
+                        callback.emit(new_layout);
+                    }
+                }
+                Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
+            }
+        })
+    };
+
     let on_widget_move_up = {
         let push_to_history = push_to_history.clone();
         let layout = layout.clone();
@@ -319,10 +946,33 @@ pub fn editor(props: &EditorProps) -> Html {
         })
     };
 
+    let on_move_widget = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(
+            move |(id, parent_id, position): (WidgetId, Option<WidgetId>, usize)| {
+                let mut new_layout = (*layout).clone();
+                match new_layout.move_widget(&id, parent_id, position) {
+                    Ok(()) => {
+                        push_to_history(new_layout.clone());
+
+                        if let Some(callback) = &on_layout_change {
+                            callback.emit(new_layout);
+                        }
+                    }
+                    Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
+                }
+            },
+        )
+    };
+
     let on_drop_widget = {
         let push_to_history = push_to_history.clone();
         let layout = layout.clone();
         let registry = registry.clone();
+        let toasts = toasts.clone();
         let on_layout_change = props.on_layout_change.clone();
         Callback::from(
             move |(widget_type, parent_id, position): (String, Option<WidgetId>, usize)| {
@@ -335,14 +985,14 @@ pub fn editor(props: &EditorProps) -> Html {
                     // Insert at the specified position
                     if let Some(parent_id) = parent_id {
                         // Insert as child
-                        if new_layout
-                            .insert_child_widget(parent_id, id, config, position)
-                            .is_ok()
-                        {
-                            push_to_history(new_layout.clone());
-                            if let Some(callback) = &on_layout_change {
-                                callback.emit(new_layout);
+                        match new_layout.insert_child_widget(parent_id, id, config, position) {
+                            Ok(()) => {
+                                push_to_history(new_layout.clone());
+                                if let Some(callback) = &on_layout_change {
+                                    callback.emit(new_layout);
+                                }
                             }
+                            Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
                         }
                     } else {
                         // Insert as root
@@ -357,57 +1007,385 @@ pub fn editor(props: &EditorProps) -> Html {
         )
     };
 
+    let on_drop_file = {
+        let layout = layout.clone();
+        let registry = registry.clone();
+        let toasts = toasts.clone();
+        let latest_edit = latest_edit.clone();
+        let strings = strings.clone();
+        let max_bytes = props.max_image_drop_bytes;
+        Callback::from(
+            move |(file, parent_id, position): (web_sys::File, Option<WidgetId>, usize)| {
+                if file.size() > f64::from(max_bytes) {
+                    show_toast(
+                        &toasts,
+                        with_count(
+                            &with_name(&strings.image_too_large_template, &file.name()),
+                            (max_bytes / 1024) as usize,
+                        ),
+                        ToastLevel::Error,
+                    );
+                    return;
+                }
+                if let Some(max_depth) = layout.max_depth() {
+                    // Checked up front too, so an oversized nest fails before the file is read
+                    if let Err(e) = layout.check_max_depth(parent_id.as_ref(), 1, max_depth) {
+                        show_toast(&toasts, e.to_string(), ToastLevel::Error);
+                        return;
+                    }
+                }
+                let Ok(widget) = registry.create_widget("basic.image") else {
+                    show_toast(
+                        &toasts,
+                        strings.image_widget_unavailable.to_string(),
+                        ToastLevel::Error,
+                    );
+                    return;
+                };
+                let Ok(reader) = web_sys::FileReader::new() else {
+                    return;
+                };
+                let read_failed = with_name(&strings.file_read_failed_template, &file.name());
+
+                let onloadend = {
+                    let toasts = toasts.clone();
+                    let latest_edit = latest_edit.clone();
+                    let reader_clone = reader.clone();
+                    let file_name = file.name();
+                    let read_failed = read_failed.clone();
+                    Closure::once_into_js(move || {
+                        let Some(src) = reader_clone.result().ok().and_then(|r| r.as_string())
+                        else {
+                            show_toast(&toasts, read_failed, ToastLevel::Error);
+                            return;
+                        };
+
+                        let mut config = widget.default_config();
+                        config.set_property("src", serde_json::json!(src));
+                        config.set_property("alt", serde_json::json!(file_name));
+                        // The layout may have changed while the file was read, so the
+                        // insert is checked again against the latest one
+                        let apply = latest_edit.borrow().clone();
+                        apply.emit(Box::new(move |layout: &mut Layout| {
+                            let id = WidgetId::new_v4();
+                            match parent_id {
+                                Some(parent_id) => {
+                                    layout.insert_child_widget(parent_id, id, config, position)
+                                }
+                                None => {
+                                    layout.insert_root_widget(id, config, position);
+                                    Ok(())
+                                }
+                            }
+                        }));
+                    })
+                };
+                reader.set_onloadend(Some(onloadend.unchecked_ref()));
+                if reader.read_as_data_url(&file).is_err() {
+                    show_toast(&toasts, read_failed, ToastLevel::Error);
+                }
+            },
+        )
+    };
+
+    let on_drop_into_slot = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let registry = registry.clone();
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(
+            move |(widget_type, parent_id, slot): (String, WidgetId, String)| {
+                let (Ok(widget), Some(slots)) = (
+                    registry.create_widget(&widget_type),
+                    widget_slots(&layout, &registry, &parent_id),
+                ) else {
+                    return;
+                };
+                let mut new_layout = (*layout).clone();
+                let id = WidgetId::new_v4();
+                match new_layout.add_widget_to_slot(
+                    parent_id,
+                    id,
+                    widget.default_config(),
+                    &slots,
+                    &slot,
+                ) {
+                    Ok(()) => {
+                        push_to_history(new_layout.clone());
+                        if let Some(callback) = &on_layout_change {
+                            callback.emit(new_layout);
+                        }
+                    }
+                    Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
+                }
+            },
+        )
+    };
+
+    let on_move_into_slot = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let registry = registry.clone();
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        Callback::from(move |(id, parent_id, slot): (WidgetId, WidgetId, String)| {
+            let Some(slots) = widget_slots(&layout, &registry, &parent_id) else {
+                return;
+            };
+            let mut new_layout = (*layout).clone();
+            match new_layout.move_widget_to_slot(&id, parent_id, &slots, &slot) {
+                Ok(()) => {
+                    push_to_history(new_layout.clone());
+                    if let Some(callback) = &on_layout_change {
+                        callback.emit(new_layout);
+                    }
+                }
+                Err(e) => show_toast(&toasts, e.to_string(), ToastLevel::Error),
+            }
+        })
+    };
+
+    // Touch drags from the palette and drag handles drop through the same handlers
+    let touch_drag = use_touch_drag({
+        let on_drop_widget = on_drop_widget.clone();
+        let on_move_widget = on_move_widget.clone();
+        let on_drop_into_slot = on_drop_into_slot.clone();
+        let on_move_into_slot = on_move_into_slot.clone();
+        Callback::from(move |(payload, target)| match (payload, target) {
+            (DragPayload::NewWidget(widget_type), DropTarget::Position(parent_id, position)) => {
+                on_drop_widget.emit((widget_type, parent_id, position))
+            }
+            (DragPayload::MoveWidget(id), DropTarget::Position(parent_id, position)) => {
+                on_move_widget.emit((id, parent_id, position))
+            }
+            (DragPayload::NewWidget(widget_type), DropTarget::Slot(parent_id, slot)) => {
+                on_drop_into_slot.emit((widget_type, parent_id, slot))
+            }
+            (DragPayload::MoveWidget(id), DropTarget::Slot(parent_id, slot)) => {
+                on_move_into_slot.emit((id, parent_id, slot))
+            }
+        })
+    });
+
     let on_import = {
         let push_to_history = push_to_history.clone();
-        let selected_widget = selected_widget.clone();
+        let set_selected = set_selected.clone();
+        let toasts = toasts.clone();
         let on_layout_change = props.on_layout_change.clone();
+        let strings = strings.clone();
         Callback::from(move |json: String| match Layout::from_json(&json) {
-            Ok(new_layout) => {
+            Ok(mut new_layout) => {
+                // Nodes that no root reaches would never be rendered
+                let pruned = new_layout.prune_orphans();
                 push_to_history(new_layout.clone());
-                selected_widget.set(None);
+                set_selected.emit(None);
+                if pruned > 0 {
+                    show_toast(
+                        &toasts,
+                        with_count(&strings.layout_imported_pruned_template, pruned),
+                        ToastLevel::Info,
+                    );
+                } else {
+                    show_toast(
+                        &toasts,
+                        strings.layout_imported.to_string(),
+                        ToastLevel::Success,
+                    );
+                }
 
                 if let Some(callback) = &on_layout_change {
                     callback.emit(new_layout);
                 }
             }
             Err(e) => {
-                log::error!("Failed to import layout: {}", e);
+                show_toast(
+                    &toasts,
+                    format!("{}: {}", strings.import_failed, e),
+                    ToastLevel::Error,
+                );
+            }
+        })
+    };
+
+    // Layout JSON pasted onto the editor, waiting for confirmation
+    let pending_paste = use_state(|| None::<String>);
+
+    let on_paste = {
+        let pending_paste = pending_paste.clone();
+        let edit_mode = edit_mode.clone();
+        Callback::from(move |e: Event| {
+            if !*edit_mode || is_text_entry_target(e.target()) {
+                return;
+            }
+            let Some(text) = e
+                .dyn_ref::<web_sys::ClipboardEvent>()
+                .and_then(|e| e.clipboard_data())
+                .and_then(|data| data.get_data("text/plain").ok())
+            else {
+                return;
+            };
+            if Layout::from_json(&text).is_ok() {
+                e.prevent_default();
+                pending_paste.set(Some(text));
+            }
+        })
+    };
+
+    let on_paste_confirm = {
+        let pending_paste = pending_paste.clone();
+        let on_import = on_import.clone();
+        Callback::from(move |_| {
+            if let Some(json) = (*pending_paste).clone() {
+                on_import.emit(json);
+            }
+            pending_paste.set(None);
+        })
+    };
+
+    let on_paste_cancel = {
+        let pending_paste = pending_paste.clone();
+        Callback::from(move |_| {
+            pending_paste.set(None);
+        })
+    };
+
+    // Starter templates whose widgets are all in the registry
+    let templates = use_memo(
+        ((*registry).clone(), strings.clone()),
+        |(registry, strings)| {
+            #[cfg(feature = "standard-widgets")]
+            {
+                crate::templates::TEMPLATES
+                    .iter()
+                    .filter(|(name, _)| {
+                        Layout::template(name).is_some_and(|template| {
+                            template.widgets_in_order().iter().all(|id| {
+                                template.get_widget(id).is_some_and(|node| {
+                                    registry.has_widget(&node.config.widget_type)
+                                })
+                            })
+                        })
+                    })
+                    .map(|(name, title)| {
+                        (AttrValue::from(*name), strings.template_title(name, title))
+                    })
+                    .collect::<Vec<_>>()
+            }
+            #[cfg(not(feature = "standard-widgets"))]
+            {
+                let _ = (registry, strings);
+                Vec::<(AttrValue, AttrValue)>::new()
+            }
+        },
+    );
+
+    let on_insert_template = {
+        let push_to_history = push_to_history.clone();
+        let layout = layout.clone();
+        let set_selected = set_selected.clone();
+        let toasts = toasts.clone();
+        let on_layout_change = props.on_layout_change.clone();
+        let message = strings.template_inserted.to_string();
+        let unknown_template = strings.unknown_template_template.to_string();
+        Callback::from(move |(name, append): (String, bool)| {
+            #[cfg(feature = "standard-widgets")]
+            let template = Layout::template(&name);
+            #[cfg(not(feature = "standard-widgets"))]
+            let template = None::<Layout>;
+            let Some(template) = template else {
+                show_toast(
+                    &toasts,
+                    with_name(&unknown_template, &name),
+                    ToastLevel::Error,
+                );
+                return;
+            };
+            // Replacing appends to an empty layout, so both are held to the nesting limit
+            let mut new_layout = if append {
+                (*layout).clone()
+            } else {
+                let mut empty = Layout::new();
+                empty.set_max_depth(layout.max_depth());
+                empty
+            };
+            if let Err(e) = new_layout.append(template) {
+                show_toast(&toasts, e.to_string(), ToastLevel::Error);
+                return;
+            }
+            let first_root = new_layout
+                .root_widgets()
+                .get(if append {
+                    layout.root_widgets().len()
+                } else {
+                    0
+                })
+                .copied();
+            push_to_history(new_layout.clone());
+            set_selected.emit(first_root);
+            show_toast(&toasts, message.clone(), ToastLevel::Success);
+
+            if let Some(callback) = &on_layout_change {
+                callback.emit(new_layout);
             }
         })
     };
 
     let on_toggle_edit_mode = {
         let edit_mode = edit_mode.clone();
-        let selected_widget = selected_widget.clone();
+        let set_selected = set_selected.clone();
         Callback::from(move |_| {
             edit_mode.set(!*edit_mode);
             // Clear selection when toggling to preview mode
             if *edit_mode {
-                selected_widget.set(None);
+                set_selected.emit(None);
             }
         })
     };
 
-    let on_clear = {
+    let on_toggle_outline_containers = {
+        let outline_containers = outline_containers.clone();
+        Callback::from(move |_| outline_containers.set(!*outline_containers))
+    };
+
+    let on_snapshot = props.on_snapshot.clone().map(|callback| {
         let layout = layout.clone();
+        let toasts = toasts.clone();
+        let message = strings.version_saved.to_string();
+        Callback::from(move |_: ()| {
+            callback.emit((*layout).clone());
+            show_toast(&toasts, message.clone(), ToastLevel::Success);
+        })
+    });
+
+    let on_clear = {
+        let layout_state = layout_state.clone();
         let history = history.clone();
         let history_index = history_index.clone();
-        let selected_widget = selected_widget.clone();
+        let set_selected = set_selected.clone();
+        let toasts = toasts.clone();
         let on_layout_change = props.on_layout_change.clone();
+        let use_local_storage = props.use_local_storage;
+        let message = strings.layout_cleared.to_string();
         Callback::from(move |_| {
-            // Clear localStorage
-            clear_storage();
+            // Clear localStorage, which controlled editors never use
+            if use_local_storage && !controlled {
+                clear_storage();
+            }
 
             // Create new empty layout
             let new_layout = Layout::new();
-            layout.set(new_layout.clone());
+            if !controlled {
+                layout_state.set(new_layout.clone());
+            }
 
             // Reset history
             history.set(vec![new_layout.clone()]);
             history_index.set(0);
 
             // Clear selection
-            selected_widget.set(None);
+            set_selected.emit(None);
+            show_toast(&toasts, message.clone(), ToastLevel::Info);
 
             // Notify parent
             if let Some(callback) = &on_layout_change {
@@ -422,11 +1400,12 @@ pub fn editor(props: &EditorProps) -> Html {
         let on_redo = on_redo.clone();
 
         use_effect(move || {
-            let callback = {
+            let window = browser_window();
+            let callback = window.as_ref().map(|window| {
                 let on_undo = on_undo.clone();
                 let on_redo = on_redo.clone();
 
-                Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
+                let callback = Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
                     // Check for Ctrl/Cmd key
                     let is_ctrl_or_cmd = e.ctrl_key() || e.meta_key();
 
@@ -445,31 +1424,210 @@ pub fn editor(props: &EditorProps) -> Html {
                             on_redo.emit(());
                         }
                     }
-                }) as Box<dyn FnMut(_)>)
-            };
+                }) as Box<dyn FnMut(_)>);
 
-            let window = web_sys::window().expect("no global window exists");
-            let _ = window
-                .add_event_listener_with_callback("keydown", callback.as_ref().unchecked_ref());
+                let _ = window
+                    .add_event_listener_with_callback("keydown", callback.as_ref().unchecked_ref());
+                callback
+            });
 
             // Cleanup
             move || {
-                let window = web_sys::window().expect("no global window exists");
+                if let (Some(window), Some(callback)) = (window, callback) {
+                    let _ = window.remove_event_listener_with_callback(
+                        "keydown",
+                        callback.as_ref().unchecked_ref(),
+                    );
+                    drop(callback);
+                }
+            }
+        });
+    }
+
+    // The host marks the layout saved by changing `saved_revision`
+    {
+        let dirty = dirty.clone();
+        use_effect_with(props.saved_revision, move |_| {
+            dirty.set(false);
+            || ()
+        });
+    }
+
+    // A controlled value the editor didn't produce is a fresh load, not an edit,
+    // so it starts a new history instead of undoing into the previous document
+    {
+        let dirty = dirty.clone();
+        let history = history.clone();
+        let history_index = history_index.clone();
+        let current = (*history).get(*history_index).cloned();
+        use_effect_with(props.value.clone(), move |value| {
+            if let Some(value) = value
+                .as_ref()
+                .filter(|value| Some(*value) != current.as_ref())
+            {
+                dirty.set(false);
+                history.set(vec![value.clone()]);
+                history_index.set(0);
+            }
+            || ()
+        });
+    }
+
+    // Report selection changes to the host, but not the initial empty selection.
+    // Controlled editors report from `set_selected` instead.
+    {
+        let is_initial = use_mut_ref(|| true);
+        let on_selection_change = props.on_selection_change.clone();
+        let controlled_selection = props.selected.is_some();
+        use_effect_with(*selected_state, move |selected| {
+            if !std::mem::replace(&mut *is_initial.borrow_mut(), false) && !controlled_selection {
+                if let Some(callback) = &on_selection_change {
+                    callback.emit(*selected);
+                }
+            }
+            || ()
+        });
+    }
+
+    // Report dirty state changes, but not the initial clean state
+    {
+        let is_initial = use_mut_ref(|| true);
+        let on_dirty_change = props.on_dirty_change.clone();
+        use_effect_with(*dirty, move |dirty| {
+            if !std::mem::replace(&mut *is_initial.borrow_mut(), false) {
+                if let Some(callback) = &on_dirty_change {
+                    callback.emit(*dirty);
+                }
+            }
+            || ()
+        });
+    }
+
+    // Leave-page confirmation while there are unsaved changes
+    use_effect_with(*dirty && props.warn_on_unsaved_changes, move |warn| {
+        let listener = browser_window().filter(|_| *warn).map(|window| {
+            let callback = Closure::wrap(Box::new(move |e: web_sys::BeforeUnloadEvent| {
+                e.prevent_default();
+                // Older browsers only prompt when a return value is set
+                e.set_return_value("");
+            }) as Box<dyn FnMut(_)>);
+            let _ = window.add_event_listener_with_callback(
+                "beforeunload",
+                callback.as_ref().unchecked_ref(),
+            );
+            (window, callback)
+        });
+
+        move || {
+            if let Some((window, callback)) = listener {
                 let _ = window.remove_event_listener_with_callback(
-                    "keydown",
+                    "beforeunload",
                     callback.as_ref().unchecked_ref(),
                 );
                 drop(callback);
             }
+        }
+    });
+
+    // Auto-save layout to localStorage once edits settle. The latest unsaved
+    // layout is kept so it can be flushed when the editor unmounts or the page
+    // is hidden before the timer fires.
+    let pending_storage_save = use_mut_ref(|| None::<Layout>);
+    {
+        let pending_storage_save = pending_storage_save.clone();
+        // A controlled editor's layout belongs to the parent, so it isn't saved here
+        let use_local_storage = props.use_local_storage && !controlled;
+        use_effect_with((*layout).clone(), move |layout| {
+            let timer = use_local_storage
+                .then(|| {
+                    *pending_storage_save.borrow_mut() = Some(layout.clone());
+                    let callback = Closure::once(move || {
+                        if let Some(layout) = pending_storage_save.borrow_mut().take() {
+                            save_to_storage(&layout);
+                        }
+                    });
+                    let window = browser_window()?;
+                    let handle = window
+                        .set_timeout_with_callback_and_timeout_and_arguments_0(
+                            callback.as_ref().unchecked_ref(),
+                            STORAGE_SAVE_DELAY_MS,
+                        )
+                        .ok()?;
+                    Some((handle, callback))
+                })
+                .flatten();
+
+            move || {
+                if let Some((handle, callback)) = timer {
+                    if let Some(window) = browser_window() {
+                        window.clear_timeout_with_handle(handle);
+                    }
+                    drop(callback);
+                }
+            }
         });
     }
+    use_effect_with((), move |_| {
+        let flush = {
+            let pending_storage_save = pending_storage_save.clone();
+            move || {
+                if let Some(layout) = pending_storage_save.borrow_mut().take() {
+                    save_to_storage(&layout);
+                }
+            }
+        };
+        let listener = browser_window().map(|window| {
+            let flush = flush.clone();
+            let callback =
+                Closure::wrap(Box::new(move |_: web_sys::Event| flush()) as Box<dyn FnMut(_)>);
+            let _ = window
+                .add_event_listener_with_callback("pagehide", callback.as_ref().unchecked_ref());
+            (window, callback)
+        });
+
+        move || {
+            if let Some((window, callback)) = listener {
+                let _ = window.remove_event_listener_with_callback(
+                    "pagehide",
+                    callback.as_ref().unchecked_ref(),
+                );
+                drop(callback);
+            }
+            flush();
+        }
+    });
 
-    // Auto-save layout to localStorage on every change
+    // Debounced autosave: each change restarts the timer, and a pending save is
+    // cancelled when the layout changes again or the editor unmounts
     {
-        let layout = (*layout).clone();
-        use_effect_with(layout.clone(), move |layout| {
-            save_to_storage(layout);
-            || ()
+        let is_initial_layout = use_mut_ref(|| true);
+        let on_autosave = props.on_autosave.clone();
+        let delay_ms = props.autosave_delay_ms;
+        use_effect_with((*layout).clone(), move |layout| {
+            // The initial layout isn't an edit, so don't report it
+            let skip = std::mem::replace(&mut *is_initial_layout.borrow_mut(), false);
+
+            let pending = on_autosave.filter(|_| !skip).and_then(|on_autosave| {
+                let layout = layout.clone();
+                let callback = Closure::once(move || on_autosave.emit(layout));
+                let window = browser_window()?;
+                let handle = window
+                    .set_timeout_with_callback_and_timeout_and_arguments_0(
+                        callback.as_ref().unchecked_ref(),
+                        delay_ms.min(i32::MAX as u32) as i32,
+                    )
+                    .ok()?;
+                Some((handle, callback))
+            });
+
+            move || {
+                if let Some((handle, callback)) = pending {
+                    if let Some(window) = browser_window() {
+                        window.clear_timeout_with_handle(handle);
+                    }
+                    drop(callback);
+                }
+            }
         });
     }
 
@@ -486,50 +1644,152 @@ pub fn editor(props: &EditorProps) -> Html {
     };
 
     html! {
-        <div class="yew-wysiwyg-editor" style={theme_style}>
-            if props.show_palette && *edit_mode {
-                <WidgetPalette
-                    registry={(*registry).clone()}
-                    on_add_widget={on_add_widget}
-                />
-            }
-            <div style="flex: 1; display: flex; flex-direction: column; overflow: hidden;">
-                if props.show_toolbar {
-                    <Toolbar
+        <ContextProvider<Rc<EditorStrings>> context={strings.clone()}>
+        <ContextProvider<TouchDrag> context={touch_drag}>
+        <ContextProvider<EditorCssInjected> context={EditorCssInjected}>
+            <div class="yew-wysiwyg-editor" style={theme_style} tabindex="-1" onpaste={on_paste}>
+                <style>{ EDITOR_CSS }</style>
+                if props.show_palette && *edit_mode {
+                    if *palette_collapsed {
+                        { render_collapsed_panel(&strings.show_palette, "»", "border-right", set_palette_collapsed(false)) }
+                    } else {
+                        <WidgetPalette
+                            registry={(*registry).clone()}
+                            on_add_widget={on_add_widget.clone()}
+                            on_collapse={set_palette_collapsed(true)}
+                            width={props.palette_width}
+                        />
+                    }
+                }
+                if props.show_outline && *edit_mode {
+                    <OutlinePanel
                         layout={(*layout).clone()}
-                        selected_widget={*selected_widget}
-                        on_import={on_import}
-                        on_clear={on_clear}
+                        registry={(*registry).clone()}
+                        selected_widget={selected_widget}
+                        on_widget_select={on_widget_select.clone()}
+                        on_move_widget={on_move_widget.clone()}
+                    />
+                }
+                <div style="flex: 1; display: flex; flex-direction: column; overflow: hidden;">
+                    if props.show_toolbar {
+                        <Toolbar
+                            layout={(*layout).clone()}
+                            selected_widget={selected_widget}
+                            on_import={on_import}
+                            on_clear={on_clear}
+                            edit_mode={*edit_mode}
+                            on_toggle_edit_mode={on_toggle_edit_mode}
+                            outline_containers={*outline_containers}
+                            on_toggle_outline_containers={on_toggle_outline_containers}
+                            on_undo={on_undo}
+                            on_redo={on_redo}
+                            can_undo={can_undo}
+                            can_redo={can_redo}
+                            on_toast={on_toast.clone()}
+                            on_snapshot={on_snapshot}
+                            themes={props.themes.iter().map(|theme| AttrValue::from(theme.name().to_string())).collect::<Vec<_>>()}
+                            selected_theme={*theme_index}
+                            on_select_theme={Callback::from(move |index| theme_index.set(index))}
+                            templates={(*templates).clone()}
+                            on_insert_template={on_insert_template}
+                        />
+                    }
+                    if props.show_history_timeline && *edit_mode {
+                        <HistoryTimeline
+                            widget_counts={history.iter().map(|entry| entry.stats().total_widgets).collect::<Vec<_>>()}
+                            current={*history_index}
+                            on_jump={on_history_jump}
+                        />
+                    }
+                    <Canvas
+                        layout={(*layout).clone()}
+                        registry={(*registry).clone()}
+                        selected_widget={selected_widget}
+                        on_widget_select={on_widget_select.clone()}
+                        on_widget_open={on_widget_open}
+                        on_widget_delete={on_widget_delete}
+                        on_widget_move_up={on_widget_move_up}
+                        on_widget_move_down={on_widget_move_down}
+                        on_widget_duplicate={on_widget_duplicate}
+                        on_config_change={on_config_change.clone()}
+                        on_drop_widget={on_drop_widget}
+                        on_move_widget={on_move_widget}
+                        on_drop_file={on_drop_file}
+                        on_drop_into_slot={on_drop_into_slot}
+                        on_move_into_slot={on_move_into_slot}
                         edit_mode={*edit_mode}
-                        on_toggle_edit_mode={on_toggle_edit_mode}
-                        on_undo={on_undo}
-                        on_redo={on_redo}
-                        can_undo={can_undo}
-                        can_redo={can_redo}
+                        outline_containers={*outline_containers}
+                        auto_scroll_on_drag={props.auto_scroll_on_drag}
+                        max_depth={props.max_depth}
+                        unknown_widget_fallback={props.unknown_widget_fallback.clone()}
+                        render_middleware={props.render_middleware.clone()}
+                        style_allowlist={(*style_allowlist).clone()}
+                        empty_message={props.empty_canvas_message.clone()}
+                        empty_preview_message={props.empty_preview_message.clone()}
+                        on_add_widget={on_add_widget}
+                    />
+                </div>
+                if props.show_config_panel && *edit_mode {
+                    if *config_panel_collapsed {
+                        { render_collapsed_panel(&strings.show_config_panel, "«", "border-left", set_config_panel_collapsed(false)) }
+                    } else {
+                        <ConfigPanel
+                            layout={(*layout).clone()}
+                            registry={(*registry).clone()}
+                            selected_widget={selected_widget}
+                            on_config_change={on_config_change.clone()}
+                            on_widget_select={on_widget_select.clone()}
+                            on_lock_change={on_lock_change}
+                            on_wrap={on_wrap}
+                            on_unwrap={on_unwrap}
+                            on_convert={on_convert}
+                            on_metadata_change={on_metadata_change}
+                            on_toast={on_toast}
+                            available_classes={props.available_classes.clone()}
+                            copied_styles={(*copied_styles).clone()}
+                            on_copy_styles={on_copy_styles}
+                            focus_request={*config_focus_request}
+                            on_collapse={set_config_panel_collapsed(true)}
+                            width={props.config_panel_width}
+                        />
+                    }
+                }
+                if let Some((_, descendant_count)) = *pending_delete {
+                    <ConfirmDialog
+                        title={strings.delete_confirm_title.clone()}
+                        message={with_count(&strings.delete_confirm_message_template, descendant_count)}
+                        confirm_label={strings.delete.clone()}
+                        on_confirm={on_delete_confirm}
+                        on_cancel={on_delete_cancel}
+                    />
+                }
+                if pending_paste.is_some() {
+                    <ConfirmDialog
+                        title={strings.paste_confirm_title.clone()}
+                        message={strings.paste_confirm_message.clone()}
+                        confirm_label={strings.paste_confirm_label.clone()}
+                        on_confirm={on_paste_confirm}
+                        on_cancel={on_paste_cancel}
                     />
                 }
-                <Canvas
-                    layout={(*layout).clone()}
-                    registry={(*registry).clone()}
-                    selected_widget={*selected_widget}
-                    on_widget_select={on_widget_select.clone()}
-                    on_widget_delete={on_widget_delete}
-                    on_widget_move_up={on_widget_move_up}
-                    on_widget_move_down={on_widget_move_down}
-                    on_config_change={on_config_change.clone()}
-                    on_drop_widget={on_drop_widget}
-                    edit_mode={*edit_mode}
-                />
+                <ToastStack toasts={toast_queue.toasts.clone()} on_dismiss={on_toast_dismiss} />
             </div>
-            if props.show_config_panel && *edit_mode {
-                <ConfigPanel
-                    layout={(*layout).clone()}
-                    registry={(*registry).clone()}
-                    selected_widget={*selected_widget}
-                    on_config_change={on_config_change.clone()}
-                    on_widget_select={on_widget_select.clone()}
-                />
-            }
-        </div>
+        </ContextProvider<EditorCssInjected>>
+        </ContextProvider<TouchDrag>>
+        </ContextProvider<Rc<EditorStrings>>>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_helpers_off_browser() {
+        // Outside a browser the helpers are no-ops instead of panicking
+        assert!(browser_window().is_none());
+        save_to_storage(&Layout::new());
+        assert!(load_from_storage().is_none());
+        clear_storage();
     }
 }
@@ -0,0 +1,403 @@
+//! Outline panel showing the layout as a tree
+
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use super::strings::{use_editor_strings, EditorStrings};
+use crate::core::registry::WidgetRegistry;
+use crate::core::widget::WidgetId;
+use crate::serialization::Layout;
+
+/// Where a dragged row would land relative to the row under the pointer
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DropPlacement {
+    Before,
+    Inside,
+    After,
+}
+
+/// Drag-to-reorder state shared by the outline rows
+struct OutlineDrag {
+    /// Row being dragged
+    dragged: UseStateHandle<Option<WidgetId>>,
+    /// Row under the pointer and where the dragged row would land
+    target: UseStateHandle<Option<(WidgetId, DropPlacement)>>,
+}
+
+/// Properties for the OutlinePanel component
+#[derive(Properties, PartialEq)]
+pub struct OutlinePanelProps {
+    pub layout: Layout,
+    pub registry: WidgetRegistry,
+    pub selected_widget: Option<WidgetId>,
+    pub on_widget_select: Callback<Option<WidgetId>>,
+    /// Move a widget to (parent, position), as for canvas drops
+    #[prop_or_default]
+    pub on_move_widget: Callback<(WidgetId, Option<WidgetId>, usize)>,
+}
+
+/// Where a dragged widget may land on `target_id`, or `None` if it can't
+///
+/// Rows are split into thirds (halves for rows that don't accept children).
+/// Widgets can't be dropped onto themselves or their own descendants.
+fn drop_placement(
+    layout: &Layout,
+    registry: &WidgetRegistry,
+    dragged_id: &WidgetId,
+    target_id: &WidgetId,
+    offset_y: i32,
+    height: i32,
+) -> Option<DropPlacement> {
+    if dragged_id == target_id || layout.descendants(dragged_id).contains(target_id) {
+        return None;
+    }
+    let node = layout.get_widget(target_id)?;
+    let accepts_children = !node.is_locked()
+        && registry
+            .create_widget(&node.config.widget_type)
+            .is_ok_and(|widget| widget.can_have_children());
+
+    let height = height.max(1);
+    Some(if accepts_children {
+        if offset_y < height / 3 {
+            DropPlacement::Before
+        } else if offset_y > height * 2 / 3 {
+            DropPlacement::After
+        } else {
+            DropPlacement::Inside
+        }
+    } else if offset_y < height / 2 {
+        DropPlacement::Before
+    } else {
+        DropPlacement::After
+    })
+}
+
+/// Parent and position for moving a widget to `placement` relative to `target_id`
+fn move_destination(
+    layout: &Layout,
+    target_id: &WidgetId,
+    placement: DropPlacement,
+) -> Option<(Option<WidgetId>, usize)> {
+    let node = layout.get_widget(target_id)?;
+    if placement == DropPlacement::Inside {
+        return Some((Some(*target_id), node.children.len()));
+    }
+    let siblings = match node.parent {
+        Some(parent_id) => &layout.get_widget(&parent_id)?.children,
+        None => layout.root_widgets(),
+    };
+    let index = siblings.iter().position(|id| id == target_id)?;
+    let position = if placement == DropPlacement::After {
+        index + 1
+    } else {
+        index
+    };
+    Some((node.parent, position))
+}
+
+/// Render a node row followed by its children, indented by depth
+fn render_outline_node(
+    id: &WidgetId,
+    depth: usize,
+    props: &OutlinePanelProps,
+    drag: &OutlineDrag,
+    strings: &EditorStrings,
+) -> Html {
+    let Some(node) = props.layout.get_widget(id) else {
+        return html! {};
+    };
+
+    let (icon, name) = match props.registry.create_widget(&node.config.widget_type) {
+        Ok(widget) => (widget.icon(), widget.display_name().to_string()),
+        Err(_) => (
+            html! { <span>{ "❓" }</span> },
+            node.config.widget_type.clone(),
+        ),
+    };
+
+    let is_selected = props.selected_widget == Some(*id);
+    let id_copy = *id;
+    let on_widget_select = props.on_widget_select.clone();
+    let onclick = Callback::from(move |e: MouseEvent| {
+        e.stop_propagation();
+        on_widget_select.emit(Some(id_copy));
+    });
+
+    // Locked widgets can't be moved, but still accept drops around them
+    let is_draggable = !node.is_locked();
+    let ondragstart = {
+        let dragged = drag.dragged.clone();
+        Callback::from(move |e: DragEvent| {
+            if let Some(dt) = e.data_transfer() {
+                let _ = dt.set_data("application/widget-id", &id_copy.to_string());
+                dt.set_effect_allowed("move");
+            }
+            dragged.set(Some(id_copy));
+        })
+    };
+
+    let ondragover = {
+        let dragged = drag.dragged.clone();
+        let target = drag.target.clone();
+        let layout = props.layout.clone();
+        let registry = props.registry.clone();
+        Callback::from(move |e: DragEvent| {
+            let Some(dragged_id) = *dragged else {
+                return;
+            };
+            let height = e
+                .current_target()
+                .and_then(|row| row.dyn_into::<HtmlElement>().ok())
+                .map(|row| row.offset_height())
+                .unwrap_or(0);
+            match drop_placement(
+                &layout,
+                &registry,
+                &dragged_id,
+                &id_copy,
+                e.offset_y(),
+                height,
+            ) {
+                Some(placement) => {
+                    e.prevent_default();
+                    if *target != Some((id_copy, placement)) {
+                        target.set(Some((id_copy, placement)));
+                    }
+                }
+                None => {
+                    if target.is_some() {
+                        target.set(None);
+                    }
+                }
+            }
+        })
+    };
+
+    let ondrop = {
+        let dragged = drag.dragged.clone();
+        let target = drag.target.clone();
+        let layout = props.layout.clone();
+        let on_move_widget = props.on_move_widget.clone();
+        Callback::from(move |e: DragEvent| {
+            e.prevent_default();
+            if let (Some(dragged_id), Some((target_id, placement))) = (*dragged, *target) {
+                if let Some((parent_id, position)) =
+                    move_destination(&layout, &target_id, placement)
+                {
+                    on_move_widget.emit((dragged_id, parent_id, position));
+                }
+            }
+            dragged.set(None);
+            target.set(None);
+        })
+    };
+
+    let ondragend = {
+        let dragged = drag.dragged.clone();
+        let target = drag.target.clone();
+        Callback::from(move |_: DragEvent| {
+            dragged.set(None);
+            target.set(None);
+        })
+    };
+
+    // Insertion indicator: a line above or below the row, or a highlight for "inside"
+    let indicator = match *drag.target {
+        Some((target_id, DropPlacement::Before)) if target_id == *id => {
+            "box-shadow: inset 0 2px 0 #3b82f6;"
+        }
+        Some((target_id, DropPlacement::After)) if target_id == *id => {
+            "box-shadow: inset 0 -2px 0 #3b82f6;"
+        }
+        Some((target_id, DropPlacement::Inside)) if target_id == *id => {
+            "outline: 2px dashed #3b82f6; outline-offset: -2px;"
+        }
+        _ => "",
+    };
+    let is_dragged = *drag.dragged == Some(*id);
+
+    let style = format!(
+        "
+        display: flex;
+        align-items: center;
+        gap: 6px;
+        width: 100%;
+        padding: 4px 8px 4px {}px;
+        border: none;
+        border-radius: 4px;
+        cursor: pointer;
+        text-align: left;
+        font-size: 13px;
+        {}
+        {}
+        {}
+        ",
+        8 + depth * 16,
+        if is_selected {
+            "background: #dbeafe; color: #1e40af; font-weight: 600;"
+        } else if indicator.starts_with("outline") {
+            "background: #eff6ff; color: #374151;"
+        } else {
+            "background: none; color: #374151;"
+        },
+        indicator,
+        if is_dragged { "opacity: 0.5;" } else { "" }
+    );
+
+    html! {
+        <>
+            <button
+                {onclick}
+                {style}
+                draggable={if is_draggable { "true" } else { "false" }}
+                ondragstart={is_draggable.then_some(ondragstart)}
+                {ondragover}
+                {ondrop}
+                {ondragend}
+                role="treeitem"
+                aria-level={(depth + 1).to_string()}
+                aria-selected={if is_selected { "true" } else { "false" }}
+            >
+                // Keep drag offsets relative to the row itself
+                <span style="font-size: 14px; pointer-events: none;">{ icon }</span>
+                <span style="pointer-events: none; flex: 1; min-width: 0; white-space: nowrap; overflow: hidden; text-overflow: ellipsis;">
+                    { name }
+                </span>
+                if node.is_locked() {
+                    <span title={strings.locked.clone()} style="font-size: 11px; pointer-events: none;">{ "🔒" }</span>
+                }
+            </button>
+            { for node.children.iter().map(|child_id| render_outline_node(child_id, depth + 1, props, drag, strings)) }
+        </>
+    }
+}
+
+/// Outline panel component - shows the layout hierarchy for quick selection
+#[function_component(OutlinePanel)]
+pub fn outline_panel(props: &OutlinePanelProps) -> Html {
+    let strings = use_editor_strings();
+    let drag = OutlineDrag {
+        dragged: use_state(|| None),
+        target: use_state(|| None),
+    };
+
+    html! {
+        <div
+            class="wysiwyg-outline"
+            style="
+                width: 220px;
+                background: #ffffff;
+                border-right: 1px solid #e5e7eb;
+                padding: 16px 8px;
+                overflow-y: auto;
+            "
+        >
+            <h3 style="margin: 0 0 12px 8px; font-size: 16px; font-weight: 600;">
+                { strings.outline_title.clone() }
+            </h3>
+            if props.layout.root_widgets().is_empty() {
+                <div style="padding: 8px; color: #9ca3af; font-size: 13px;">
+                    { strings.outline_empty.clone() }
+                </div>
+            } else {
+                <div role="tree" aria-label={strings.outline_label.clone()} style="display: flex; flex-direction: column; gap: 2px;">
+                    { for props.layout.root_widgets().iter().map(|id| render_outline_node(id, 0, props, &drag, &strings)) }
+                </div>
+            }
+        </div>
+    }
+}
+
+#[cfg(all(test, feature = "standard-widgets"))]
+mod tests {
+    use super::*;
+    use crate::core::widget::WidgetConfig;
+
+    /// Root widgets `[a, row, b]` with `row` holding `[x, y]`
+    fn sample_layout() -> (Layout, [WidgetId; 5]) {
+        let ids = [(); 5].map(|_| WidgetId::new_v4());
+        let [a, row, b, x, y] = ids;
+        let mut layout = Layout::new();
+        layout.add_root_widget(a, WidgetConfig::new("text"));
+        layout.add_root_widget(row, WidgetConfig::new("container.row"));
+        layout.add_root_widget(b, WidgetConfig::new("text"));
+        layout
+            .add_child_widget(row, x, WidgetConfig::new("text"))
+            .unwrap();
+        layout
+            .add_child_widget(row, y, WidgetConfig::new("text"))
+            .unwrap();
+        (layout, ids)
+    }
+
+    #[test]
+    fn test_drop_placement() {
+        let registry = WidgetRegistry::with_standard_widgets();
+        let (mut layout, [a, row, b, x, _]) = sample_layout();
+
+        // Containers split into thirds, with the middle dropping inside
+        let placement = |layout: &Layout, target, offset_y| {
+            drop_placement(layout, &registry, &b, target, offset_y, 90)
+        };
+        assert_eq!(placement(&layout, &row, 10), Some(DropPlacement::Before));
+        assert_eq!(placement(&layout, &row, 45), Some(DropPlacement::Inside));
+        assert_eq!(placement(&layout, &row, 80), Some(DropPlacement::After));
+
+        // Other widgets split into halves
+        assert_eq!(placement(&layout, &a, 40), Some(DropPlacement::Before));
+        assert_eq!(placement(&layout, &a, 50), Some(DropPlacement::After));
+
+        // Locked containers don't take drops inside
+        layout.set_locked(&row, true).unwrap();
+        assert_eq!(placement(&layout, &row, 40), Some(DropPlacement::Before));
+        assert_eq!(placement(&layout, &row, 50), Some(DropPlacement::After));
+
+        // A widget can't land on itself or inside its own subtree
+        assert_eq!(drop_placement(&layout, &registry, &b, &b, 10, 90), None);
+        assert_eq!(drop_placement(&layout, &registry, &row, &x, 10, 90), None);
+    }
+
+    #[test]
+    fn test_move_destination() {
+        let (layout, [a, row, b, x, y]) = sample_layout();
+
+        assert_eq!(
+            move_destination(&layout, &b, DropPlacement::Before),
+            Some((None, 2))
+        );
+        assert_eq!(
+            move_destination(&layout, &b, DropPlacement::After),
+            Some((None, 3))
+        );
+        assert_eq!(
+            move_destination(&layout, &row, DropPlacement::Inside),
+            Some((Some(row), 2))
+        );
+        assert_eq!(
+            move_destination(&layout, &y, DropPlacement::Before),
+            Some((Some(row), 1))
+        );
+        assert_eq!(
+            move_destination(&layout, &WidgetId::new_v4(), DropPlacement::Before),
+            None
+        );
+
+        // Moving within the same list lands next to the target either way
+        let mut moved = layout.clone();
+        let (parent, position) = move_destination(&moved, &b, DropPlacement::After).unwrap();
+        moved.move_widget(&a, parent, position).unwrap();
+        assert_eq!(moved.root_widgets(), &[row, b, a]);
+
+        let mut moved = layout.clone();
+        let (parent, position) = move_destination(&moved, &a, DropPlacement::Before).unwrap();
+        moved.move_widget(&b, parent, position).unwrap();
+        assert_eq!(moved.root_widgets(), &[b, a, row]);
+
+        let mut moved = layout;
+        let (parent, position) = move_destination(&moved, &y, DropPlacement::After).unwrap();
+        moved.move_widget(&x, parent, position).unwrap();
+        assert_eq!(moved.get_widget(&row).unwrap().children, vec![y, x]);
+    }
+}
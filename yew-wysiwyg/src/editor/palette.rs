@@ -1,27 +1,137 @@
 //! Widget palette for selecting and adding widgets
 
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
 
+use super::browser_window;
+use super::canvas::widget_type_drag_format;
+use super::strings::{use_editor_strings, with_name, EditorStrings};
+use super::styles::use_standalone_css;
+use super::touch_drag::{DragPayload, TouchDrag};
 use crate::core::registry::WidgetRegistry;
-use crate::core::widget::WidgetConfig;
+use crate::core::widget::{WidgetConfig, WidgetId, WidgetProps};
+
+/// Delay before the hover preview appears, in milliseconds
+const PREVIEW_DELAY_MS: i32 = 400;
+
+/// Distance the pointer must travel while pressed before a palette item becomes draggable
+const DRAG_THRESHOLD_PX: i32 = 5;
+
+/// Widget preview shown while hovering a palette item
+#[derive(Clone, PartialEq)]
+struct HoverPreview {
+    widget_type: String,
+    x: i32,
+    y: i32,
+}
+
+/// Cancel a pending preview timeout, if any
+fn cancel_preview_timeout(timeout: &Rc<RefCell<Option<i32>>>) {
+    if let Some(handle) = timeout.borrow_mut().take() {
+        if let Some(window) = browser_window() {
+            window.clear_timeout_with_handle(handle);
+        }
+    }
+}
+
+/// Render a scaled-down preview of a widget with its default configuration
+fn render_preview(
+    registry: &WidgetRegistry,
+    preview: &HoverPreview,
+    strings: &EditorStrings,
+) -> Html {
+    let Ok(widget) = registry.create_widget(&preview.widget_type) else {
+        return html! {};
+    };
+
+    let props = WidgetProps {
+        id: WidgetId::nil(),
+        edit_mode: false,
+        config: widget.default_config(),
+        children: Vec::new(),
+        on_config_change: Callback::noop(),
+        on_delete: Callback::noop(),
+    };
+
+    let style = format!(
+        "
+        position: fixed;
+        left: {}px;
+        top: {}px;
+        width: 240px;
+        max-height: 200px;
+        overflow: hidden;
+        background: #ffffff;
+        border: 1px solid #e5e7eb;
+        border-radius: 8px;
+        padding: 12px;
+        box-shadow: 0 10px 15px -3px rgba(0, 0, 0, 0.1);
+        pointer-events: none;
+        z-index: 900;
+        ",
+        preview.x + 16,
+        preview.y + 8
+    );
+
+    html! {
+        <div class="wysiwyg-palette-preview" {style}>
+            <div style="font-size: 11px; color: #6b7280; margin-bottom: 8px;">
+                { format!("{}: {}", strings.preview, widget.display_name()) }
+            </div>
+            <div style="transform: scale(0.75); transform-origin: top left; width: 133%;">
+                { widget.render(&props) }
+                if widget.can_have_children() {
+                    <div style="min-height: 40px; border: 2px dashed #d1d5db; background: #fafafa; border-radius: 4px; display: flex; align-items: center; justify-content: center; color: #9ca3af; font-size: 13px;">
+                        { strings.drop_widgets_here.clone() }
+                    </div>
+                }
+            </div>
+        </div>
+    }
+}
 
 /// Properties for the WidgetPalette component
 #[derive(Properties, PartialEq)]
 pub struct WidgetPaletteProps {
     pub registry: WidgetRegistry,
     pub on_add_widget: Callback<(String, WidgetConfig)>,
+    /// Shows a collapse button in the header when set
+    #[prop_or_default]
+    pub on_collapse: Option<Callback<()>>,
+    /// Panel width in pixels
+    #[prop_or(250)]
+    pub width: u32,
 }
 
 /// Widget palette component - shows available widgets
 #[function_component(WidgetPalette)]
 pub fn widget_palette(props: &WidgetPaletteProps) -> Html {
+    let standalone_css = use_standalone_css();
+    let strings = use_editor_strings();
     let widget_types = props.registry.widget_types();
+    let preview = use_state(|| None::<HoverPreview>);
+    let preview_timeout = use_mut_ref(|| None::<i32>);
+    // Where the pointer was pressed on a palette item, until it moves far enough to drag
+    let press_origin = use_mut_ref(|| None::<(i32, i32)>);
+    // Provided by the `Editor`; a standalone palette only drags with a mouse
+    let touch_drag = use_context::<TouchDrag>();
+
+    // Don't leave a pending timeout behind when the palette unmounts
+    {
+        let preview_timeout = preview_timeout.clone();
+        use_effect_with((), move |_| {
+            move || cancel_preview_timeout(&preview_timeout)
+        });
+    }
 
     html! {
         <div
             class="wysiwyg-palette"
-            style="
-                width: 250px;
+            style={format!("
+                width: {}px;
                 background: #ffffff;
                 border-right: 1px solid #e5e7eb;
                 padding: 16px;
@@ -29,11 +139,25 @@ pub fn widget_palette(props: &WidgetPaletteProps) -> Html {
                 display: flex;
                 flex-direction: column;
                 gap: 8px;
-            "
+            ", props.width)}
         >
-            <h3 style="margin: 0 0 16px 0; font-size: 16px; font-weight: 600;">
-                { "Widgets" }
-            </h3>
+            { standalone_css }
+            <div style="display: flex; align-items: center; justify-content: space-between; margin-bottom: 16px;">
+                <h3 style="margin: 0; font-size: 16px; font-weight: 600;">
+                    { strings.palette_title.clone() }
+                </h3>
+                if let Some(on_collapse) = &props.on_collapse {
+                    <button
+                        class="wysiwyg-panel-toggle"
+                        onclick={on_collapse.reform(|_: MouseEvent| ())}
+                        title={strings.hide_palette.clone()}
+                        aria-label={strings.hide_palette.clone()}
+                        aria-expanded="true"
+                    >
+                        { "«" }
+                    </button>
+                }
+            </div>
 
             <div style="display: flex; flex-direction: column; gap: 8px;">
                 {
@@ -50,45 +174,155 @@ pub fn widget_palette(props: &WidgetPaletteProps) -> Html {
                                     on_add.emit((widget_type_clone.clone(), default_config.clone()));
                                 });
 
+                                let drag_image_ref = NodeRef::default();
                                 let widget_type_for_drag = widget_type.clone();
-                                let ondragstart = Callback::from(move |e: DragEvent| {
-                                    e.stop_propagation();
-                                    if let Some(dt) = e.data_transfer() {
-                                        let _ = dt.set_data("application/widget-type", &widget_type_for_drag);
-                                        dt.set_effect_allowed("copy");
+                                let ondragstart = {
+                                    let preview = preview.clone();
+                                    let preview_timeout = preview_timeout.clone();
+                                    let drag_image_ref = drag_image_ref.clone();
+                                    Callback::from(move |e: DragEvent| {
+                                        e.stop_propagation();
+                                        cancel_preview_timeout(&preview_timeout);
+                                        preview.set(None);
+                                        if let Some(dt) = e.data_transfer() {
+                                            let _ = dt.set_data("application/widget-type", &widget_type_for_drag);
+                                            // Lets the canvas preview the widget before the drop
+                                            let _ = dt.set_data(&widget_type_drag_format(&widget_type_for_drag), "");
+                                            dt.set_effect_allowed("copy");
+                                            if let Some(drag_image) = drag_image_ref.cast::<web_sys::Element>() {
+                                                dt.set_drag_image(&drag_image, 12, 16);
+                                            }
+                                        }
+                                    })
+                                };
+
+                                // Items only become draggable once the pointer moves past the
+                                // threshold, so a slightly shaky click still adds the widget
+                                let onpointerdown = {
+                                    let press_origin = press_origin.clone();
+                                    Callback::from(move |e: PointerEvent| {
+                                        if e.button() == 0 {
+                                            *press_origin.borrow_mut() = Some((e.client_x(), e.client_y()));
+                                        }
+                                    })
+                                };
+
+                                let onpointermove = {
+                                    let press_origin = press_origin.clone();
+                                    Callback::from(move |e: PointerEvent| {
+                                        // The release may have happened outside the item, so a move
+                                        // with no button down ends the press
+                                        if e.buttons() & 1 == 0 {
+                                            *press_origin.borrow_mut() = None;
+                                            return;
+                                        }
+                                        let Some((x, y)) = *press_origin.borrow() else {
+                                            return;
+                                        };
+                                        let (dx, dy) = (e.client_x() - x, e.client_y() - y);
+                                        if dx * dx + dy * dy > DRAG_THRESHOLD_PX * DRAG_THRESHOLD_PX {
+                                            if let Some(item) = e.current_target().and_then(|t| t.dyn_into::<web_sys::Element>().ok()) {
+                                                let _ = item.set_attribute("draggable", "true");
+                                            }
+                                            *press_origin.borrow_mut() = None;
+                                        }
+                                    })
+                                };
+
+                                let onpointerup = {
+                                    let press_origin = press_origin.clone();
+                                    Callback::from(move |e: PointerEvent| {
+                                        *press_origin.borrow_mut() = None;
+                                        if let Some(item) = e.current_target().and_then(|t| t.dyn_into::<web_sys::Element>().ok()) {
+                                            let _ = item.remove_attribute("draggable");
+                                        }
+                                    })
+                                };
+
+                                // Fired when the browser takes over the pointer (e.g. to scroll or
+                                // start the native drag), after which no pointerup arrives
+                                let onpointercancel = {
+                                    let press_origin = press_origin.clone();
+                                    Callback::from(move |_: PointerEvent| {
+                                        *press_origin.borrow_mut() = None;
+                                    })
+                                };
+
+                                let ondragend = Callback::from(|e: DragEvent| {
+                                    if let Some(item) = e.current_target().and_then(|t| t.dyn_into::<web_sys::Element>().ok()) {
+                                        let _ = item.remove_attribute("draggable");
                                     }
                                 });
 
+                                let onmouseenter = {
+                                    let preview = preview.clone();
+                                    let preview_timeout = preview_timeout.clone();
+                                    let widget_type = widget_type.clone();
+                                    // Hover styling lives in the editor stylesheet; this only schedules the preview
+                                    Callback::from(move |e: MouseEvent| {
+                                        // Show the preview after a short delay
+                                        cancel_preview_timeout(&preview_timeout);
+                                        let next = HoverPreview {
+                                            widget_type: widget_type.clone(),
+                                            x: e.client_x(),
+                                            y: e.client_y(),
+                                        };
+                                        let preview = preview.clone();
+                                        let callback = Closure::once_into_js(move || {
+                                            preview.set(Some(next));
+                                        });
+                                        if let Some(window) = browser_window() {
+                                            if let Ok(handle) = window
+                                                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                                                    callback.unchecked_ref(),
+                                                    PREVIEW_DELAY_MS,
+                                                )
+                                            {
+                                                *preview_timeout.borrow_mut() = Some(handle);
+                                            }
+                                        }
+                                    })
+                                };
+
+                                let onmouseleave = {
+                                    let preview = preview.clone();
+                                    let preview_timeout = preview_timeout.clone();
+                                    Callback::from(move |_: MouseEvent| {
+                                        cancel_preview_timeout(&preview_timeout);
+                                        preview.set(None);
+                                    })
+                                };
+
+                                let touch = touch_drag
+                                    .as_ref()
+                                    .map(|touch_drag| touch_drag.listeners(DragPayload::NewWidget(widget_type.clone())));
+
+                                // Enter/Space activate the button natively, adding the widget like a click
+                                let aria_label = format!(
+                                    "{}: {}",
+                                    with_name(&strings.add_widget_template, widget.display_name()),
+                                    widget.description()
+                                );
+
                                 html! {
+                                    <>
                                     <button
                                         {onclick}
-                                        draggable="true"
+                                        aria-label={aria_label}
+                                        title={strings.palette_item_hint.clone()}
+                                        {onpointerdown}
+                                        {onpointermove}
+                                        {onpointerup}
+                                        {onpointercancel}
                                         {ondragstart}
+                                        {ondragend}
+                                        ontouchstart={touch.as_ref().map(|touch| touch.ontouchstart.clone())}
+                                        ontouchmove={touch.as_ref().map(|touch| touch.ontouchmove.clone())}
+                                        ontouchend={touch.as_ref().map(|touch| touch.ontouchend.clone())}
+                                        ontouchcancel={touch.as_ref().map(|touch| touch.ontouchcancel.clone())}
                                         class="wysiwyg-palette-item"
-                                        style="
-                                            display: flex;
-                                            align-items: center;
-                                            gap: 8px;
-                                            padding: 12px;
-                                            background: #f9fafb;
-                                            border: 1px solid #e5e7eb;
-                                            border-radius: 6px;
-                                            cursor: pointer;
-                                            text-align: left;
-                                            transition: all 0.15s;
-                                        "
-                                        onmouseenter={Callback::from(|e: MouseEvent| {
-                                            if let Some(target) = e.target_dyn_into::<web_sys::HtmlElement>() {
-                                                let _ = target.style().set_property("background", "#f3f4f6");
-                                                let _ = target.style().set_property("border-color", "#d1d5db");
-                                            }
-                                        })}
-                                        onmouseleave={Callback::from(|e: MouseEvent| {
-                                            if let Some(target) = e.target_dyn_into::<web_sys::HtmlElement>() {
-                                                let _ = target.style().set_property("background", "#f9fafb");
-                                                let _ = target.style().set_property("border-color", "#e5e7eb");
-                                            }
-                                        })}
+                                        {onmouseenter}
+                                        {onmouseleave}
                                     >
                                         <span style="font-size: 24px;">
                                             { widget.icon() }
@@ -102,6 +336,32 @@ pub fn widget_palette(props: &WidgetPaletteProps) -> Html {
                                             </div>
                                         </div>
                                     </button>
+                                    // Offscreen pill used as the drag image for this item
+                                    <div
+                                        ref={drag_image_ref}
+                                        class="wysiwyg-palette-drag-image"
+                                        aria-hidden="true"
+                                        style="
+                                            position: fixed;
+                                            top: -1000px;
+                                            left: -1000px;
+                                            display: inline-flex;
+                                            align-items: center;
+                                            gap: 6px;
+                                            padding: 6px 12px;
+                                            background: #3b82f6;
+                                            color: white;
+                                            border-radius: 999px;
+                                            font-size: 13px;
+                                            font-weight: 500;
+                                            white-space: nowrap;
+                                            box-shadow: 0 4px 6px -1px rgba(0, 0, 0, 0.2);
+                                        "
+                                    >
+                                        { widget.icon() }
+                                        <span>{ widget.display_name() }</span>
+                                    </div>
+                                    </>
                                 }
                             }
                             Err(_) => html! {}
@@ -117,9 +377,13 @@ pub fn widget_palette(props: &WidgetPaletteProps) -> Html {
                     color: #9ca3af;
                     font-size: 14px;
                 ">
-                    { "No widgets available" }
+                    { strings.palette_empty.clone() }
                 </div>
             }
+
+            if let Some(preview) = (*preview).clone() {
+                { render_preview(&props.registry, &preview, &strings) }
+            }
         </div>
     }
 }
@@ -0,0 +1,411 @@
+//! User-facing text of the editor chrome, for localization
+
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Text of the editor chrome: toolbar labels, panel headings, canvas controls,
+/// config panel sections, dialogs, and the editor's own notifications
+///
+/// `Default` gives the built-in English. Override individual fields to translate
+/// the editor:
+///
+/// ```
+/// use yew_wysiwyg::EditorStrings;
+///
+/// let strings = EditorStrings {
+///     undo: "↶ Deshacer".into(),
+///     redo: "↷ Rehacer".into(),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Fields ending in `_template` contain a `{count}` or `{name}` placeholder (or
+/// both). Widget names, descriptions, and configuration forms (such as the Image
+/// widget's URL fields) come from the widgets themselves, and the empty canvas
+/// text is set with the `empty_canvas_message` and `empty_preview_message`
+/// editor props. Messages produced outside the editor, such as the details of
+/// layout and JSON errors appended after a translated prefix, stay as they are.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditorStrings {
+    // Toolbar
+    pub title: AttrValue,
+    pub undo: AttrValue,
+    pub undo_tooltip: AttrValue,
+    pub redo: AttrValue,
+    pub redo_tooltip: AttrValue,
+    pub import_export: AttrValue,
+    pub save_version: AttrValue,
+    pub save_version_tooltip: AttrValue,
+    pub version_saved: AttrValue,
+    pub clear: AttrValue,
+    pub clear_tooltip: AttrValue,
+    pub preview: AttrValue,
+    pub edit: AttrValue,
+    pub outline_containers: AttrValue,
+    pub outline_containers_tooltip: AttrValue,
+    pub theme_tooltip: AttrValue,
+    pub history: AttrValue,
+    pub templates: AttrValue,
+    pub templates_tooltip: AttrValue,
+    pub template_replace: AttrValue,
+    pub template_append: AttrValue,
+    pub template_inserted: AttrValue,
+    pub widget_count_template: AttrValue,
+    pub orphaned_count_template: AttrValue,
+    pub orphaned_tooltip: AttrValue,
+    pub clear_confirm_title: AttrValue,
+    pub clear_confirm_message: AttrValue,
+    pub clear_confirm_label: AttrValue,
+    pub template_replace_confirm_title: AttrValue,
+    pub template_replace_confirm_message_template: AttrValue,
+    pub template_replace_confirm_label: AttrValue,
+    pub template_hero: AttrValue,
+    pub template_contact_form: AttrValue,
+    pub template_pricing: AttrValue,
+
+    // Import/Export dialog
+    pub import_export_title: AttrValue,
+    pub import_export_hint: AttrValue,
+    pub pretty: AttrValue,
+    pub minified: AttrValue,
+    pub export_format: AttrValue,
+    pub load_from_file: AttrValue,
+    pub copy: AttrValue,
+    pub download: AttrValue,
+    pub load: AttrValue,
+    pub close: AttrValue,
+    pub json_generation_failed: AttrValue,
+    pub invalid_json: AttrValue,
+    pub file_reading_unsupported: AttrValue,
+    pub json_copied: AttrValue,
+    pub json_copy_failed: AttrValue,
+    pub json_download_failed: AttrValue,
+
+    // Palette and outline
+    pub palette_title: AttrValue,
+    pub palette_empty: AttrValue,
+    pub palette_item_hint: AttrValue,
+    pub add_widget_template: AttrValue,
+    pub outline_label: AttrValue,
+    pub outline_title: AttrValue,
+    pub outline_empty: AttrValue,
+    pub hide_palette: AttrValue,
+    pub show_palette: AttrValue,
+    pub hide_config_panel: AttrValue,
+    pub show_config_panel: AttrValue,
+
+    // Canvas controls
+    pub drag_to_move: AttrValue,
+    pub move_up: AttrValue,
+    pub move_down: AttrValue,
+    pub duplicate: AttrValue,
+    pub delete: AttrValue,
+    pub expand: AttrValue,
+    pub collapse: AttrValue,
+    pub add_child: AttrValue,
+    pub locked: AttrValue,
+    pub drop_here: AttrValue,
+    pub drop_widgets_here: AttrValue,
+    pub max_depth_reached: AttrValue,
+    pub unknown_widget_type: AttrValue,
+    pub widget_not_found: AttrValue,
+
+    // Config panel
+    pub no_widget_selected: AttrValue,
+    pub no_widget_selected_hint: AttrValue,
+    pub properties: AttrValue,
+    pub appearance: AttrValue,
+    pub advanced: AttrValue,
+    pub layout: AttrValue,
+    pub structure: AttrValue,
+    pub widget_info: AttrValue,
+    pub widget_type: AttrValue,
+    pub widget_id: AttrValue,
+    pub copy_full_id: AttrValue,
+    pub copied: AttrValue,
+    pub widget_id_copied: AttrValue,
+    pub help: AttrValue,
+    pub help_docs_link: AttrValue,
+    pub reset: AttrValue,
+    pub reset_tooltip: AttrValue,
+    pub copy_styles: AttrValue,
+    pub copy_styles_tooltip: AttrValue,
+    pub paste_styles: AttrValue,
+    pub paste_styles_tooltip: AttrValue,
+    pub styles_copied: AttrValue,
+    pub lock: AttrValue,
+    pub unlock: AttrValue,
+    pub wrap: AttrValue,
+    pub unwrap_container: AttrValue,
+    pub unwrap_container_tooltip: AttrValue,
+    pub container_type: AttrValue,
+    pub convert_to: AttrValue,
+    pub convert_tooltip: AttrValue,
+    pub css_classes: AttrValue,
+    pub css_classes_placeholder: AttrValue,
+    pub anchor_id: AttrValue,
+    pub anchor_id_placeholder: AttrValue,
+    pub anchor_id_invalid: AttrValue,
+    pub anchor_id_taken: AttrValue,
+    pub anchor_link_template: AttrValue,
+    pub visibility: AttrValue,
+    pub visibility_always: AttrValue,
+    pub visibility_preview_only: AttrValue,
+    pub visibility_edit_only: AttrValue,
+    pub metadata: AttrValue,
+    pub metadata_hint: AttrValue,
+    pub invalid_json_object: AttrValue,
+    pub column_span: AttrValue,
+    pub row_span: AttrValue,
+    pub grid_area: AttrValue,
+    pub grid_area_invalid_template: AttrValue,
+    pub grid_area_missing_template: AttrValue,
+    pub row_item_width: AttrValue,
+    pub row_item_width_placeholder: AttrValue,
+    pub fill_remaining_space: AttrValue,
+    pub breakpoint: AttrValue,
+    pub breakpoint_base: AttrValue,
+    pub breakpoint_all_sizes: AttrValue,
+    pub breakpoint_and_wider_template: AttrValue,
+    pub background: AttrValue,
+    pub text_color: AttrValue,
+    pub padding: AttrValue,
+    pub margin: AttrValue,
+    pub border: AttrValue,
+    pub border_width: AttrValue,
+    pub border_radius: AttrValue,
+    pub border_color: AttrValue,
+    pub side_top: AttrValue,
+    pub side_right: AttrValue,
+    pub side_bottom: AttrValue,
+    pub side_left: AttrValue,
+    pub no_style_value: AttrValue,
+    pub clear_style_template: AttrValue,
+    pub reset_confirm_title: AttrValue,
+    pub reset_confirm_message: AttrValue,
+    pub reset_confirm_label: AttrValue,
+
+    // Dialogs
+    pub cancel: AttrValue,
+    pub delete_confirm_title: AttrValue,
+    pub delete_confirm_message_template: AttrValue,
+    pub paste_confirm_title: AttrValue,
+    pub paste_confirm_message: AttrValue,
+    pub paste_confirm_label: AttrValue,
+
+    // Notifications
+    pub image_too_large_template: AttrValue,
+    pub image_widget_unavailable: AttrValue,
+    pub file_read_failed_template: AttrValue,
+    pub unknown_template_template: AttrValue,
+    pub layout_imported: AttrValue,
+    pub layout_imported_pruned_template: AttrValue,
+    pub import_failed: AttrValue,
+    pub layout_cleared: AttrValue,
+    pub dismiss_notification: AttrValue,
+}
+
+impl Default for EditorStrings {
+    fn default() -> Self {
+        Self {
+            title: "Page Editor".into(),
+            undo: "↶ Undo".into(),
+            undo_tooltip: "Undo (Ctrl+Z)".into(),
+            redo: "↷ Redo".into(),
+            redo_tooltip: "Redo (Ctrl+Y)".into(),
+            import_export: "Import/Export".into(),
+            save_version: "Save version".into(),
+            save_version_tooltip: "Save a snapshot of the current layout as a version".into(),
+            version_saved: "Version saved".into(),
+            clear: "Clear".into(),
+            clear_tooltip: "Clear all widgets and start fresh".into(),
+            preview: "Preview".into(),
+            edit: "Edit".into(),
+            outline_containers: "▭ Outlines".into(),
+            outline_containers_tooltip: "Outline every container to show the layout structure".into(),
+            theme_tooltip: "Preview the layout under another theme".into(),
+            history: "History".into(),
+            templates: "Templates…".into(),
+            templates_tooltip: "Start from a ready-made layout".into(),
+            template_replace: "Replace layout".into(),
+            template_append: "Add to layout".into(),
+            template_inserted: "Template added".into(),
+            widget_count_template: "{count} widgets".into(),
+            orphaned_count_template: "({count} orphaned)".into(),
+            orphaned_tooltip: "These nodes are stored in the layout but not attached to the tree, so they aren't rendered or counted".into(),
+            clear_confirm_title: "Clear All Widgets?".into(),
+            clear_confirm_message: "This will remove all widgets from the editor and clear the saved layout. This action cannot be undone.".into(),
+            clear_confirm_label: "Clear All".into(),
+            template_replace_confirm_title: "Replace Layout?".into(),
+            template_replace_confirm_message_template: "This will replace all widgets in the editor with the {name} template. Use Undo to get them back.".into(),
+            template_replace_confirm_label: "Replace".into(),
+            template_hero: "Hero Section".into(),
+            template_contact_form: "Contact Form".into(),
+            template_pricing: "Pricing Cards".into(),
+
+            import_export_title: "Import/Export Layout".into(),
+            import_export_hint: "Copy the JSON below to export, or paste JSON and click Load to import.".into(),
+            pretty: "Pretty".into(),
+            minified: "Minified".into(),
+            export_format: "Export format".into(),
+            load_from_file: "Load from file:".into(),
+            copy: "Copy".into(),
+            download: "Download".into(),
+            load: "Load".into(),
+            close: "Close".into(),
+            json_generation_failed: "Error generating JSON".into(),
+            invalid_json: "Invalid JSON".into(),
+            file_reading_unsupported: "File reading is not supported".into(),
+            json_copied: "JSON copied to clipboard".into(),
+            json_copy_failed: "Failed to copy JSON".into(),
+            json_download_failed: "Failed to download JSON".into(),
+
+            palette_title: "Widgets".into(),
+            palette_empty: "No widgets available".into(),
+            palette_item_hint: "Click or press Enter to add, or drag onto the canvas".into(),
+            add_widget_template: "Add {name}".into(),
+            outline_label: "Layout outline".into(),
+            outline_title: "Outline".into(),
+            outline_empty: "No widgets yet".into(),
+            hide_palette: "Hide widgets".into(),
+            show_palette: "Show widgets".into(),
+            hide_config_panel: "Hide properties".into(),
+            show_config_panel: "Show properties".into(),
+
+            drag_to_move: "Drag to move".into(),
+            move_up: "Move up".into(),
+            move_down: "Move down".into(),
+            duplicate: "Duplicate".into(),
+            delete: "Delete".into(),
+            expand: "Expand".into(),
+            collapse: "Collapse".into(),
+            add_child: "Add child widget".into(),
+            locked: "Locked".into(),
+            drop_here: "Drop here".into(),
+            drop_widgets_here: "Drop widgets here".into(),
+            max_depth_reached: "Maximum nesting depth reached".into(),
+            unknown_widget_type: "Unknown widget type".into(),
+            widget_not_found: "Widget not found".into(),
+
+            no_widget_selected: "No Widget Selected".into(),
+            no_widget_selected_hint: "Select a widget to edit its properties".into(),
+            properties: "Properties".into(),
+            appearance: "Appearance".into(),
+            advanced: "Advanced".into(),
+            layout: "Layout".into(),
+            structure: "Structure".into(),
+            widget_info: "Widget Info".into(),
+            widget_type: "Type:".into(),
+            widget_id: "ID:".into(),
+            copy_full_id: "Copy full ID".into(),
+            copied: "Copied".into(),
+            widget_id_copied: "Widget ID copied".into(),
+            help: "Help for this widget".into(),
+            help_docs_link: "Open documentation".into(),
+            reset: "↺ Reset".into(),
+            reset_tooltip: "Reset to default configuration".into(),
+            copy_styles: "Copy Styles".into(),
+            copy_styles_tooltip: "Copy this widget's inline styles and CSS classes".into(),
+            paste_styles: "Paste Styles".into(),
+            paste_styles_tooltip: "Merge the copied styles and classes into this widget".into(),
+            styles_copied: "Styles copied".into(),
+            lock: "🔓 Lock".into(),
+            unlock: "🔒 Unlock".into(),
+            wrap: "Wrap".into(),
+            unwrap_container: "Unwrap Container".into(),
+            unwrap_container_tooltip: "Replace this container with its children".into(),
+            container_type: "Container type".into(),
+            convert_to: "Convert to…".into(),
+            convert_tooltip: "Change this widget's type; properties the new type doesn't have are dropped".into(),
+            css_classes: "CSS Classes:".into(),
+            css_classes_placeholder: "Space-separated class names".into(),
+            anchor_id: "Anchor ID:".into(),
+            anchor_id_placeholder: "e.g. pricing".into(),
+            anchor_id_invalid: "IDs can't contain spaces".into(),
+            anchor_id_taken: "Another widget already uses this ID".into(),
+            anchor_link_template: "Link to it with #{name}".into(),
+            visibility: "Visibility:".into(),
+            visibility_always: "Always".into(),
+            visibility_preview_only: "Preview only".into(),
+            visibility_edit_only: "Edit only (hidden in preview and exports)".into(),
+            metadata: "Metadata".into(),
+            metadata_hint: "App-specific data stored with this widget, as a JSON object. Applied when the field loses focus.".into(),
+            invalid_json_object: "Invalid JSON object".into(),
+            column_span: "Column Span:".into(),
+            row_span: "Row Span:".into(),
+            grid_area: "Grid Area:".into(),
+            grid_area_invalid_template: "\"{name}\" is not a valid area name".into(),
+            grid_area_missing_template: "The grid's template areas don't include \"{name}\"".into(),
+            row_item_width: "Width:".into(),
+            row_item_width_placeholder: "auto (e.g. 50% or 240px)".into(),
+            fill_remaining_space: "Fill remaining space".into(),
+            breakpoint: "Breakpoint".into(),
+            breakpoint_base: "Base".into(),
+            breakpoint_all_sizes: "All screen sizes".into(),
+            breakpoint_and_wider_template: "{name} and wider screens".into(),
+            background: "Background:".into(),
+            text_color: "Text Color:".into(),
+            padding: "Padding:".into(),
+            margin: "Margin:".into(),
+            border: "Border:".into(),
+            border_width: "Width".into(),
+            border_radius: "Radius".into(),
+            border_color: "Border Color:".into(),
+            side_top: "Top".into(),
+            side_right: "Right".into(),
+            side_bottom: "Bottom".into(),
+            side_left: "Left".into(),
+            no_style_value: "none".into(),
+            clear_style_template: "Clear {name}".into(),
+            reset_confirm_title: "Reset Widget?".into(),
+            reset_confirm_message: "All properties and styles of this widget will be replaced with its defaults.".into(),
+            reset_confirm_label: "Reset".into(),
+
+            cancel: "Cancel".into(),
+            delete_confirm_title: "Delete Widget?".into(),
+            delete_confirm_message_template: "This container has nested widgets ({count}) that will also be removed.".into(),
+            paste_confirm_title: "Import Pasted Layout?".into(),
+            paste_confirm_message: "The clipboard contains a layout. Importing it will replace the current layout.".into(),
+            paste_confirm_label: "Import".into(),
+
+            image_too_large_template: "{name} is too large to embed (limit {count} KB)".into(),
+            image_widget_unavailable: "Image widgets are not available".into(),
+            file_read_failed_template: "Failed to read {name}".into(),
+            unknown_template_template: "Unknown template: {name}".into(),
+            layout_imported: "Layout imported".into(),
+            layout_imported_pruned_template: "Layout imported; removed {count} orphaned nodes".into(),
+            import_failed: "Failed to import layout".into(),
+            layout_cleared: "Layout cleared".into(),
+            dismiss_notification: "Dismiss notification".into(),
+        }
+    }
+}
+
+impl EditorStrings {
+    /// Title of a built-in starter template, or `default` for other names
+    #[cfg(feature = "standard-widgets")]
+    pub(crate) fn template_title(&self, name: &str, default: &str) -> AttrValue {
+        match name {
+            "hero" => self.template_hero.clone(),
+            "contact-form" => self.template_contact_form.clone(),
+            "pricing" => self.template_pricing.clone(),
+            _ => AttrValue::from(default.to_string()),
+        }
+    }
+}
+
+/// Fill the `{count}` placeholder of a `_template` string
+pub(crate) fn with_count(template: &str, count: usize) -> String {
+    template.replace("{count}", &count.to_string())
+}
+
+/// Fill the `{name}` placeholder of a `_template` string
+pub(crate) fn with_name(template: &str, name: &str) -> String {
+    template.replace("{name}", name)
+}
+
+/// Get the strings provided by the enclosing `Editor`, or the English defaults
+#[hook]
+pub(crate) fn use_editor_strings() -> Rc<EditorStrings> {
+    use_context::<Rc<EditorStrings>>().unwrap_or_default()
+}
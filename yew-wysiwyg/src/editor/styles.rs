@@ -0,0 +1,109 @@
+//! Base stylesheet for the editor chrome
+//!
+//! Most chrome is styled inline; this covers states inline styles can't express
+//! (hover, focus, active). Rules target the `wysiwyg-*` classes, so apps can
+//! override them with more specific selectors.
+
+use yew::prelude::*;
+
+/// Context marking that an enclosing `Editor` has injected [`EDITOR_CSS`]
+#[derive(Clone, PartialEq)]
+pub(crate) struct EditorCssInjected;
+
+/// Stylesheet for components that also work outside the `Editor` (palette,
+/// canvas); empty when an enclosing `Editor` already injected it
+#[hook]
+pub(crate) fn use_standalone_css() -> Html {
+    if use_context::<EditorCssInjected>().is_some() {
+        html! {}
+    } else {
+        html! { <style>{ EDITOR_CSS }</style> }
+    }
+}
+
+/// Stylesheet injected once by the `Editor`
+pub(crate) const EDITOR_CSS: &str = "
+.breadcrumb-link {
+    background: none;
+    border: none;
+    color: #3b82f6;
+    cursor: pointer;
+    padding: 4px 8px;
+    border-radius: 4px;
+    font-size: 13px;
+}
+.breadcrumb-link:hover {
+    background: #eff6ff;
+    text-decoration: underline;
+}
+.wysiwyg-palette-item {
+    display: flex;
+    align-items: center;
+    gap: 8px;
+    padding: 12px;
+    background: #f9fafb;
+    border: 1px solid #e5e7eb;
+    border-radius: 6px;
+    cursor: pointer;
+    text-align: left;
+    transition: all 0.15s;
+    /* Vertical swipes scroll the palette; any other swipe drags the widget */
+    touch-action: pan-y;
+}
+.wysiwyg-palette-item:hover {
+    background: #f3f4f6;
+    border-color: #d1d5db;
+}
+.wysiwyg-panel-toggle {
+    background: none;
+    border: none;
+    color: #6b7280;
+    cursor: pointer;
+    padding: 2px 6px;
+    border-radius: 4px;
+    font-size: 16px;
+    line-height: 1;
+}
+.wysiwyg-panel-toggle:hover {
+    background: #f3f4f6;
+    color: #374151;
+}
+.wysiwyg-palette-item:focus-visible,
+.wysiwyg-widget-wrapper:focus-visible,
+.wysiwyg-panel-toggle:focus-visible,
+.breadcrumb-link:focus-visible {
+    outline: 2px solid #3b82f6;
+    outline-offset: 2px;
+}
+.wysiwyg-canvas {
+    box-sizing: border-box;
+    /* Lets the canvas shrink and scroll inside the editor's flex row */
+    min-width: 0;
+    min-height: 0;
+}
+.wysiwyg-canvas:focus-visible {
+    outline: 2px solid #3b82f6;
+    outline-offset: -2px;
+}
+.wysiwyg-drop-zone {
+    box-sizing: border-box;
+}
+/* The label and ghost inside a hovered zone would otherwise fire dragleave on it */
+.wysiwyg-drop-zone > * {
+    pointer-events: none;
+}
+.wysiwyg-drag-handle {
+    color: #6b7280;
+    cursor: grab;
+}
+.wysiwyg-drag-handle:hover {
+    color: #374151;
+}
+.wysiwyg-drag-handle:active {
+    cursor: grabbing;
+}
+.wysiwyg-touch-drop-target {
+    outline: 2px solid #3b82f6;
+    background-color: #dbeafe !important;
+}
+";
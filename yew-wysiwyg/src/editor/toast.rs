@@ -0,0 +1,227 @@
+//! Toast notifications for editor feedback
+
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use super::browser_window;
+use super::strings::use_editor_strings;
+
+/// How long a toast stays on screen before dismissing itself
+const TOAST_DURATION_MS: i32 = 4000;
+
+/// Severity of an editor notification, which sets its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Error,
+}
+
+impl ToastLevel {
+    /// Accent color for the toast's left border
+    fn color(&self) -> &'static str {
+        match self {
+            ToastLevel::Info => "var(--wysiwyg-primary, #3b82f6)",
+            ToastLevel::Success => "var(--wysiwyg-success, #10b981)",
+            ToastLevel::Error => "var(--wysiwyg-danger, #ef4444)",
+        }
+    }
+}
+
+/// A single notification in the queue
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Toast {
+    pub id: u32,
+    pub message: String,
+    pub level: ToastLevel,
+}
+
+/// Actions on the toast queue
+pub(crate) enum ToastAction {
+    Show(String, ToastLevel),
+    Dismiss(u32),
+}
+
+/// Queue of visible toasts
+///
+/// A reducer rather than plain state, so toasts shown or dismissed from
+/// callbacks created in earlier renders don't overwrite each other.
+#[derive(Default, PartialEq)]
+pub(crate) struct ToastQueue {
+    pub toasts: Vec<Toast>,
+    next_id: u32,
+}
+
+impl Reducible for ToastQueue {
+    type Action = ToastAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut toasts = self.toasts.clone();
+        let mut next_id = self.next_id;
+        match action {
+            ToastAction::Show(message, level) => {
+                toasts.push(Toast {
+                    id: next_id,
+                    message,
+                    level,
+                });
+                next_id = next_id.wrapping_add(1);
+            }
+            ToastAction::Dismiss(id) => toasts.retain(|toast| toast.id != id),
+        }
+        Rc::new(Self { toasts, next_id })
+    }
+}
+
+/// Handle used to show toasts
+pub(crate) type ToastDispatcher = UseReducerDispatcher<ToastQueue>;
+
+/// Show a toast that dismisses itself after a few seconds
+pub(crate) fn show_toast(toasts: &ToastDispatcher, message: impl Into<String>, level: ToastLevel) {
+    toasts.dispatch(ToastAction::Show(message.into(), level));
+}
+
+/// Properties for the ToastStack component
+#[derive(Properties, PartialEq)]
+pub(crate) struct ToastStackProps {
+    pub toasts: Vec<Toast>,
+    pub on_dismiss: Callback<u32>,
+}
+
+/// Stack of toasts in the bottom-right corner of the editor
+#[function_component(ToastStack)]
+pub(crate) fn toast_stack(props: &ToastStackProps) -> Html {
+    if props.toasts.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div
+            aria-live="polite"
+            style="
+                position: fixed;
+                right: 16px;
+                bottom: 16px;
+                display: flex;
+                flex-direction: column;
+                gap: 8px;
+                z-index: 1100;
+            "
+        >
+            { for props.toasts.iter().map(|toast| html! {
+                <ToastItem
+                    key={toast.id}
+                    toast={toast.clone()}
+                    on_dismiss={props.on_dismiss.clone()}
+                />
+            }) }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ToastItemProps {
+    toast: Toast,
+    on_dismiss: Callback<u32>,
+}
+
+/// A single toast, dismissed by its own timer or the close button
+#[function_component(ToastItem)]
+fn toast_item(props: &ToastItemProps) -> Html {
+    let strings = use_editor_strings();
+    // The timer is cleared if the toast is closed early or the editor unmounts
+    {
+        let id = props.toast.id;
+        let on_dismiss = props.on_dismiss.clone();
+        use_effect_with(id, move |_| {
+            let pending = browser_window().and_then(|window| {
+                let callback = Closure::once(move || on_dismiss.emit(id));
+                let handle = window
+                    .set_timeout_with_callback_and_timeout_and_arguments_0(
+                        callback.as_ref().unchecked_ref(),
+                        TOAST_DURATION_MS,
+                    )
+                    .ok()?;
+                Some((handle, callback))
+            });
+
+            move || {
+                if let Some((handle, callback)) = pending {
+                    if let Some(window) = browser_window() {
+                        window.clear_timeout_with_handle(handle);
+                    }
+                    drop(callback);
+                }
+            }
+        });
+    }
+
+    let on_close = {
+        let id = props.toast.id;
+        let on_dismiss = props.on_dismiss.clone();
+        Callback::from(move |_: MouseEvent| on_dismiss.emit(id))
+    };
+
+    let role = if props.toast.level == ToastLevel::Error {
+        "alert"
+    } else {
+        "status"
+    };
+
+    html! {
+        <div
+            {role}
+            style={format!("
+                display: flex;
+                align-items: center;
+                gap: 12px;
+                min-width: 220px;
+                max-width: 360px;
+                padding: 10px 12px;
+                background: white;
+                color: #1f2937;
+                border-left: 4px solid {};
+                border-radius: 4px;
+                box-shadow: 0 4px 12px rgba(0,0,0,0.15);
+                font-size: 14px;
+            ", props.toast.level.color())}
+        >
+            <span style="flex: 1;">{ &props.toast.message }</span>
+            <button
+                onclick={on_close}
+                aria-label={strings.dismiss_notification.clone()}
+                style="
+                    background: none;
+                    border: none;
+                    color: #6b7280;
+                    cursor: pointer;
+                    font-size: 16px;
+                    line-height: 1;
+                "
+            >
+                { "×" }
+            </button>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toast_queue() {
+        let queue = Rc::new(ToastQueue::default())
+            .reduce(ToastAction::Show("Saved".into(), ToastLevel::Success))
+            .reduce(ToastAction::Show("Failed".into(), ToastLevel::Error));
+        assert_eq!(queue.toasts.len(), 2);
+        assert_ne!(queue.toasts[0].id, queue.toasts[1].id);
+
+        let first = queue.toasts[0].id;
+        let queue = queue.reduce(ToastAction::Dismiss(first));
+        assert_eq!(queue.toasts.len(), 1);
+        assert_eq!(queue.toasts[0].message, "Failed");
+    }
+}
@@ -1,10 +1,62 @@
 //! Toolbar component for editor actions
 
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
 use yew::prelude::*;
 
+use super::dialog::{trap_focus, use_dialog_focus, use_escape_key, ConfirmDialog};
+use super::strings::{use_editor_strings, with_count, with_name, EditorStrings};
+use super::toast::ToastLevel;
+use super::{browser_window, copy_to_clipboard};
 use crate::core::widget::WidgetId;
 use crate::serialization::Layout;
 
+/// Serialize the layout for the export textarea
+fn export_json(layout: &Layout, minified: bool, strings: &EditorStrings) -> String {
+    let json = if minified {
+        layout.to_json()
+    } else {
+        // Sorted so repeated exports of the same layout diff cleanly
+        layout.to_json_sorted()
+    };
+    json.unwrap_or_else(|e| format!("{}: {}", strings.json_generation_failed, e))
+}
+
+/// File name used when downloading the exported layout
+const EXPORT_FILE_NAME: &str = "layout.json";
+
+/// Delay before releasing an export's Blob URL, leaving the browser time to start the download
+const REVOKE_URL_DELAY_MS: i32 = 1000;
+
+/// Save JSON to a file by clicking a temporary link to a Blob URL
+fn download_json(json: &str, file_name: &str) -> Result<(), JsValue> {
+    let window = browser_window().ok_or_else(|| JsValue::from_str("No window available"))?;
+    let document = window
+        .document()
+        .ok_or_else(|| JsValue::from_str("No document available"))?;
+
+    let parts = js_sys::Array::of1(&JsValue::from_str(json));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("application/json");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let anchor: web_sys::HtmlAnchorElement = document.create_element("a")?.unchecked_into();
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    // Revoking right after the click can cancel the download in some browsers
+    let revoke = Closure::once_into_js(move || {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    });
+    window.set_timeout_with_callback_and_timeout_and_arguments_0(
+        revoke.unchecked_ref(),
+        REVOKE_URL_DELAY_MS,
+    )?;
+    Ok(())
+}
+
 /// Properties for the Toolbar component
 #[derive(Properties, PartialEq)]
 pub struct ToolbarProps {
@@ -14,35 +66,94 @@ pub struct ToolbarProps {
     pub on_clear: Callback<()>,
     pub edit_mode: bool,
     pub on_toggle_edit_mode: Callback<()>,
+    #[prop_or_default]
+    pub outline_containers: bool,
+    #[prop_or_default]
+    pub on_toggle_outline_containers: Callback<()>,
     pub on_undo: Callback<()>,
     pub on_redo: Callback<()>,
     pub can_undo: bool,
     pub can_redo: bool,
+    #[prop_or_default]
+    pub on_toast: Callback<(String, ToastLevel)>,
+    /// Shows a "Save version" button when set
+    #[prop_or_default]
+    pub on_snapshot: Option<Callback<()>>,
+    /// Names of the themes to switch between; the selector is shown for two or more
+    #[prop_or_default]
+    pub themes: Vec<AttrValue>,
+    #[prop_or_default]
+    pub selected_theme: usize,
+    #[prop_or_default]
+    pub on_select_theme: Callback<usize>,
+    /// Starter templates as (name, title) pairs; the templates menu is hidden when empty
+    #[prop_or_default]
+    pub templates: Vec<(AttrValue, AttrValue)>,
+    /// Receives the chosen template's name and whether to append it instead of replacing the layout
+    #[prop_or_default]
+    pub on_insert_template: Callback<(String, bool)>,
 }
 
 /// Toolbar component
 #[function_component(Toolbar)]
 pub fn toolbar(props: &ToolbarProps) -> Html {
+    let strings = use_editor_strings();
     let show_modal = use_state(|| false);
     let json_content = use_state(String::new);
     let import_error = use_state(|| Option::<String>::None);
     let show_clear_confirm = use_state(|| false);
+    // Template (name, title) waiting for confirmation before replacing the layout
+    let pending_template = use_state(|| Option::<(String, AttrValue)>::None);
+    let minified_export = use_state(|| false);
+
+    // Focus management for the Import/Export dialog
+    let modal_opener_ref = use_node_ref();
+    let modal_dialog_ref = use_node_ref();
+    use_dialog_focus(
+        *show_modal,
+        modal_dialog_ref.clone(),
+        modal_opener_ref.clone(),
+    );
 
     let on_modal_open = {
         let show_modal = show_modal.clone();
         let json_content = json_content.clone();
         let layout = props.layout.clone();
         let import_error = import_error.clone();
+        let minified_export = minified_export.clone();
+        let strings = strings.clone();
         Callback::from(move |_: MouseEvent| {
-            let export_json = layout
-                .to_json_pretty()
-                .unwrap_or_else(|e| format!("Error generating JSON: {}", e));
-            json_content.set(export_json);
+            json_content.set(export_json(&layout, *minified_export, &strings));
             import_error.set(None);
             show_modal.set(true);
         })
     };
 
+    let on_export_format = |minified: bool| {
+        let json_content = json_content.clone();
+        let layout = props.layout.clone();
+        let import_error = import_error.clone();
+        let minified_export = minified_export.clone();
+        let strings = strings.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            json_content.set(export_json(&layout, minified, &strings));
+            import_error.set(None);
+            minified_export.set(minified);
+        })
+    };
+
+    let format_button_style = |active: bool| {
+        format!(
+            "padding: 4px 10px; border: none; cursor: pointer; font-size: 12px; {}",
+            if active {
+                "background: #3b82f6; color: white;"
+            } else {
+                "background: #f3f4f6; color: #374151;"
+            }
+        )
+    };
+
     let on_json_change = {
         let json_content = json_content.clone();
         let import_error = import_error.clone();
@@ -58,6 +169,7 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
         let on_import = props.on_import.clone();
         let import_error = import_error.clone();
         let show_modal = show_modal.clone();
+        let strings = strings.clone();
         Callback::from(move |e: MouseEvent| {
             e.stop_propagation();
             let json = (*json_content).clone();
@@ -70,18 +182,97 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                     show_modal.set(false);
                 }
                 Err(err) => {
-                    import_error.set(Some(format!("Invalid JSON: {}", err)));
+                    import_error.set(Some(format!("{}: {}", strings.invalid_json, err)));
+                }
+            }
+        })
+    };
+
+    let on_file_change = {
+        let json_content = json_content.clone();
+        let import_error = import_error.clone();
+        let strings = strings.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let Some(file) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            // Allow choosing the same file again
+            input.set_value("");
+
+            let reader = match web_sys::FileReader::new() {
+                Ok(reader) => reader,
+                Err(_) => {
+                    import_error.set(Some(strings.file_reading_unsupported.to_string()));
+                    return;
                 }
+            };
+
+            let onloadend = {
+                let json_content = json_content.clone();
+                let import_error = import_error.clone();
+                let reader_clone = reader.clone();
+                let read_failed = with_name(&strings.file_read_failed_template, &file.name());
+                let invalid_json = strings.invalid_json.clone();
+                Closure::once_into_js(move || {
+                    if let Some(err) = reader_clone.error() {
+                        import_error.set(Some(format!("{}: {}", read_failed, err.message())));
+                        return;
+                    }
+                    let Some(json) = reader_clone.result().ok().and_then(|r| r.as_string()) else {
+                        import_error.set(Some(read_failed));
+                        return;
+                    };
+
+                    // Validate up front so problems show before clicking Load
+                    match Layout::from_json(&json) {
+                        Ok(_) => import_error.set(None),
+                        Err(err) => import_error.set(Some(format!("{}: {}", invalid_json, err))),
+                    }
+                    json_content.set(json);
+                })
+            };
+            reader.set_onloadend(Some(onloadend.unchecked_ref()));
+
+            if reader.read_as_text(&file).is_err() {
+                import_error.set(Some(with_name(
+                    &strings.file_read_failed_template,
+                    &file.name(),
+                )));
+            }
+        })
+    };
+
+    let on_download_json = {
+        let json_content = json_content.clone();
+        let on_toast = props.on_toast.clone();
+        let download_failed = strings.json_download_failed.to_string();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            if download_json(&json_content, EXPORT_FILE_NAME).is_err() {
+                on_toast.emit((download_failed.clone(), ToastLevel::Error));
             }
         })
     };
 
     let on_copy_json = {
         let json_content = json_content.clone();
+        let on_toast = props.on_toast.clone();
+        let strings = strings.clone();
         Callback::from(move |e: MouseEvent| {
             e.stop_propagation();
-            // In a real implementation, we'd use the clipboard API
-            log::info!("Copy JSON: {}", *json_content);
+            let on_toast = on_toast.clone();
+            let strings = strings.clone();
+            copy_to_clipboard(
+                &json_content,
+                Callback::from(move |copied: bool| {
+                    on_toast.emit(if copied {
+                        (strings.json_copied.to_string(), ToastLevel::Success)
+                    } else {
+                        (strings.json_copy_failed.to_string(), ToastLevel::Error)
+                    });
+                }),
+            );
         })
     };
 
@@ -92,6 +283,15 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
         })
     };
 
+    // Count what the canvas renders; nodes unreachable from the roots are flagged separately
+    let widget_count = props.layout.stats().total_widgets;
+    let orphaned_count = props
+        .layout
+        .to_serialized()
+        .nodes
+        .len()
+        .saturating_sub(widget_count);
+
     let on_clear_click = {
         let show_clear_confirm = show_clear_confirm.clone();
         Callback::from(move |_: MouseEvent| {
@@ -101,20 +301,70 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
 
     let on_clear_cancel = {
         let show_clear_confirm = show_clear_confirm.clone();
-        Callback::from(move |_: MouseEvent| {
+        Callback::from(move |_| {
             show_clear_confirm.set(false);
         })
     };
 
+    let on_template_select = {
+        let on_insert_template = props.on_insert_template.clone();
+        let pending_template = pending_template.clone();
+        let templates = props.templates.clone();
+        let is_empty = props.layout.root_widgets().is_empty();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let value = select.value();
+            // Back to the menu title so the same template can be picked again
+            select.set_value("");
+            let Some((mode, name)) = value.split_once(':') else {
+                return;
+            };
+            if mode == "append" || is_empty {
+                on_insert_template.emit((name.to_string(), mode == "append"));
+            } else if let Some((_, title)) = templates.iter().find(|(n, _)| n == name) {
+                pending_template.set(Some((name.to_string(), title.clone())));
+            }
+        })
+    };
+
+    let on_template_replace_cancel = {
+        let pending_template = pending_template.clone();
+        Callback::from(move |_| {
+            pending_template.set(None);
+        })
+    };
+
+    let on_template_replace_confirm = {
+        let pending_template = pending_template.clone();
+        let on_insert_template = props.on_insert_template.clone();
+        Callback::from(move |_| {
+            if let Some((name, _)) = (*pending_template).clone() {
+                on_insert_template.emit((name, false));
+            }
+            pending_template.set(None);
+        })
+    };
+
     let on_clear_confirm = {
         let show_clear_confirm = show_clear_confirm.clone();
         let on_clear = props.on_clear.clone();
-        Callback::from(move |_: MouseEvent| {
+        Callback::from(move |_| {
             show_clear_confirm.set(false);
             on_clear.emit(());
         })
     };
 
+    // Close the Import/Export modal on Escape
+    {
+        let show_modal = show_modal.clone();
+        use_escape_key(
+            *show_modal,
+            Callback::from(move |_| {
+                show_modal.set(false);
+            }),
+        );
+    }
+
     html! {
         <>
             <div
@@ -130,7 +380,7 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                 "
             >
                 <h2 style="margin: 0; font-size: 18px; font-weight: 600; flex: 1;">
-                    { "Page Editor" }
+                    { strings.title.clone() }
                 </h2>
 
                 <button
@@ -149,9 +399,9 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                     ", if props.can_undo { "#3b82f6" } else { "#d1d5db" },
                        if props.can_undo { "pointer" } else { "not-allowed" },
                        if props.can_undo { "1" } else { "0.6" })}
-                    title="Undo (Ctrl+Z)"
+                    title={strings.undo_tooltip.clone()}
                 >
-                    { "↶ Undo" }
+                    { strings.undo.clone() }
                 </button>
 
                 <button
@@ -170,12 +420,13 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                     ", if props.can_redo { "#3b82f6" } else { "#d1d5db" },
                        if props.can_redo { "pointer" } else { "not-allowed" },
                        if props.can_redo { "1" } else { "0.6" })}
-                    title="Redo (Ctrl+Y)"
+                    title={strings.redo_tooltip.clone()}
                 >
-                    { "↷ Redo" }
+                    { strings.redo.clone() }
                 </button>
 
                 <button
+                    ref={modal_opener_ref}
                     onclick={on_modal_open}
                     style="
                         padding: 8px 16px;
@@ -188,9 +439,28 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                         font-weight: 500;
                     "
                 >
-                    { "Import/Export" }
+                    { strings.import_export.clone() }
                 </button>
 
+                if let Some(on_snapshot) = &props.on_snapshot {
+                    <button
+                        onclick={on_snapshot.reform(|_: MouseEvent| ())}
+                        style="
+                            padding: 8px 16px;
+                            background: #3b82f6;
+                            color: white;
+                            border: none;
+                            border-radius: 4px;
+                            cursor: pointer;
+                            font-size: 14px;
+                            font-weight: 500;
+                        "
+                        title={strings.save_version_tooltip.clone()}
+                    >
+                        { strings.save_version.clone() }
+                    </button>
+                }
+
                 <button
                     onclick={on_clear_click}
                     style="
@@ -203,9 +473,9 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                         font-size: 14px;
                         font-weight: 500;
                     "
-                    title="Clear all widgets and start fresh"
+                    title={strings.clear_tooltip.clone()}
                 >
-                    { "Clear" }
+                    { strings.clear.clone() }
                 </button>
 
                 <button
@@ -221,9 +491,83 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                         font-weight: 500;
                     ", if props.edit_mode { "#10b981" } else { "#6b7280" })}
                 >
-                    { if props.edit_mode { "Preview" } else { "Edit" } }
+                    { if props.edit_mode { strings.preview.clone() } else { strings.edit.clone() } }
                 </button>
 
+                if props.edit_mode {
+                    <button
+                        onclick={props.on_toggle_outline_containers.reform(|_| ())}
+                        aria-pressed={if props.outline_containers { "true" } else { "false" }}
+                        style={format!("
+                            padding: 8px 12px;
+                            background: {};
+                            color: {};
+                            border: 1px solid #d1d5db;
+                            border-radius: 4px;
+                            cursor: pointer;
+                            font-size: 14px;
+                            font-weight: 500;
+                        ", if props.outline_containers { "#e5e7eb" } else { "white" },
+                           if props.outline_containers { "#111827" } else { "#374151" })}
+                        title={strings.outline_containers_tooltip.clone()}
+                    >
+                        { strings.outline_containers.clone() }
+                    </button>
+                }
+
+                if !props.templates.is_empty() {
+                    <select
+                        onchange={on_template_select}
+                        aria-label={strings.templates_tooltip.clone()}
+                        title={strings.templates_tooltip.clone()}
+                        style="
+                            padding: 7px 8px;
+                            border: 1px solid #d1d5db;
+                            border-radius: 4px;
+                            background: white;
+                            font-size: 14px;
+                            color: #374151;
+                        "
+                    >
+                        <option value="" selected=true disabled=true>{ strings.templates.clone() }</option>
+                        <optgroup label={strings.template_replace.clone()}>
+                            { for props.templates.iter().map(|(name, title)| html! {
+                                <option value={format!("replace:{}", name)}>{ title.clone() }</option>
+                            }) }
+                        </optgroup>
+                        <optgroup label={strings.template_append.clone()}>
+                            { for props.templates.iter().map(|(name, title)| html! {
+                                <option value={format!("append:{}", name)}>{ title.clone() }</option>
+                            }) }
+                        </optgroup>
+                    </select>
+                }
+
+                if props.themes.len() > 1 {
+                    <select
+                        onchange={props.on_select_theme.reform(|e: Event| {
+                            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                            select.value().parse().unwrap_or_default()
+                        })}
+                        aria-label={strings.theme_tooltip.clone()}
+                        title={strings.theme_tooltip.clone()}
+                        style="
+                            padding: 7px 8px;
+                            border: 1px solid #d1d5db;
+                            border-radius: 4px;
+                            background: white;
+                            font-size: 14px;
+                            color: #374151;
+                        "
+                    >
+                        { for props.themes.iter().enumerate().map(|(index, name)| html! {
+                            <option value={index.to_string()} selected={index == props.selected_theme}>
+                                { name.clone() }
+                            </option>
+                        }) }
+                    </select>
+                }
+
                 <div style="
                     padding: 8px 12px;
                     background: #f3f4f6;
@@ -231,7 +575,15 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                     font-size: 13px;
                     color: #6b7280;
                 ">
-                    { format!("{} widgets", props.layout.to_serialized().nodes.len()) }
+                    { with_count(&strings.widget_count_template, widget_count) }
+                    if orphaned_count > 0 {
+                        <span
+                            title={strings.orphaned_tooltip.clone()}
+                            style="margin-left: 6px; color: #b45309;"
+                        >
+                            { with_count(&strings.orphaned_count_template, orphaned_count) }
+                        </span>
+                    }
                 </div>
             </div>
 
@@ -264,11 +616,18 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                             flex-direction: column;
                             box-shadow: 0 20px 25px -5px rgba(0, 0, 0, 0.1);
                         "
+                        ref={modal_dialog_ref.clone()}
+                        role="dialog"
+                        aria-modal="true"
                         onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
+                        onkeydown={
+                            let modal_dialog_ref = modal_dialog_ref.clone();
+                            Callback::from(move |e: KeyboardEvent| trap_focus(&e, &modal_dialog_ref))
+                        }
                     >
                         <div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 16px;">
                             <h3 style="margin: 0; font-size: 18px; font-weight: 600;">
-                                { "Import/Export Layout" }
+                                { strings.import_export_title.clone() }
                             </h3>
                             <button
                                 onclick={on_close_modal.clone()}
@@ -287,9 +646,31 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                             </button>
                         </div>
 
-                        <p style="margin: 0 0 12px 0; color: #6b7280; font-size: 14px;">
-                            { "Copy the JSON below to export, or paste JSON and click Load to import." }
-                        </p>
+                        <div style="display: flex; justify-content: space-between; align-items: center; gap: 12px; margin-bottom: 12px;">
+                            <p style="margin: 0; color: #6b7280; font-size: 14px;">
+                                { strings.import_export_hint.clone() }
+                            </p>
+                            <div
+                                role="group"
+                                aria-label={strings.export_format.clone()}
+                                style="display: flex; flex-shrink: 0; border: 1px solid #e5e7eb; border-radius: 4px; overflow: hidden;"
+                            >
+                                <button
+                                    onclick={on_export_format(false)}
+                                    aria-pressed={if *minified_export { "false" } else { "true" }}
+                                    style={format_button_style(!*minified_export)}
+                                >
+                                    { strings.pretty.clone() }
+                                </button>
+                                <button
+                                    onclick={on_export_format(true)}
+                                    aria-pressed={if *minified_export { "true" } else { "false" }}
+                                    style={format_button_style(*minified_export)}
+                                >
+                                    { strings.minified.clone() }
+                                </button>
+                            </div>
+                        </div>
 
                         <textarea
                             value={(*json_content).clone()}
@@ -306,6 +687,16 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                             "
                         />
 
+                        <label style="display: flex; align-items: center; gap: 8px; margin-bottom: 12px; font-size: 13px; color: #374151;">
+                            { strings.load_from_file.clone() }
+                            <input
+                                type="file"
+                                accept=".json,application/json"
+                                onchange={on_file_change}
+                                style="font-size: 13px;"
+                            />
+                        </label>
+
                         if let Some(error) = (*import_error).clone() {
                             <div style="
                                 padding: 8px 12px;
@@ -333,79 +724,39 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                                     font-size: 14px;
                                 "
                             >
-                                { "Copy" }
+                                { strings.copy.clone() }
                             </button>
                             <button
-                                onclick={on_load_click}
+                                onclick={on_download_json}
                                 style="
                                     padding: 8px 16px;
-                                    background: #10b981;
+                                    background: #3b82f6;
                                     color: white;
                                     border: none;
                                     border-radius: 4px;
                                     cursor: pointer;
                                     font-size: 14px;
-                                    font-weight: 500;
                                 "
                             >
-                                { "Load" }
+                                { strings.download.clone() }
                             </button>
                             <button
-                                onclick={on_close_modal.clone()}
+                                onclick={on_load_click}
                                 style="
                                     padding: 8px 16px;
-                                    background: #f3f4f6;
-                                    color: #374151;
+                                    background: #10b981;
+                                    color: white;
                                     border: none;
                                     border-radius: 4px;
                                     cursor: pointer;
                                     font-size: 14px;
+                                    font-weight: 500;
                                 "
                             >
-                                { "Close" }
+                                { strings.load.clone() }
                             </button>
-                        </div>
-                    </div>
-                </div>
-            }
-
-            // Clear confirmation modal
-            if *show_clear_confirm {
-                <div
-                    style="
-                        position: fixed;
-                        top: 0;
-                        left: 0;
-                        right: 0;
-                        bottom: 0;
-                        background: rgba(0, 0, 0, 0.5);
-                        display: flex;
-                        align-items: center;
-                        justify-content: center;
-                        z-index: 1000;
-                    "
-                    onclick={on_clear_cancel.clone()}
-                >
-                    <div
-                        style="
-                            background: white;
-                            border-radius: 8px;
-                            padding: 24px;
-                            max-width: 400px;
-                            width: 90%;
-                            box-shadow: 0 20px 25px -5px rgba(0, 0, 0, 0.1);
-                        "
-                        onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
-                    >
-                        <h3 style="margin: 0 0 16px 0; font-size: 18px; font-weight: 600; color: #111827;">
-                            { "Clear All Widgets?" }
-                        </h3>
-                        <p style="margin: 0 0 24px 0; color: #6b7280; line-height: 1.5;">
-                            { "This will remove all widgets from the editor and clear the saved layout. This action cannot be undone." }
-                        </p>
-                        <div style="display: flex; gap: 12px; justify-content: flex-end;">
                             <button
-                                onclick={on_clear_cancel}
+                                onclick={on_close_modal.clone()}
                                 style="
                                     padding: 8px 16px;
                                     background: #f3f4f6;
@@ -414,30 +765,35 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
                                     border-radius: 4px;
                                     cursor: pointer;
                                     font-size: 14px;
-                                    font-weight: 500;
-                                "
-                            >
-                                { "Cancel" }
-                            </button>
-                            <button
-                                onclick={on_clear_confirm}
-                                style="
-                                    padding: 8px 16px;
-                                    background: #ef4444;
-                                    color: white;
-                                    border: none;
-                                    border-radius: 4px;
-                                    cursor: pointer;
-                                    font-size: 14px;
-                                    font-weight: 500;
                                 "
                             >
-                                { "Clear All" }
+                                { strings.close.clone() }
                             </button>
                         </div>
                     </div>
                 </div>
             }
+
+            // Clear confirmation modal
+            if *show_clear_confirm {
+                <ConfirmDialog
+                    title={strings.clear_confirm_title.clone()}
+                    message={strings.clear_confirm_message.clone()}
+                    confirm_label={strings.clear_confirm_label.clone()}
+                    on_confirm={on_clear_confirm}
+                    on_cancel={on_clear_cancel}
+                />
+            }
+
+            if let Some((_, title)) = &*pending_template {
+                <ConfirmDialog
+                    title={strings.template_replace_confirm_title.clone()}
+                    message={with_name(&strings.template_replace_confirm_message_template, title)}
+                    confirm_label={strings.template_replace_confirm_label.clone()}
+                    on_confirm={on_template_replace_confirm}
+                    on_cancel={on_template_replace_cancel}
+                />
+            }
         </>
     }
 }
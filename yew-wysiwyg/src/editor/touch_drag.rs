@@ -0,0 +1,219 @@
+//! Touch dragging for palette items and canvas drag handles
+//!
+//! Most mobile browsers don't fire HTML5 drag events for touch input, so touch
+//! drags are tracked by hand. Once a finger moves past a small threshold, the
+//! drop zone under it is found with `elementFromPoint` and highlighted; lifting
+//! the finger drops onto that zone through the same callbacks a mouse drop uses.
+//! Drop zones describe their target with `data-drop-parent`, `data-drop-position`
+//! and `data-drop-slot` attributes.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use web_sys::Element;
+use yew::prelude::*;
+
+use super::browser_window;
+use crate::core::widget::WidgetId;
+
+/// Distance in pixels a touch must travel before it becomes a drag
+const TOUCH_DRAG_THRESHOLD_PX: i32 = 8;
+
+/// Class added to the drop zone under a touch drag
+const TOUCH_TARGET_CLASS: &str = "wysiwyg-touch-drop-target";
+
+/// Elements a touch drag can drop onto
+const DROP_ZONE_SELECTOR: &str = "[data-drop-position], [data-drop-slot]";
+
+/// What a drag carries
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DragPayload {
+    /// A new widget of this type, from the palette
+    NewWidget(String),
+    /// An existing widget, moved by its drag handle
+    MoveWidget(WidgetId),
+}
+
+/// Where a drop lands
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DropTarget {
+    /// At a position among a parent's children (`None` for the root)
+    Position(Option<WidgetId>, usize),
+    /// Into a named slot of a parent
+    Slot(WidgetId, String),
+}
+
+impl DropTarget {
+    /// Read the target a drop zone element describes
+    fn from_element(element: &Element) -> Option<Self> {
+        let parent = element
+            .get_attribute("data-drop-parent")
+            .and_then(|id| WidgetId::parse_str(&id).ok());
+        if let Some(slot) = element.get_attribute("data-drop-slot") {
+            return Some(Self::Slot(parent?, slot));
+        }
+        let position = element.get_attribute("data-drop-position")?.parse().ok()?;
+        Some(Self::Position(parent, position))
+    }
+}
+
+/// Get the drop zone under a viewport point, if any
+fn drop_zone_at(x: i32, y: i32) -> Option<Element> {
+    browser_window()?
+        .document()?
+        .element_from_point(x as f32, y as f32)?
+        .closest(DROP_ZONE_SELECTOR)
+        .ok()
+        .flatten()
+}
+
+/// Progress of the touch currently pressed on a drag source
+#[derive(Default)]
+struct TouchDragState {
+    payload: Option<DragPayload>,
+    origin: (i32, i32),
+    /// Whether the touch has moved far enough to count as a drag
+    started: bool,
+    /// Drop zone under the touch, carrying [`TOUCH_TARGET_CLASS`]
+    highlighted: Option<Element>,
+}
+
+/// Touch drag shared by the palette and canvas, provided as a context by the `Editor`
+#[derive(Clone)]
+pub(crate) struct TouchDrag {
+    dragging: UseStateHandle<bool>,
+    state: Rc<RefCell<TouchDragState>>,
+    on_drop: Callback<(DragPayload, DropTarget)>,
+}
+
+impl PartialEq for TouchDrag {
+    fn eq(&self, other: &Self) -> bool {
+        self.dragging == other.dragging
+            && Rc::ptr_eq(&self.state, &other.state)
+            && self.on_drop == other.on_drop
+    }
+}
+
+/// Touch listeners for a drag source, spread onto its element
+pub(crate) struct TouchDragListeners {
+    pub ontouchstart: Callback<TouchEvent>,
+    pub ontouchmove: Callback<TouchEvent>,
+    pub ontouchend: Callback<TouchEvent>,
+    pub ontouchcancel: Callback<TouchEvent>,
+}
+
+/// Create the touch drag state; `on_drop` receives every completed drop
+#[hook]
+pub(crate) fn use_touch_drag(on_drop: Callback<(DragPayload, DropTarget)>) -> TouchDrag {
+    TouchDrag {
+        dragging: use_state_eq(|| false),
+        state: use_mut_ref(TouchDragState::default),
+        on_drop,
+    }
+}
+
+impl TouchDrag {
+    /// Whether a touch drag is in progress, so drop zones should be shown
+    pub(crate) fn is_dragging(&self) -> bool {
+        *self.dragging
+    }
+
+    /// Listeners that drag `payload` from the element they're attached to
+    ///
+    /// Touch events keep targeting the element a touch started on, so the
+    /// element must stay mounted for the whole drag.
+    pub(crate) fn listeners(&self, payload: DragPayload) -> TouchDragListeners {
+        let ontouchstart = {
+            let state = self.state.clone();
+            Callback::from(move |e: TouchEvent| {
+                let touches = e.touches();
+                let Some(touch) = touches.get(0).filter(|_| touches.length() == 1) else {
+                    return;
+                };
+                *state.borrow_mut() = TouchDragState {
+                    payload: Some(payload.clone()),
+                    origin: (touch.client_x(), touch.client_y()),
+                    ..TouchDragState::default()
+                };
+            })
+        };
+
+        let ontouchmove = {
+            let state = self.state.clone();
+            let dragging = self.dragging.clone();
+            Callback::from(move |e: TouchEvent| {
+                let Some(touch) = e.touches().get(0) else {
+                    return;
+                };
+                let mut state = state.borrow_mut();
+                if state.payload.is_none() {
+                    return;
+                }
+                let (x, y) = (touch.client_x(), touch.client_y());
+                if !state.started {
+                    let (dx, dy) = (x - state.origin.0, y - state.origin.1);
+                    if dx * dx + dy * dy <= TOUCH_DRAG_THRESHOLD_PX * TOUCH_DRAG_THRESHOLD_PX {
+                        return;
+                    }
+                    state.started = true;
+                    dragging.set(true);
+                }
+
+                let zone = drop_zone_at(x, y);
+                if zone != state.highlighted {
+                    if let Some(previous) = state.highlighted.take() {
+                        let _ = previous.class_list().remove_1(TOUCH_TARGET_CLASS);
+                    }
+                    if let Some(zone) = &zone {
+                        let _ = zone.class_list().add_1(TOUCH_TARGET_CLASS);
+                    }
+                    state.highlighted = zone;
+                }
+            })
+        };
+
+        let ontouchend = {
+            let state = self.state.clone();
+            let dragging = self.dragging.clone();
+            let on_drop = self.on_drop.clone();
+            Callback::from(move |_: TouchEvent| {
+                let state = std::mem::take(&mut *state.borrow_mut());
+                if let Some(zone) = &state.highlighted {
+                    let _ = zone.class_list().remove_1(TOUCH_TARGET_CLASS);
+                }
+                if !state.started {
+                    return;
+                }
+                dragging.set(false);
+                let target = state
+                    .highlighted
+                    .as_ref()
+                    .and_then(DropTarget::from_element);
+                if let (Some(payload), Some(target)) = (state.payload, target) {
+                    on_drop.emit((payload, target));
+                }
+            })
+        };
+
+        let ontouchcancel = {
+            let state = self.state.clone();
+            let dragging = self.dragging.clone();
+            Callback::from(move |_: TouchEvent| {
+                let state = std::mem::take(&mut *state.borrow_mut());
+                if let Some(zone) = &state.highlighted {
+                    let _ = zone.class_list().remove_1(TOUCH_TARGET_CLASS);
+                }
+                if state.started {
+                    dragging.set(false);
+                }
+            })
+        };
+
+        TouchDragListeners {
+            ontouchstart,
+            ontouchmove,
+            ontouchend,
+            ontouchcancel,
+        }
+    }
+}
@@ -30,18 +30,27 @@ pub mod editor;
 pub mod error;
 pub mod serialization;
 
+#[cfg(feature = "standard-widgets")]
+pub mod templates;
 #[cfg(feature = "standard-widgets")]
 pub mod widgets;
 
 // Re-exports
 pub use crate::core::{
+    loading::{LoadFuture, LoadingWidget},
     registry::WidgetRegistry,
+    style::StyleAllowlist,
     theme::{Theme, ThemeConfig},
-    widget::{Widget, WidgetConfig, WidgetFactory, WidgetProps},
+    widget::{Breakpoint, SlotSpec, Visibility, Widget, WidgetConfig, WidgetFactory, WidgetProps},
 };
-pub use crate::editor::Editor;
+pub use crate::editor::{Editor, EditorStrings, LayoutView};
 pub use crate::error::{Error, Result};
-pub use crate::serialization::{Layout, LayoutNode, SerializedLayout};
+pub use crate::serialization::{
+    ExtractedStyles, Layout, LayoutBuilder, LayoutNode, LayoutStats, MergeConflict, MergeResult,
+    SerializedLayout,
+};
 
+#[cfg(feature = "standard-widgets")]
+pub use crate::templates::TEMPLATES;
 #[cfg(feature = "standard-widgets")]
 pub use crate::widgets::{container, text};
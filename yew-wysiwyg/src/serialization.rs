@@ -1,11 +1,22 @@
 //! Serialization and deserialization for layouts
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use crate::core::widget::{WidgetConfig, WidgetId};
+use crate::core::registry::WidgetRegistry;
+use crate::core::widget::{
+    widget_class_name, Breakpoint, SlotSpec, WidgetConfig, WidgetId, ELEMENT_ID, GRID_AREA,
+    GRID_COLUMN_SPAN, GRID_ROW_SPAN, VISIBILITY,
+};
+use crate::core::yew_source;
 use crate::error::{Error, Result};
 
+/// Version of the serialization format written by this crate
+///
+/// Layouts with a newer major version are rejected on load; older ones are accepted.
+pub const CURRENT_VERSION: &str = "1.0";
+
 /// Serialized representation of a layout
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SerializedLayout {
@@ -16,13 +27,14 @@ pub struct SerializedLayout {
     /// All nodes in the layout
     pub nodes: HashMap<WidgetId, LayoutNode>,
     /// Metadata about the layout
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
 impl Default for SerializedLayout {
     fn default() -> Self {
         Self {
-            version: "1.0".to_string(),
+            version: CURRENT_VERSION.to_string(),
             root_nodes: Vec::new(),
             nodes: HashMap::new(),
             metadata: HashMap::new(),
@@ -46,9 +58,56 @@ impl SerializedLayout {
         serde_json::to_string_pretty(self).map_err(Into::into)
     }
 
+    /// Serialize to pretty JSON with map keys in sorted order
+    ///
+    /// Nodes, properties, and metadata are written by key instead of in hash
+    /// order, so equal layouts always produce the same text and stored exports
+    /// diff cleanly. Inline styles keep their insertion order, which decides
+    /// between overlapping declarations.
+    pub fn to_json_sorted(&self) -> Result<String> {
+        self.to_json_sorted_with_indent("  ")
+    }
+
+    /// Like [`to_json_sorted`](Self::to_json_sorted), indenting each level with `indent`
+    pub fn to_json_sorted_with_indent(&self, indent: &str) -> Result<String> {
+        let mut output = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+        let mut serializer = serde_json::Serializer::with_formatter(&mut output, formatter);
+        SortedLayout::new(self).serialize(&mut serializer)?;
+        String::from_utf8(output).map_err(|e| Error::SerializationError(e.to_string()))
+    }
+
     /// Deserialize from JSON string
+    ///
+    /// Fails if the layout was written in a newer format than this crate supports.
     pub fn from_json(json: &str) -> Result<Self> {
-        serde_json::from_str(json).map_err(|e| Error::DeserializationError(e.to_string()))
+        let layout: Self =
+            serde_json::from_str(json).map_err(|e| Error::DeserializationError(e.to_string()))?;
+        layout.check_version()?;
+        Ok(layout)
+    }
+
+    /// Check that the format version's major number isn't newer than [`CURRENT_VERSION`]
+    fn check_version(&self) -> Result<()> {
+        let major = |version: &str| {
+            version
+                .trim()
+                .split('.')
+                .next()
+                .and_then(|major| major.parse::<u32>().ok())
+        };
+        let supported = major(CURRENT_VERSION).unwrap_or_default();
+        match major(&self.version) {
+            Some(version) if version <= supported => Ok(()),
+            Some(_) => Err(Error::DeserializationError(format!(
+                "Layout format version {} is newer than the supported version {}",
+                self.version, CURRENT_VERSION
+            ))),
+            None => Err(Error::DeserializationError(format!(
+                "Unrecognized layout format version: {}",
+                self.version
+            ))),
+        }
     }
 
     /// Add a node to the layout
@@ -77,7 +136,14 @@ impl SerializedLayout {
     }
 
     /// Validate the layout structure
+    ///
+    /// Only referential integrity and the tree shape are checked: every node is
+    /// listed at most once, and none is its own ancestor. Widget types are deliberately not
+    /// looked up in any registry, so nodes of unknown types are kept as-is and
+    /// survive a load/export round-trip unchanged.
     pub fn validate(&self) -> Result<()> {
+        self.check_version()?;
+
         // Check that all root nodes exist
         for root_id in &self.root_nodes {
             if !self.nodes.contains_key(root_id) {
@@ -100,20 +166,135 @@ impl SerializedLayout {
             }
         }
 
+        // Each node is listed at most once, as a root or as one parent's child
+        let mut listed_under: HashMap<WidgetId, Option<WidgetId>> = HashMap::new();
+        let listings = self.root_nodes.iter().map(|id| (*id, None)).chain(
+            self.nodes
+                .iter()
+                .flat_map(|(id, node)| node.children.iter().map(move |child| (*child, Some(*id)))),
+        );
+        for (id, parent) in listings {
+            if listed_under.insert(id, parent).is_some() {
+                return Err(Error::InvalidOperation(format!(
+                    "Node {} is listed more than once",
+                    id
+                )));
+            }
+        }
+
+        // No node may contain itself, which would make tree walks loop forever
+        for id in self.nodes.keys() {
+            let mut current = listed_under.get(id).copied().flatten();
+            let mut steps = 0;
+            while let Some(parent_id) = current {
+                steps += 1;
+                if parent_id == *id || steps > self.nodes.len() {
+                    return Err(Error::InvalidOperation(format!(
+                        "Node {} is its own ancestor",
+                        id
+                    )));
+                }
+                current = listed_under.get(&parent_id).copied().flatten();
+            }
+        }
+
         Ok(())
     }
 }
 
+/// Borrowed view of a [`SerializedLayout`] that writes its hash maps in key order
+///
+/// Mirrors the serde layout of `SerializedLayout`, `LayoutNode`, and
+/// `WidgetConfig`; keep the fields in sync when those change.
+#[derive(Serialize)]
+struct SortedLayout<'a> {
+    version: &'a str,
+    root_nodes: &'a [WidgetId],
+    nodes: BTreeMap<&'a WidgetId, SortedNode<'a>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<&'a str, &'a serde_json::Value>,
+}
+
+#[derive(Serialize)]
+struct SortedNode<'a> {
+    config: SortedConfig<'a>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    children: &'a [WidgetId],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<WidgetId>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<&'a str, &'a serde_json::Value>,
+}
+
+#[derive(Serialize)]
+struct SortedConfig<'a> {
+    widget_type: &'a str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    properties: BTreeMap<&'a str, &'a serde_json::Value>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    css_classes: &'a [String],
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    inline_styles: &'a IndexMap<String, String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    responsive_styles: &'a BTreeMap<Breakpoint, IndexMap<String, String>>,
+}
+
+/// Sort a string-keyed map's entries by key
+fn sorted_entries<V>(map: &HashMap<String, V>) -> BTreeMap<&str, &V> {
+    map.iter()
+        .map(|(key, value)| (key.as_str(), value))
+        .collect()
+}
+
+impl<'a> SortedLayout<'a> {
+    fn new(layout: &'a SerializedLayout) -> Self {
+        Self {
+            version: &layout.version,
+            root_nodes: &layout.root_nodes,
+            nodes: layout
+                .nodes
+                .iter()
+                .map(|(id, node)| {
+                    let config = &node.config;
+                    let sorted = SortedNode {
+                        config: SortedConfig {
+                            widget_type: &config.widget_type,
+                            properties: sorted_entries(&config.properties),
+                            css_classes: &config.css_classes,
+                            inline_styles: &config.inline_styles,
+                            responsive_styles: &config.responsive_styles,
+                        },
+                        children: &node.children,
+                        parent: node.parent,
+                        metadata: sorted_entries(&node.metadata),
+                    };
+                    (id, sorted)
+                })
+                .collect(),
+            metadata: sorted_entries(&layout.metadata),
+        }
+    }
+}
+
+/// Node metadata key marking a widget as locked against moves, deletion, and edits
+pub const LOCKED_KEY: &str = "locked";
+
+/// Node metadata key naming the parent's slot a widget is placed in
+pub const SLOT_KEY: &str = "slot";
+
 /// A node in the layout tree
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LayoutNode {
     /// Widget configuration
     pub config: WidgetConfig,
     /// Child widget IDs (for containers)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<WidgetId>,
     /// Parent widget ID (if any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent: Option<WidgetId>,
     /// Custom metadata for this node
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
@@ -135,6 +316,29 @@ impl LayoutNode {
         }
     }
 
+    /// Check whether this node is locked against edits
+    pub fn is_locked(&self) -> bool {
+        self.metadata
+            .get(LOCKED_KEY)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Lock or unlock this node
+    pub fn set_locked(&mut self, locked: bool) {
+        if locked {
+            self.metadata
+                .insert(LOCKED_KEY.to_string(), serde_json::json!(true));
+        } else {
+            self.metadata.remove(LOCKED_KEY);
+        }
+    }
+
+    /// Get the name of the parent's slot this node fills, if any
+    pub fn slot(&self) -> Option<&str> {
+        self.metadata.get(SLOT_KEY).and_then(|v| v.as_str())
+    }
+
     /// Remove a child from this node
     pub fn remove_child(&mut self, child_id: &WidgetId) -> bool {
         if let Some(pos) = self.children.iter().position(|id| id == child_id) {
@@ -146,10 +350,64 @@ impl LayoutNode {
     }
 }
 
+/// Inline styles extracted from a layout into a class-based stylesheet
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedStyles {
+    /// Layout whose nodes reference generated classes instead of inline styles
+    pub layout: Layout,
+    /// CSS rules for the generated classes, in document order
+    pub stylesheet: String,
+}
+
+/// A widget whose configuration differs between two merged layouts
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeConflict {
+    /// Widget present in both layouts
+    pub id: WidgetId,
+    /// Configuration from the layout `merge` was called on (kept in the result)
+    pub ours: WidgetConfig,
+    /// Configuration from the layout passed to `merge`
+    pub theirs: WidgetConfig,
+}
+
+/// Outcome of [`Layout::merge`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeResult {
+    /// Merged layout
+    pub layout: Layout,
+    /// Widgets whose configurations differ, in document order of the other layout
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// Summary metrics for a layout, from [`Layout::stats`]
+///
+/// Only widgets reachable from the roots are counted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayoutStats {
+    /// Number of widgets in the tree
+    pub total_widgets: usize,
+    /// Number of widgets of each type, sorted by type
+    pub widgets_by_type: BTreeMap<String, usize>,
+    /// Depth of the deepest widget, where root widgets have depth 1 (0 when empty)
+    pub max_depth: usize,
+    /// Number of root widgets
+    pub root_count: usize,
+}
+
 /// In-memory representation of a layout
-#[derive(Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Layout {
     serialized: SerializedLayout,
+    /// Deepest nesting inserts and moves may create (not serialized)
+    max_depth: Option<usize>,
+}
+
+// The nesting limit is runtime configuration, so layouts with the same
+// content are equal whatever limit they carry
+impl PartialEq for Layout {
+    fn eq(&self, other: &Self) -> bool {
+        self.serialized == other.serialized
+    }
 }
 
 impl Layout {
@@ -157,13 +415,45 @@ impl Layout {
     pub fn new() -> Self {
         Self {
             serialized: SerializedLayout::new(),
+            max_depth: None,
         }
     }
 
     /// Create from serialized layout
     pub fn from_serialized(serialized: SerializedLayout) -> Result<Self> {
         serialized.validate()?;
-        Ok(Self { serialized })
+        Ok(Self {
+            serialized,
+            max_depth: None,
+        })
+    }
+
+    /// Limit how deep widgets may be nested, where root widgets have depth 1
+    ///
+    /// Once set, adding children, moving, wrapping, duplicating, and appending
+    /// widgets fail with `Error::InvalidOperation` instead of nesting deeper.
+    /// Existing widgets past the limit are left alone. The limit is not
+    /// serialized or compared, so layouts loaded with [`Layout::from_json`]
+    /// start without one.
+    pub fn set_max_depth(&mut self, max_depth: Option<usize>) {
+        self.max_depth = max_depth;
+    }
+
+    /// Get the nesting limit set with [`Layout::set_max_depth`]
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Check a subtree `subtree_height` levels tall against the layout's own limit
+    fn ensure_within_max_depth(
+        &self,
+        parent_id: Option<&WidgetId>,
+        subtree_height: usize,
+    ) -> Result<()> {
+        match self.max_depth {
+            Some(max_depth) => self.check_max_depth(parent_id, subtree_height, max_depth),
+            None => Ok(()),
+        }
     }
 
     /// Get the serialized representation
@@ -196,11 +486,19 @@ impl Layout {
         child_id: WidgetId,
         config: WidgetConfig,
     ) -> Result<()> {
+        self.ensure_within_max_depth(Some(&parent_id), 1)?;
         let parent = self
             .serialized
             .get_node_mut(&parent_id)
             .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?;
 
+        if parent.is_locked() {
+            return Err(Error::InvalidOperation(format!(
+                "Widget {} is locked",
+                parent_id
+            )));
+        }
+
         parent.add_child(child_id);
 
         let mut child_node = LayoutNode::new(config);
@@ -218,11 +516,19 @@ impl Layout {
         config: WidgetConfig,
         position: usize,
     ) -> Result<()> {
+        self.ensure_within_max_depth(Some(&parent_id), 1)?;
         let parent = self
             .serialized
             .get_node_mut(&parent_id)
             .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?;
 
+        if parent.is_locked() {
+            return Err(Error::InvalidOperation(format!(
+                "Widget {} is locked",
+                parent_id
+            )));
+        }
+
         let pos = position.min(parent.children.len());
         parent.children.insert(pos, child_id);
 
@@ -233,118 +539,1109 @@ impl Layout {
         Ok(())
     }
 
-    /// Remove a widget and its children
-    pub fn remove_widget(&mut self, id: &WidgetId) -> Result<()> {
+    /// Insert a widget right before `reference`, under the same parent (or in the root list)
+    pub fn insert_before(
+        &mut self,
+        reference: &WidgetId,
+        id: WidgetId,
+        config: WidgetConfig,
+    ) -> Result<()> {
+        self.insert_next_to(reference, id, config, 0)
+    }
+
+    /// Insert a widget right after `reference`, under the same parent (or in the root list)
+    pub fn insert_after(
+        &mut self,
+        reference: &WidgetId,
+        id: WidgetId,
+        config: WidgetConfig,
+    ) -> Result<()> {
+        self.insert_next_to(reference, id, config, 1)
+    }
+
+    /// Insert a widget `offset` places from `reference` in its sibling list
+    fn insert_next_to(
+        &mut self,
+        reference: &WidgetId,
+        id: WidgetId,
+        config: WidgetConfig,
+        offset: usize,
+    ) -> Result<()> {
+        let parent_id = self
+            .serialized
+            .get_node(reference)
+            .ok_or_else(|| Error::WidgetNotFound(reference.to_string()))?
+            .parent;
+        let siblings = match parent_id {
+            Some(parent_id) => {
+                &self
+                    .serialized
+                    .get_node(&parent_id)
+                    .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?
+                    .children
+            }
+            None => &self.serialized.root_nodes,
+        };
+        let pos = siblings
+            .iter()
+            .position(|sibling_id| sibling_id == reference)
+            .ok_or_else(|| Error::InvalidOperation("Widget not found in parent".to_string()))?;
+        match parent_id {
+            Some(parent_id) => self.insert_child_widget(parent_id, id, config, pos + offset),
+            None => {
+                self.insert_root_widget(id, config, pos + offset);
+                Ok(())
+            }
+        }
+    }
+
+    /// Add a child widget into one of the parent's slots
+    ///
+    /// Fails if the slot is not in `slots` or is already filled.
+    pub fn add_widget_to_slot(
+        &mut self,
+        parent_id: WidgetId,
+        child_id: WidgetId,
+        config: WidgetConfig,
+        slots: &[SlotSpec],
+        slot: &str,
+    ) -> Result<()> {
+        let position = self.slot_position(&parent_id, &child_id, slots, slot)?;
+        self.insert_child_widget(parent_id, child_id, config, position)?;
+        self.set_slot(&child_id, slot);
+        Ok(())
+    }
+
+    /// Move a widget into one of another widget's slots
+    ///
+    /// Fails if the slot is not in `slots` or is filled by a different widget,
+    /// and for the same reasons as [`Layout::move_widget`].
+    pub fn move_widget_to_slot(
+        &mut self,
+        id: &WidgetId,
+        parent_id: WidgetId,
+        slots: &[SlotSpec],
+        slot: &str,
+    ) -> Result<()> {
+        let position = self.slot_position(&parent_id, id, slots, slot)?;
+        self.move_widget(id, Some(parent_id), position)?;
+        self.set_slot(id, slot);
+        Ok(())
+    }
+
+    /// Get the widget filling a slot of `parent_id`
+    pub fn slot_child(&self, parent_id: &WidgetId, slot: &str) -> Option<WidgetId> {
+        self.serialized
+            .get_node(parent_id)?
+            .children
+            .iter()
+            .copied()
+            .find(|child_id| {
+                self.serialized
+                    .get_node(child_id)
+                    .is_some_and(|child| child.slot() == Some(slot))
+            })
+    }
+
+    /// Index in the parent's children where a widget for `slot` goes, keeping
+    /// children in slot order
+    fn slot_position(
+        &self,
+        parent_id: &WidgetId,
+        id: &WidgetId,
+        slots: &[SlotSpec],
+        slot: &str,
+    ) -> Result<usize> {
+        let slot_index = |name: Option<&str>| {
+            name.and_then(|name| slots.iter().position(|spec| spec.name == name))
+        };
+        let index = slot_index(Some(slot))
+            .ok_or_else(|| Error::InvalidOperation(format!("Unknown slot {}", slot)))?;
+        if self
+            .slot_child(parent_id, slot)
+            .is_some_and(|child_id| child_id != *id)
+        {
+            return Err(Error::InvalidOperation(format!(
+                "Slot {} is already filled",
+                slot
+            )));
+        }
+        let children = &self
+            .serialized
+            .get_node(parent_id)
+            .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?
+            .children;
+        Ok(children
+            .iter()
+            .position(|child_id| {
+                child_id != id
+                    && slot_index(
+                        self.serialized
+                            .get_node(child_id)
+                            .and_then(LayoutNode::slot),
+                    )
+                    .is_none_or(|other| other > index)
+            })
+            .unwrap_or(children.len()))
+    }
+
+    fn set_slot(&mut self, id: &WidgetId, slot: &str) {
+        if let Some(node) = self.serialized.get_node_mut(id) {
+            node.metadata
+                .insert(SLOT_KEY.to_string(), serde_json::json!(slot));
+        }
+    }
+
+    /// Check whether `element_id` is free for `widget_id`, i.e. no other widget uses it
+    pub fn is_element_id_available(&self, element_id: &str, widget_id: &WidgetId) -> bool {
+        !self
+            .serialized
+            .nodes
+            .iter()
+            .any(|(id, node)| id != widget_id && node.config.element_id() == Some(element_id))
+    }
+
+    /// Check whether a widget is locked
+    pub fn is_locked(&self, id: &WidgetId) -> bool {
+        self.serialized
+            .get_node(id)
+            .map(|node| node.is_locked())
+            .unwrap_or(false)
+    }
+
+    /// Lock or unlock a widget
+    pub fn set_locked(&mut self, id: &WidgetId, locked: bool) -> Result<()> {
+        self.serialized
+            .get_node_mut(id)
+            .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?
+            .set_locked(locked);
+        Ok(())
+    }
+
+    /// Replace a widget's configuration
+    ///
+    /// The widget type can't change this way: a config whose `widget_type`
+    /// differs from the widget's current type is rejected, as are changes to
+    /// locked widgets.
+    pub fn set_widget_config(&mut self, id: &WidgetId, config: WidgetConfig) -> Result<()> {
+        self.ensure_unlocked(id)?;
+        let node = self
+            .serialized
+            .get_node_mut(id)
+            .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?;
+        if node.config.widget_type != config.widget_type {
+            return Err(Error::InvalidConfig(format!(
+                "Widget {} is a {}, not a {}",
+                id, node.config.widget_type, config.widget_type
+            )));
+        }
+        node.config = config;
+        Ok(())
+    }
+
+    /// Replace a widget's custom metadata
+    ///
+    /// The lock flag is managed by [`Layout::set_locked`] and the slot by the
+    /// slot operations: `locked` and `slot` keys in `metadata` are ignored and
+    /// the widget's current lock state and slot are kept. Fails if the widget
+    /// is locked.
+    pub fn set_node_metadata(
+        &mut self,
+        id: &WidgetId,
+        mut metadata: HashMap<String, serde_json::Value>,
+    ) -> Result<()> {
+        self.ensure_unlocked(id)?;
         let node = self
+            .serialized
+            .get_node_mut(id)
+            .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?;
+        let locked = node.is_locked();
+        metadata.remove(LOCKED_KEY);
+        metadata.remove(SLOT_KEY);
+        if let Some(slot) = node.metadata.remove(SLOT_KEY) {
+            metadata.insert(SLOT_KEY.to_string(), slot);
+        }
+        node.metadata = metadata;
+        node.set_locked(locked);
+        Ok(())
+    }
+
+    /// Return an error if the widget is locked
+    fn ensure_unlocked(&self, id: &WidgetId) -> Result<()> {
+        if self.is_locked(id) {
+            return Err(Error::InvalidOperation(format!("Widget {} is locked", id)));
+        }
+        Ok(())
+    }
+
+    /// Duplicate a widget and its subtree, inserting the copy right after the original
+    ///
+    /// Returns the id of the copy.
+    pub fn duplicate_widget(&mut self, id: &WidgetId) -> Result<WidgetId> {
+        let parent_id = self
             .serialized
             .get_node(id)
             .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?
-            .clone();
+            .parent;
+        let siblings = match parent_id {
+            Some(parent_id) => {
+                &self
+                    .serialized
+                    .get_node(&parent_id)
+                    .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?
+                    .children
+            }
+            None => &self.serialized.root_nodes,
+        };
+        let pos = siblings
+            .iter()
+            .position(|sibling_id| sibling_id == id)
+            .ok_or_else(|| Error::InvalidOperation("Widget not found in parent".to_string()))?;
+        self.duplicate_widget_into(id, parent_id, pos + 1)
+    }
 
-        // Remove from parent or root
-        if let Some(parent_id) = node.parent {
-            if let Some(parent) = self.serialized.get_node_mut(&parent_id) {
-                parent.remove_child(id);
+    /// Duplicate a widget and its subtree at `position` under `new_parent` (or the root list)
+    ///
+    /// Every node in the copy gets a fresh id, and anchor ids are dropped so they
+    /// stay unique. Returns the id of the copy. Fails if `new_parent` is locked.
+    pub fn duplicate_widget_into(
+        &mut self,
+        id: &WidgetId,
+        new_parent: Option<WidgetId>,
+        position: usize,
+    ) -> Result<WidgetId> {
+        if !self.serialized.nodes.contains_key(id) {
+            return Err(Error::WidgetNotFound(id.to_string()));
+        }
+        if let Some(parent_id) = new_parent {
+            if !self.serialized.nodes.contains_key(&parent_id) {
+                return Err(Error::WidgetNotFound(parent_id.to_string()));
             }
-        } else {
-            self.serialized.root_nodes.retain(|root_id| root_id != id);
+            self.ensure_unlocked(&parent_id)?;
         }
+        self.ensure_within_max_depth(new_parent.as_ref(), self.subtree_height(id))?;
 
-        // Recursively remove children
-        for child_id in &node.children {
-            self.remove_widget(child_id)?;
+        let copy_id = self.copy_subtree(id, new_parent);
+        // The original keeps its slot; the copy lands in the free child list
+        if let Some(node) = self.serialized.get_node_mut(&copy_id) {
+            node.metadata.remove(SLOT_KEY);
         }
+        let siblings = match new_parent {
+            Some(parent_id) => {
+                &mut self
+                    .serialized
+                    .get_node_mut(&parent_id)
+                    .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?
+                    .children
+            }
+            None => &mut self.serialized.root_nodes,
+        };
+        siblings.insert(position.min(siblings.len()), copy_id);
 
-        // Remove the node itself
-        self.serialized.remove_node(id);
+        Ok(copy_id)
+    }
+
+    /// Add copies of a node and its descendants with fresh ids, returning the new root id
+    ///
+    /// The copied root is not linked into its parent's children.
+    fn copy_subtree(&mut self, id: &WidgetId, parent: Option<WidgetId>) -> WidgetId {
+        let copy_id = WidgetId::new_v4();
+        let Some(mut node) = self.serialized.get_node(id).cloned() else {
+            return copy_id;
+        };
+        node.config.properties.remove(ELEMENT_ID);
+        node.parent = parent;
+        node.children = node
+            .children
+            .iter()
+            .map(|child_id| self.copy_subtree(child_id, Some(copy_id)))
+            .collect();
+        self.serialized.add_node(copy_id, node);
+        copy_id
+    }
+
+    /// Wrap a widget in a new container placed at the widget's position
+    ///
+    /// Returns the id of the new container. Fails if the widget or its parent is locked.
+    pub fn wrap_widget(
+        &mut self,
+        id: &WidgetId,
+        container_config: WidgetConfig,
+    ) -> Result<WidgetId> {
+        let parent_id = self
+            .serialized
+            .get_node(id)
+            .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?
+            .parent;
+        self.ensure_unlocked(id)?;
+        if let Some(parent_id) = parent_id {
+            self.ensure_unlocked(&parent_id)?;
+        }
+        // The wrapped subtree moves one level deeper
+        self.ensure_within_max_depth(parent_id.as_ref(), self.subtree_height(id) + 1)?;
+
+        let container_id = WidgetId::new_v4();
+        let siblings = match parent_id {
+            Some(parent_id) => {
+                &mut self
+                    .serialized
+                    .get_node_mut(&parent_id)
+                    .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?
+                    .children
+            }
+            None => &mut self.serialized.root_nodes,
+        };
+        let pos = siblings
+            .iter()
+            .position(|sibling_id| sibling_id == id)
+            .ok_or_else(|| Error::InvalidOperation("Widget not found in parent".to_string()))?;
+        siblings[pos] = container_id;
+
+        let mut container = LayoutNode::new(container_config);
+        container.parent = parent_id;
+        container.add_child(*id);
+        self.serialized.add_node(container_id, container);
+
+        if let Some(node) = self.serialized.get_node_mut(id) {
+            node.parent = Some(container_id);
+        }
+
+        Ok(container_id)
+    }
+
+    /// Change a widget into another registered widget type, keeping its id and place
+    ///
+    /// The new config starts from the new type's `default_config`. Properties
+    /// whose keys the new type also has, the editor-managed placement keys
+    /// (anchor id, visibility, grid placement), and all inline styles, responsive
+    /// styles, and classes carry over. Other properties are dropped. Fails if the
+    /// widget is locked, or if it has children the new type can't hold.
+    pub fn convert_widget(
+        &mut self,
+        id: &WidgetId,
+        new_type: &str,
+        registry: &WidgetRegistry,
+    ) -> Result<()> {
+        self.ensure_unlocked(id)?;
+        let node = self
+            .serialized
+            .get_node_mut(id)
+            .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?;
+        let widget = registry.create_widget(new_type)?;
+        if !node.children.is_empty() && !widget.can_have_children() {
+            return Err(Error::InvalidOperation(format!(
+                "Widget {} has children and can't become a {}",
+                id, new_type
+            )));
+        }
 
+        let mut config = widget.default_config();
+        config.widget_type = new_type.to_string();
+        for (key, value) in &node.config.properties {
+            let placement = [
+                ELEMENT_ID,
+                VISIBILITY,
+                GRID_COLUMN_SPAN,
+                GRID_ROW_SPAN,
+                GRID_AREA,
+            ]
+            .contains(&key.as_str());
+            if placement || config.properties.contains_key(key) {
+                config.properties.insert(key.clone(), value.clone());
+            }
+        }
+        config.css_classes = std::mem::take(&mut node.config.css_classes);
+        config.inline_styles = std::mem::take(&mut node.config.inline_styles);
+        config.responsive_styles = std::mem::take(&mut node.config.responsive_styles);
+        node.config = config;
         Ok(())
     }
 
-    /// Move a widget up in its parent's children list (or root list)
-    pub fn move_widget_up(&mut self, id: &WidgetId) -> Result<()> {
+    /// Replace a container with its children, keeping their order
+    ///
+    /// Only widgets whose registered type can have children can be unwrapped.
+    /// Fails if the container, its parent, or any of its children is locked.
+    pub fn unwrap_container(&mut self, id: &WidgetId, registry: &WidgetRegistry) -> Result<()> {
         let node = self
             .serialized
             .get_node(id)
             .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?
             .clone();
-
-        // Determine if this is a root widget or has a parent
+        if !registry
+            .create_widget(&node.config.widget_type)
+            .is_ok_and(|widget| widget.can_have_children())
+        {
+            return Err(Error::InvalidOperation(format!(
+                "Widget {} is not a container",
+                id
+            )));
+        }
+        self.ensure_unlocked(id)?;
         if let Some(parent_id) = node.parent {
-            // Move within parent's children
-            let parent = self
-                .serialized
-                .get_node_mut(&parent_id)
-                .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?;
-
-            let pos = parent
-                .children
-                .iter()
-                .position(|child_id| child_id == id)
-                .ok_or_else(|| Error::InvalidOperation("Widget not found in parent".to_string()))?;
+            self.ensure_unlocked(&parent_id)?;
+        }
+        for child_id in &node.children {
+            self.ensure_unlocked(child_id)?;
+        }
 
-            if pos > 0 {
-                parent.children.swap(pos - 1, pos);
+        let siblings = match node.parent {
+            Some(parent_id) => {
+                &mut self
+                    .serialized
+                    .get_node_mut(&parent_id)
+                    .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?
+                    .children
             }
-        } else {
-            // Move within root nodes
-            let pos = self
-                .serialized
-                .root_nodes
-                .iter()
-                .position(|root_id| root_id == id)
-                .ok_or_else(|| Error::InvalidOperation("Widget not found in roots".to_string()))?;
+            None => &mut self.serialized.root_nodes,
+        };
+        let pos = siblings
+            .iter()
+            .position(|sibling_id| sibling_id == id)
+            .ok_or_else(|| Error::InvalidOperation("Widget not found in parent".to_string()))?;
+        siblings.splice(pos..=pos, node.children.iter().copied());
 
-            if pos > 0 {
-                self.serialized.root_nodes.swap(pos - 1, pos);
+        for child_id in &node.children {
+            if let Some(child) = self.serialized.get_node_mut(child_id) {
+                child.parent = node.parent;
+                // The container's slots go away with it
+                child.metadata.remove(SLOT_KEY);
             }
         }
+        self.serialized.remove_node(id);
 
         Ok(())
     }
 
-    /// Move a widget down in its parent's children list (or root list)
-    pub fn move_widget_down(&mut self, id: &WidgetId) -> Result<()> {
+    /// Remove a widget and its children
+    ///
+    /// Fails if the widget or any of its descendants is locked.
+    pub fn remove_widget(&mut self, id: &WidgetId) -> Result<()> {
+        if !self.serialized.nodes.contains_key(id) {
+            return Err(Error::WidgetNotFound(id.to_string()));
+        }
+        self.ensure_unlocked(id)?;
+        for descendant in self.descendants(id) {
+            self.ensure_unlocked(&descendant)?;
+        }
+        self.remove_subtree(id)
+    }
+
+    /// Remove a widget and its children without lock checks
+    fn remove_subtree(&mut self, id: &WidgetId) -> Result<()> {
         let node = self
             .serialized
             .get_node(id)
             .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?
             .clone();
 
-        // Determine if this is a root widget or has a parent
+        // Remove from parent or root
         if let Some(parent_id) = node.parent {
-            // Move within parent's children
-            let parent = self
-                .serialized
-                .get_node_mut(&parent_id)
-                .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?;
-
-            let pos = parent
-                .children
-                .iter()
-                .position(|child_id| child_id == id)
-                .ok_or_else(|| Error::InvalidOperation("Widget not found in parent".to_string()))?;
-
-            if pos < parent.children.len() - 1 {
-                parent.children.swap(pos, pos + 1);
+            if let Some(parent) = self.serialized.get_node_mut(&parent_id) {
+                parent.remove_child(id);
             }
         } else {
-            // Move within root nodes
-            let pos = self
-                .serialized
-                .root_nodes
-                .iter()
-                .position(|root_id| root_id == id)
-                .ok_or_else(|| Error::InvalidOperation("Widget not found in roots".to_string()))?;
+            self.serialized.root_nodes.retain(|root_id| root_id != id);
+        }
 
-            if pos < self.serialized.root_nodes.len() - 1 {
-                self.serialized.root_nodes.swap(pos, pos + 1);
-            }
+        // Recursively remove children
+        for child_id in &node.children {
+            self.remove_subtree(child_id)?;
         }
 
+        // Remove the node itself
+        self.serialized.remove_node(id);
+
         Ok(())
     }
 
+    /// Get all descendants of a widget in depth-first order (excluding the widget itself)
+    pub fn descendants(&self, id: &WidgetId) -> Vec<WidgetId> {
+        let mut result = Vec::new();
+        // Each node is visited once, so a cycle can't loop forever
+        let mut visited = HashSet::from([*id]);
+        let mut stack: Vec<WidgetId> = self
+            .serialized
+            .get_node(id)
+            .map(|node| node.children.iter().rev().copied().collect())
+            .unwrap_or_default();
+
+        while let Some(current) = stack.pop() {
+            if !visited.insert(current) {
+                continue;
+            }
+            result.push(current);
+            if let Some(node) = self.serialized.get_node(&current) {
+                stack.extend(node.children.iter().rev().copied());
+            }
+        }
+
+        result
+    }
+
+    /// Get all widgets reachable from the roots in document (depth-first) order
+    pub fn widgets_in_order(&self) -> Vec<WidgetId> {
+        let mut result = Vec::new();
+        for root_id in &self.serialized.root_nodes {
+            if self.serialized.nodes.contains_key(root_id) {
+                result.push(*root_id);
+                result.extend(self.descendants(root_id));
+            }
+        }
+        result
+    }
+
+    /// Check whether two layouts have the same tree and widget configurations
+    ///
+    /// Widget IDs are ignored: nodes are matched by their position among their
+    /// siblings. Node metadata is not compared.
+    pub fn structurally_eq(&self, other: &Layout) -> bool {
+        fn subtrees_eq(
+            ours: &Layout,
+            our_ids: &[WidgetId],
+            theirs: &Layout,
+            their_ids: &[WidgetId],
+        ) -> bool {
+            our_ids.len() == their_ids.len()
+                && our_ids.iter().zip(their_ids).all(|(our_id, their_id)| {
+                    match (ours.get_widget(our_id), theirs.get_widget(their_id)) {
+                        (Some(our_node), Some(their_node)) => {
+                            our_node.config == their_node.config
+                                && subtrees_eq(
+                                    ours,
+                                    &our_node.children,
+                                    theirs,
+                                    &their_node.children,
+                                )
+                        }
+                        (None, None) => true,
+                        _ => false,
+                    }
+                })
+        }
+
+        subtrees_eq(self, self.root_widgets(), other, other.root_widgets())
+    }
+
+    /// Get a widget's depth in the tree: 1 for root widgets, 0 if it doesn't exist
+    pub fn depth_of(&self, id: &WidgetId) -> usize {
+        let mut depth = 0;
+        let mut current = self.serialized.get_node(id).map(|_| *id);
+        while let Some(current_id) = current {
+            depth += 1;
+            // Guard against parent cycles in hand-edited layouts
+            if depth > self.serialized.nodes.len() {
+                break;
+            }
+            current = self
+                .serialized
+                .get_node(&current_id)
+                .and_then(|node| node.parent);
+        }
+        depth
+    }
+
+    /// Get the number of levels in a widget's subtree: 1 for a widget without children
+    pub fn subtree_height(&self, id: &WidgetId) -> usize {
+        let Some(node) = self.serialized.get_node(id) else {
+            return 0;
+        };
+        let mut height = 1;
+        let mut visited = HashSet::from([*id]);
+        let mut stack: Vec<(WidgetId, usize)> =
+            node.children.iter().map(|child| (*child, 2)).collect();
+        while let Some((current, depth)) = stack.pop() {
+            if !visited.insert(current) {
+                continue;
+            }
+            if let Some(node) = self.serialized.get_node(&current) {
+                height = height.max(depth);
+                stack.extend(node.children.iter().map(|child| (*child, depth + 1)));
+            }
+        }
+        height
+    }
+
+    /// Check that a subtree `subtree_height` levels tall fits under `parent_id`
+    /// (or at the root) without nesting deeper than `max_depth`
+    ///
+    /// Returns `Error::InvalidOperation` when it doesn't fit.
+    pub fn check_max_depth(
+        &self,
+        parent_id: Option<&WidgetId>,
+        subtree_height: usize,
+        max_depth: usize,
+    ) -> Result<()> {
+        let parent_depth = parent_id.map_or(0, |id| self.depth_of(id));
+        if parent_depth + subtree_height > max_depth {
+            return Err(Error::InvalidOperation(format!(
+                "Widgets can't be nested more than {} levels deep",
+                max_depth
+            )));
+        }
+        Ok(())
+    }
+
+    /// Compute widget counts and nesting depth by walking the tree from the roots
+    pub fn stats(&self) -> LayoutStats {
+        let mut stats = LayoutStats::default();
+        let mut stack: Vec<(WidgetId, usize)> = self
+            .serialized
+            .root_nodes
+            .iter()
+            .map(|id| (*id, 1))
+            .collect();
+        let mut visited = HashSet::new();
+
+        while let Some((id, depth)) = stack.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            let Some(node) = self.serialized.get_node(&id) else {
+                continue;
+            };
+            if depth == 1 {
+                stats.root_count += 1;
+            }
+            stats.total_widgets += 1;
+            *stats
+                .widgets_by_type
+                .entry(node.config.widget_type.clone())
+                .or_default() += 1;
+            stats.max_depth = stats.max_depth.max(depth);
+            stack.extend(node.children.iter().map(|child| (*child, depth + 1)));
+        }
+
+        stats
+    }
+
+    /// Remove every node that no root reaches, returning how many were removed
+    ///
+    /// Loading keeps such nodes so that no data is silently dropped; call this to
+    /// discard them, e.g. after importing a layout from elsewhere.
+    pub fn prune_orphans(&mut self) -> usize {
+        let reachable: HashSet<WidgetId> = self.widgets_in_order().into_iter().collect();
+        let before = self.serialized.nodes.len();
+        self.serialized.nodes.retain(|id, _| reachable.contains(id));
+        before - self.serialized.nodes.len()
+    }
+
+    /// Move every node's inline and responsive styles into a generated class and collect them into a stylesheet
+    ///
+    /// Each styled node gets the class from [`widget_class_name`], derived from its widget ID,
+    /// so the class names are stable across exports. Breakpoint styles become `@media` rules
+    /// for the same class. The original layout is left untouched.
+    pub fn extract_stylesheet(&self) -> ExtractedStyles {
+        let mut layout = self.clone();
+        let mut stylesheet = String::new();
+
+        for id in self.widgets_in_order() {
+            let Some(node) = layout.serialized.get_node_mut(&id) else {
+                continue;
+            };
+            if node.config.inline_styles.is_empty() && node.config.responsive_styles.is_empty() {
+                continue;
+            }
+
+            let class = widget_class_name(&id);
+            if !node.config.inline_styles.is_empty() {
+                // Keep declaration order so shorthand/longhand overrides behave as they did inline
+                let declarations: Vec<_> = node.config.inline_styles.drain(..).collect();
+
+                stylesheet.push_str(&format!(".{} {{\n", class));
+                for (property, value) in declarations {
+                    stylesheet.push_str(&format!("  {}: {};\n", property, value));
+                }
+                stylesheet.push_str("}\n");
+            }
+            stylesheet.push_str(&node.config.responsive_css(&class));
+            node.config.responsive_styles.clear();
+
+            node.config.css_classes.push(class);
+        }
+
+        ExtractedStyles { layout, stylesheet }
+    }
+
+    /// Merge another layout into this one without a common base
+    ///
+    /// Widgets present in only one layout are kept. New widgets from `other` are
+    /// placed after their nearest preceding sibling that already exists, or first
+    /// if there is none. For widgets present in both, this layout's placement and
+    /// configuration win; differing configurations are reported as conflicts so
+    /// the caller can resolve them. The result is deterministic.
+    pub fn merge(&self, other: &Layout) -> MergeResult {
+        let mut merged = self.clone();
+        let mut conflicts = Vec::new();
+
+        // Parents come before their children, so new subtrees attach correctly
+        for id in other.widgets_in_order() {
+            let Some(theirs) = other.serialized.get_node(&id) else {
+                continue;
+            };
+
+            if let Some(ours) = merged.serialized.get_node_mut(&id) {
+                if ours.config != theirs.config {
+                    conflicts.push(MergeConflict {
+                        id,
+                        ours: ours.config.clone(),
+                        theirs: theirs.config.clone(),
+                    });
+                }
+                for (key, value) in &theirs.metadata {
+                    ours.metadata
+                        .entry(key.clone())
+                        .or_insert_with(|| value.clone());
+                }
+                continue;
+            }
+
+            let parent = theirs
+                .parent
+                .filter(|parent_id| merged.serialized.nodes.contains_key(parent_id));
+            let their_siblings = match theirs.parent {
+                Some(parent_id) => other
+                    .serialized
+                    .get_node(&parent_id)
+                    .map(|node| node.children.as_slice())
+                    .unwrap_or_default(),
+                None => other.serialized.root_nodes.as_slice(),
+            };
+            let our_siblings = match parent {
+                Some(parent_id) => {
+                    &mut merged
+                        .serialized
+                        .get_node_mut(&parent_id)
+                        .expect("parent exists")
+                        .children
+                }
+                None => &mut merged.serialized.root_nodes,
+            };
+
+            let index = their_siblings
+                .iter()
+                .position(|sibling| *sibling == id)
+                .unwrap_or(their_siblings.len());
+            let preceding = their_siblings[..index]
+                .iter()
+                .rev()
+                .find_map(|sibling| our_siblings.iter().position(|s| s == sibling));
+            let position = preceding.map_or(0, |pos| pos + 1);
+            our_siblings.insert(position, id);
+
+            let mut node = theirs.clone();
+            node.parent = parent;
+            // Children are attached as they are visited
+            node.children.clear();
+            merged.serialized.add_node(id, node);
+        }
+
+        for (key, value) in &other.serialized.metadata {
+            merged
+                .serialized
+                .metadata
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+
+        MergeResult {
+            layout: merged,
+            conflicts,
+        }
+    }
+
+    /// Add all of another layout's widgets after this layout's root widgets
+    ///
+    /// Returns the IDs of the appended root widgets. Fails without changing
+    /// anything if a widget ID is already used in this layout, or if the
+    /// widgets nest deeper than the layout's limit.
+    pub fn append(&mut self, other: Layout) -> Result<Vec<WidgetId>> {
+        let height = other
+            .root_widgets()
+            .iter()
+            .map(|id| other.subtree_height(id))
+            .max()
+            .unwrap_or(0);
+        self.ensure_within_max_depth(None, height)?;
+        if let Some(id) = other
+            .serialized
+            .nodes
+            .keys()
+            .find(|id| self.serialized.nodes.contains_key(id))
+        {
+            return Err(Error::InvalidOperation(format!(
+                "Widget {} is already in the layout",
+                id
+            )));
+        }
+        let roots = other.serialized.root_nodes;
+        self.serialized.root_nodes.extend(roots.iter().copied());
+        self.serialized.nodes.extend(other.serialized.nodes);
+        Ok(roots)
+    }
+
+    /// Move a widget to `position` under `new_parent` (or the root list when `None`)
+    ///
+    /// `position` is an index into the target list before the widget is taken out,
+    /// matching the drop zones rendered between widgets. Fails if the widget or
+    /// either parent is locked, or if the target is the widget itself or one of
+    /// its descendants.
+    pub fn move_widget(
+        &mut self,
+        id: &WidgetId,
+        new_parent: Option<WidgetId>,
+        position: usize,
+    ) -> Result<()> {
+        let old_parent = self
+            .serialized
+            .get_node(id)
+            .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?
+            .parent;
+        self.ensure_unlocked(id)?;
+        if let Some(parent_id) = old_parent {
+            self.ensure_unlocked(&parent_id)?;
+        }
+        if let Some(parent_id) = new_parent {
+            if !self.serialized.nodes.contains_key(&parent_id) {
+                return Err(Error::WidgetNotFound(parent_id.to_string()));
+            }
+            if parent_id == *id || self.descendants(id).contains(&parent_id) {
+                return Err(Error::InvalidOperation(
+                    "Cannot move a widget into itself".to_string(),
+                ));
+            }
+            self.ensure_within_max_depth(Some(&parent_id), self.subtree_height(id))?;
+            self.ensure_unlocked(&parent_id)?;
+        }
+
+        let old_siblings = match old_parent {
+            Some(parent_id) => {
+                &mut self
+                    .serialized
+                    .get_node_mut(&parent_id)
+                    .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?
+                    .children
+            }
+            None => &mut self.serialized.root_nodes,
+        };
+        let old_pos = old_siblings
+            .iter()
+            .position(|sibling_id| sibling_id == id)
+            .ok_or_else(|| Error::InvalidOperation("Widget not found in parent".to_string()))?;
+        old_siblings.remove(old_pos);
+
+        let mut position = position;
+        if old_parent == new_parent && old_pos < position {
+            position -= 1;
+        }
+        let new_siblings = match new_parent {
+            Some(parent_id) => {
+                &mut self
+                    .serialized
+                    .get_node_mut(&parent_id)
+                    .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?
+                    .children
+            }
+            None => &mut self.serialized.root_nodes,
+        };
+        new_siblings.insert(position.min(new_siblings.len()), *id);
+
+        // Dropping between widgets takes the widget out of any slot it filled
+        if let Some(node) = self.serialized.get_node_mut(id) {
+            node.parent = new_parent;
+            node.metadata.remove(SLOT_KEY);
+        }
+
+        Ok(())
+    }
+
+    /// Move a widget up in its parent's children list (or root list)
+    pub fn move_widget_up(&mut self, id: &WidgetId) -> Result<()> {
+        self.ensure_unlocked(id)?;
+        let node = self
+            .serialized
+            .get_node(id)
+            .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?
+            .clone();
+
+        // Determine if this is a root widget or has a parent
+        if let Some(parent_id) = node.parent {
+            // Move within parent's children
+            let parent = self
+                .serialized
+                .get_node_mut(&parent_id)
+                .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?;
+
+            let pos = parent
+                .children
+                .iter()
+                .position(|child_id| child_id == id)
+                .ok_or_else(|| Error::InvalidOperation("Widget not found in parent".to_string()))?;
+
+            if pos > 0 {
+                parent.children.swap(pos - 1, pos);
+            }
+        } else {
+            // Move within root nodes
+            let pos = self
+                .serialized
+                .root_nodes
+                .iter()
+                .position(|root_id| root_id == id)
+                .ok_or_else(|| Error::InvalidOperation("Widget not found in roots".to_string()))?;
+
+            if pos > 0 {
+                self.serialized.root_nodes.swap(pos - 1, pos);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Move a widget down in its parent's children list (or root list)
+    pub fn move_widget_down(&mut self, id: &WidgetId) -> Result<()> {
+        self.ensure_unlocked(id)?;
+        let node = self
+            .serialized
+            .get_node(id)
+            .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?
+            .clone();
+
+        // Determine if this is a root widget or has a parent
+        if let Some(parent_id) = node.parent {
+            // Move within parent's children
+            let parent = self
+                .serialized
+                .get_node_mut(&parent_id)
+                .ok_or_else(|| Error::WidgetNotFound(parent_id.to_string()))?;
+
+            let pos = parent
+                .children
+                .iter()
+                .position(|child_id| child_id == id)
+                .ok_or_else(|| Error::InvalidOperation("Widget not found in parent".to_string()))?;
+
+            if pos < parent.children.len() - 1 {
+                parent.children.swap(pos, pos + 1);
+            }
+        } else {
+            // Move within root nodes
+            let pos = self
+                .serialized
+                .root_nodes
+                .iter()
+                .position(|root_id| root_id == id)
+                .ok_or_else(|| Error::InvalidOperation("Widget not found in roots".to_string()))?;
+
+            if pos < self.serialized.root_nodes.len() - 1 {
+                self.serialized.root_nodes.swap(pos, pos + 1);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Generate Rust source for an `html!` block reproducing the layout
+    ///
+    /// Widgets without a source form (including types missing from the registry)
+    /// become a fragment holding a comment and their children. Edit-only widgets
+    /// are left out along with their subtrees.
+    pub fn to_yew_source(&self, registry: &WidgetRegistry) -> Result<String> {
+        let roots = self
+            .serialized
+            .root_nodes
+            .iter()
+            .filter(|id| self.is_exported(id))
+            .map(|id| self.node_yew_source(id, registry))
+            .collect::<Result<Vec<_>>>()?;
+
+        let body = match roots.as_slice() {
+            [] => return Ok("html! {}\n".to_string()),
+            [root] => root.clone(),
+            _ => yew_source::fragment(&roots),
+        };
+        Ok(format!("html! {{\n{}\n}}\n", yew_source::indent(&body)))
+    }
+
+    /// Check whether a widget belongs in exported output, i.e. isn't edit-only
+    fn is_exported(&self, id: &WidgetId) -> bool {
+        self.serialized
+            .get_node(id)
+            .is_none_or(|node| node.config.visibility().is_visible(false))
+    }
+
+    /// Generate the source for one node and its subtree
+    fn node_yew_source(&self, id: &WidgetId, registry: &WidgetRegistry) -> Result<String> {
+        let node = self
+            .serialized
+            .get_node(id)
+            .ok_or_else(|| Error::WidgetNotFound(id.to_string()))?;
+        let children = node
+            .children
+            .iter()
+            .filter(|child_id| self.is_exported(child_id))
+            .map(|child_id| self.node_yew_source(child_id, registry))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Responsive styles need a generated class and a stylesheet next to the widget
+        let mut config = node.config.clone();
+        let responsive_css = (!config.responsive_styles.is_empty()).then(|| {
+            let class = widget_class_name(id);
+            let css = config.responsive_css(&class);
+            config.css_classes.push(class);
+            css
+        });
+
+        let source = registry
+            .create_widget(&config.widget_type)
+            .ok()
+            .and_then(|widget| widget.to_yew_source(&config, &children));
+        let source = match source {
+            Some(source) => source,
+            None => {
+                let mut items = vec![format!("// Unsupported widget: {}", config.widget_type)];
+                items.extend(children);
+                yew_source::fragment(&items)
+            }
+        };
+
+        // The anchor id goes on the widget's root element, and on a wrapper (with the
+        // grid placement) only when there is no single root, matching the canvas
+        let mut wrapper_attributes = Vec::new();
+        let source = match config.element_id() {
+            Some(element_id) => {
+                match yew_source::with_root_attribute(
+                    &source,
+                    "id",
+                    yew_source::literal(element_id),
+                ) {
+                    Some(source) => source,
+                    None => {
+                        wrapper_attributes.push(("id", yew_source::literal(element_id)));
+                        source
+                    }
+                }
+            }
+            None => source,
+        };
+        let grid_style = config.grid_item_style();
+        if !grid_style.is_empty() {
+            wrapper_attributes.push(("style", yew_source::literal(&grid_style)));
+        }
+        let source = if wrapper_attributes.is_empty() {
+            source
+        } else {
+            yew_source::element("div", &wrapper_attributes, &[source])
+        };
+
+        Ok(match responsive_css {
+            Some(css) => yew_source::fragment(&[
+                yew_source::element("style", &[], &[yew_source::text(&css)]),
+                source,
+            ]),
+            None => source,
+        })
+    }
+
     /// Get root widget IDs
     pub fn root_widgets(&self) -> &[WidgetId] {
         &self.serialized.root_nodes
@@ -365,6 +1662,16 @@ impl Layout {
         self.serialized.to_json_pretty()
     }
 
+    /// Serialize to pretty JSON with sorted keys (see [`SerializedLayout::to_json_sorted`])
+    pub fn to_json_sorted(&self) -> Result<String> {
+        self.serialized.to_json_sorted()
+    }
+
+    /// Serialize to sorted JSON indented with `indent` (see [`SerializedLayout::to_json_sorted`])
+    pub fn to_json_sorted_with_indent(&self, indent: &str) -> Result<String> {
+        self.serialized.to_json_sorted_with_indent(indent)
+    }
+
     /// Deserialize from JSON
     pub fn from_json(json: &str) -> Result<Self> {
         let serialized = SerializedLayout::from_json(json)?;
@@ -378,9 +1685,123 @@ impl Default for Layout {
     }
 }
 
+/// Fluent builder for constructing a [`Layout`] in code
+///
+/// IDs are generated for every widget. Give a widget a name to look its ID up
+/// in the map returned by [`LayoutBuilder::build`].
+///
+/// ```
+/// use serde_json::json;
+/// use yew_wysiwyg::{LayoutBuilder, WidgetConfig};
+///
+/// let mut builder = LayoutBuilder::new();
+/// builder
+///     .widget(WidgetConfig::new("text.heading").with_property("content", json!("Welcome")))
+///     .named_container("columns", "container.row", |row| {
+///         row.named_container("left", "container.column", |column| {
+///             column.widget(
+///                 WidgetConfig::new("text.paragraph").with_property("content", json!("Left")),
+///             );
+///         })
+///         .named_container("right", "container.column", |column| {
+///             column.widget(
+///                 WidgetConfig::new("text.paragraph").with_property("content", json!("Right")),
+///             );
+///         });
+///     });
+/// let (layout, names) = builder.build();
+///
+/// assert_eq!(layout.root_widgets().len(), 2);
+/// let columns = layout.get_widget(&names["columns"]).unwrap();
+/// assert_eq!(columns.children, vec![names["left"], names["right"]]);
+/// ```
+#[derive(Debug, Default)]
+pub struct LayoutBuilder {
+    layout: Layout,
+    parent: Option<WidgetId>,
+    names: HashMap<String, WidgetId>,
+}
+
+impl LayoutBuilder {
+    /// Create a builder for an empty layout
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a widget to the current container (or as a root widget)
+    pub fn widget(&mut self, config: impl Into<WidgetConfig>) -> &mut Self {
+        self.insert(config.into());
+        self
+    }
+
+    /// Add a widget and record its ID under `name`
+    pub fn named_widget(
+        &mut self,
+        name: impl Into<String>,
+        config: impl Into<WidgetConfig>,
+    ) -> &mut Self {
+        let id = self.insert(config.into());
+        self.names.insert(name.into(), id);
+        self
+    }
+
+    /// Add a container and build its children with `children`
+    pub fn container(
+        &mut self,
+        config: impl Into<WidgetConfig>,
+        children: impl FnOnce(&mut LayoutBuilder),
+    ) -> &mut Self {
+        let id = self.insert(config.into());
+        self.with_parent(id, children);
+        self
+    }
+
+    /// Add a container, record its ID under `name`, and build its children
+    pub fn named_container(
+        &mut self,
+        name: impl Into<String>,
+        config: impl Into<WidgetConfig>,
+        children: impl FnOnce(&mut LayoutBuilder),
+    ) -> &mut Self {
+        let id = self.insert(config.into());
+        self.names.insert(name.into(), id);
+        self.with_parent(id, children);
+        self
+    }
+
+    /// Finish building, returning the layout and the IDs of named widgets
+    pub fn build(self) -> (Layout, HashMap<String, WidgetId>) {
+        (self.layout, self.names)
+    }
+
+    fn insert(&mut self, config: WidgetConfig) -> WidgetId {
+        let id = WidgetId::new_v4();
+        match self.parent {
+            Some(parent_id) => {
+                // Builder nodes are never locked, so the parent always accepts children
+                if let Some(parent) = self.layout.serialized.get_node_mut(&parent_id) {
+                    parent.add_child(id);
+                }
+                let mut node = LayoutNode::new(config);
+                node.parent = Some(parent_id);
+                self.layout.serialized.add_node(id, node);
+            }
+            None => self.layout.add_root_widget(id, config),
+        }
+        id
+    }
+
+    fn with_parent(&mut self, parent_id: WidgetId, children: impl FnOnce(&mut LayoutBuilder)) {
+        let previous = self.parent.replace(parent_id);
+        children(self);
+        self.parent = previous;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::widget::is_valid_element_id;
 
     #[test]
     fn test_layout_serialization() {
@@ -395,4 +1816,1132 @@ mod tests {
 
         assert_eq!(deserialized.root_widgets().len(), 1);
     }
+
+    #[test]
+    fn test_empty_fields_are_omitted() {
+        let mut layout = Layout::new();
+        let root = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+        layout.add_root_widget(root, WidgetConfig::new("container"));
+        layout
+            .add_child_widget(root, child, WidgetConfig::new("test"))
+            .unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&layout.to_json().unwrap()).unwrap();
+        assert!(json.get("metadata").is_none());
+        let root_node = &json["nodes"][root.to_string()];
+        assert!(root_node.get("parent").is_none());
+        assert!(root_node.get("metadata").is_none());
+        assert_eq!(
+            root_node["config"],
+            serde_json::json!({ "widget_type": "container" })
+        );
+        assert!(json["nodes"][child.to_string()].get("children").is_none());
+
+        // The compact form still loads with defaults filled in
+        let restored = Layout::from_json(&json.to_string()).unwrap();
+        assert_eq!(restored, layout);
+
+        // Older exports that include the empty fields keep loading too
+        let verbose = serde_json::json!({
+            "version": "1.0",
+            "root_nodes": [root],
+            "nodes": {
+                root.to_string(): {
+                    "config": {
+                        "widget_type": "container",
+                        "properties": {},
+                        "css_classes": [],
+                        "inline_styles": {}
+                    },
+                    "children": [],
+                    "parent": null,
+                    "metadata": {}
+                }
+            },
+            "metadata": {}
+        });
+        assert!(Layout::from_json(&verbose.to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_to_json_sorted() {
+        let mut layout = Layout::new();
+        let root = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+        let mut config = WidgetConfig::new("container")
+            .with_property("zeta", serde_json::json!({ "b": 1, "a": 2 }))
+            .with_property("alpha", serde_json::json!(true))
+            .with_class("card")
+            .with_style("padding-top", "0")
+            .with_style("padding", "8px");
+        config.responsive_styles.insert(
+            Breakpoint::Md,
+            IndexMap::from([("gap".to_string(), "4px".to_string())]),
+        );
+        layout.add_root_widget(root, config);
+        layout
+            .add_child_widget(root, child, WidgetConfig::new("test"))
+            .unwrap();
+        layout
+            .set_node_metadata(
+                &child,
+                HashMap::from([
+                    ("note".to_string(), serde_json::json!("x")),
+                    ("author".to_string(), serde_json::json!("y")),
+                ]),
+            )
+            .unwrap();
+        layout
+            .to_serialized_mut()
+            .metadata
+            .insert("title".to_string(), serde_json::json!("Page"));
+
+        let sorted = layout.to_json_sorted().unwrap();
+        // Same content as the regular export, and it loads back unchanged
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&sorted).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&layout.to_json().unwrap()).unwrap()
+        );
+        assert_eq!(Layout::from_json(&sorted).unwrap(), layout);
+
+        // Keys are sorted, styles keep their order
+        assert!(sorted.find("\"alpha\"").unwrap() < sorted.find("\"zeta\"").unwrap());
+        assert!(sorted.find("\"a\"").unwrap() < sorted.find("\"b\"").unwrap());
+        assert!(sorted.find("\"padding-top\"").unwrap() < sorted.find("\"padding\"").unwrap());
+        let (first, second) = if root < child {
+            (root, child)
+        } else {
+            (child, root)
+        };
+        assert!(
+            sorted.find(&format!("\"{}\": {{", first)).unwrap()
+                < sorted.find(&format!("\"{}\": {{", second)).unwrap()
+        );
+
+        // A rebuilt copy serializes to the same text
+        let copy = Layout::from_json(&layout.to_json().unwrap()).unwrap();
+        assert_eq!(copy.to_json_sorted().unwrap(), sorted);
+
+        let tabbed = layout.to_json_sorted_with_indent("\t").unwrap();
+        assert!(tabbed.contains("\n\t\"nodes\": {"));
+        assert_eq!(tabbed.replace('\t', "  "), sorted);
+    }
+
+    #[test]
+    fn test_unknown_widget_round_trip() {
+        let root = WidgetId::new_v4();
+        let unknown = WidgetId::new_v4();
+        let unknown_config = serde_json::json!({
+            "widget_type": "custom.not_registered",
+            "properties": {
+                "title": "Hello",
+                "count": 3,
+                "nested": { "items": [1, 2, { "deep": true }], "flag": null }
+            },
+            "css_classes": ["a", "b"],
+            "inline_styles": { "color": "red", "margin": "0 auto" }
+        });
+        let input = serde_json::json!({
+            "version": "1.0",
+            "root_nodes": [root],
+            "nodes": {
+                root.to_string(): {
+                    "config": { "widget_type": "container.column" },
+                    "children": [unknown]
+                },
+                unknown.to_string(): {
+                    "config": unknown_config,
+                    "parent": root,
+                    "metadata": { "note": "keep me" }
+                }
+            }
+        });
+
+        let layout = Layout::from_json(&input.to_string()).unwrap();
+        let exported: serde_json::Value =
+            serde_json::from_str(&layout.to_json_pretty().unwrap()).unwrap();
+
+        let exported_node = &exported["nodes"][unknown.to_string()];
+        // Compare canonical (key-sorted) forms byte for byte
+        assert_eq!(
+            serde_json::to_string(&exported_node["config"]).unwrap(),
+            serde_json::to_string(&unknown_config).unwrap()
+        );
+        assert_eq!(exported_node["metadata"]["note"], "keep me");
+        assert_eq!(exported, input);
+    }
+
+    #[test]
+    fn test_extract_stylesheet() {
+        let mut layout = Layout::new();
+        let id = WidgetId::new_v4();
+        let responsive = WidgetId::new_v4();
+        layout.add_root_widget(
+            id,
+            WidgetConfig::new("test")
+                .with_class("existing")
+                .with_style("padding", "8px")
+                .with_style("color", "red"),
+        );
+        let mut config = WidgetConfig::new("test");
+        config.set_style(Some(Breakpoint::Md), "display", Some("none".to_string()));
+        layout.add_root_widget(responsive, config);
+
+        let extracted = layout.extract_stylesheet();
+        let class = widget_class_name(&id);
+        let responsive_class = widget_class_name(&responsive);
+        let node = extracted.layout.get_widget(&id).unwrap();
+
+        assert!(node.config.inline_styles.is_empty());
+        assert_eq!(
+            node.config.css_classes,
+            vec!["existing".to_string(), class.clone()]
+        );
+        assert_eq!(
+            extracted.stylesheet,
+            format!(
+                ".{} {{\n  padding: 8px;\n  color: red;\n}}\n{}",
+                class,
+                layout
+                    .get_widget(&responsive)
+                    .unwrap()
+                    .config
+                    .responsive_css(&responsive_class)
+            )
+        );
+        assert!(extracted.stylesheet.contains("@media (min-width: 768px)"));
+        let node = extracted.layout.get_widget(&responsive).unwrap();
+        assert!(node.config.responsive_styles.is_empty());
+        assert_eq!(node.config.css_classes, vec![responsive_class]);
+        // The live layout keeps its inline styles
+        assert_eq!(
+            layout.get_widget(&id).unwrap().config.inline_styles.len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_locked_widgets_refuse_edits() {
+        let mut layout = Layout::new();
+        let container = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+        let other = WidgetId::new_v4();
+
+        layout.add_root_widget(container, WidgetConfig::new("container"));
+        layout
+            .add_child_widget(container, child, WidgetConfig::new("test"))
+            .unwrap();
+        layout.add_root_widget(other, WidgetConfig::new("test"));
+
+        layout.set_locked(&child, true).unwrap();
+        assert!(layout.is_locked(&child));
+        assert!(matches!(
+            layout.remove_widget(&child),
+            Err(Error::InvalidOperation(_))
+        ));
+        // Removing an ancestor of a locked widget is refused without partial removal
+        assert!(layout.remove_widget(&container).is_err());
+        assert_eq!(layout.to_serialized().nodes.len(), 3);
+        assert!(layout.move_widget_up(&child).is_err());
+
+        layout.set_locked(&container, true).unwrap();
+        assert!(layout
+            .add_child_widget(container, WidgetId::new_v4(), WidgetConfig::new("test"))
+            .is_err());
+
+        layout.set_locked(&container, false).unwrap();
+        layout.set_locked(&child, false).unwrap();
+        assert!(layout.remove_widget(&container).is_ok());
+        assert_eq!(layout.root_widgets(), &[other]);
+    }
+
+    #[test]
+    fn test_set_node_metadata_keeps_lock() {
+        let mut layout = Layout::new();
+        let id = WidgetId::new_v4();
+        layout.add_root_widget(id, WidgetConfig::new("test"));
+
+        // A `locked` key can't lock the widget
+        let metadata = HashMap::from([
+            ("cms_ref".to_string(), serde_json::json!("page-42")),
+            (LOCKED_KEY.to_string(), serde_json::json!(true)),
+        ]);
+        layout.set_node_metadata(&id, metadata).unwrap();
+
+        let node = layout.to_serialized().nodes[&id].clone();
+        assert!(!node.is_locked());
+        assert_eq!(node.metadata["cms_ref"], "page-42");
+        assert!(layout
+            .set_node_metadata(&WidgetId::new_v4(), HashMap::new())
+            .is_err());
+
+        // Locked widgets' metadata can't be edited, nor unlocked through it
+        layout.set_locked(&id, true).unwrap();
+        let metadata = HashMap::from([(LOCKED_KEY.to_string(), serde_json::json!(false))]);
+        assert!(matches!(
+            layout.set_node_metadata(&id, metadata),
+            Err(Error::InvalidOperation(_))
+        ));
+        let node = layout.to_serialized().nodes[&id].clone();
+        assert!(node.is_locked());
+        assert_eq!(node.metadata["cms_ref"], "page-42");
+    }
+
+    #[test]
+    fn test_set_node_metadata_keeps_slot() {
+        let mut layout = Layout::new();
+        let card = WidgetId::new_v4();
+        let header = WidgetId::new_v4();
+        let loose = WidgetId::new_v4();
+        layout.add_root_widget(card, WidgetConfig::new("container"));
+        let slots = [SlotSpec::new("header", "Header")];
+        layout
+            .add_widget_to_slot(card, header, WidgetConfig::new("test"), &slots, "header")
+            .unwrap();
+        layout
+            .add_child_widget(card, loose, WidgetConfig::new("test"))
+            .unwrap();
+
+        let renamed = HashMap::from([(SLOT_KEY.to_string(), serde_json::json!("footer"))]);
+        layout.set_node_metadata(&header, renamed.clone()).unwrap();
+        assert_eq!(layout.slot_child(&card, "header"), Some(header));
+
+        // A widget outside any slot can't claim one through its metadata
+        layout.set_node_metadata(&loose, renamed).unwrap();
+        assert_eq!(layout.get_widget(&loose).unwrap().slot(), None);
+
+        layout.set_node_metadata(&header, HashMap::new()).unwrap();
+        assert_eq!(layout.slot_child(&card, "header"), Some(header));
+    }
+
+    #[test]
+    fn test_wrap_widget() {
+        let mut layout = Layout::new();
+        let first = WidgetId::new_v4();
+        let row = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+        let last = WidgetId::new_v4();
+
+        layout.add_root_widget(first, WidgetConfig::new("test"));
+        layout.add_root_widget(row, WidgetConfig::new("container.row"));
+        layout
+            .add_child_widget(row, child, WidgetConfig::new("test"))
+            .unwrap();
+        layout.add_root_widget(last, WidgetConfig::new("test"));
+
+        // Root widget keeps its position inside the new container
+        let card = layout
+            .wrap_widget(&first, WidgetConfig::new("container.card"))
+            .unwrap();
+        assert_eq!(layout.root_widgets(), &[card, row, last]);
+        assert_eq!(layout.get_widget(&card).unwrap().children, vec![first]);
+        assert_eq!(layout.get_widget(&card).unwrap().parent, None);
+        assert_eq!(layout.get_widget(&first).unwrap().parent, Some(card));
+
+        // Nested widget is replaced in its parent's children
+        let column = layout
+            .wrap_widget(&child, WidgetConfig::new("container.column"))
+            .unwrap();
+        assert_eq!(layout.get_widget(&row).unwrap().children, vec![column]);
+        assert_eq!(layout.get_widget(&column).unwrap().parent, Some(row));
+        assert_eq!(layout.get_widget(&child).unwrap().parent, Some(column));
+        assert!(layout.to_serialized().validate().is_ok());
+
+        layout.set_locked(&last, true).unwrap();
+        assert!(layout
+            .wrap_widget(&last, WidgetConfig::new("container.card"))
+            .is_err());
+    }
+
+    #[cfg(feature = "standard-widgets")]
+    #[test]
+    fn test_unwrap_container() {
+        let registry = WidgetRegistry::with_standard_widgets();
+        let mut layout = Layout::new();
+        let first = WidgetId::new_v4();
+        let row = WidgetId::new_v4();
+        let column = WidgetId::new_v4();
+        let a = WidgetId::new_v4();
+        let b = WidgetId::new_v4();
+        let c = WidgetId::new_v4();
+
+        layout.add_root_widget(first, WidgetConfig::new("test"));
+        layout.add_root_widget(row, WidgetConfig::new("container.row"));
+        layout
+            .add_child_widget(row, a, WidgetConfig::new("test"))
+            .unwrap();
+        layout
+            .add_child_widget(row, column, WidgetConfig::new("container.column"))
+            .unwrap();
+        layout
+            .add_child_widget(column, b, WidgetConfig::new("test"))
+            .unwrap();
+        layout
+            .add_child_widget(column, c, WidgetConfig::new("test"))
+            .unwrap();
+
+        assert!(matches!(
+            layout.unwrap_container(&first, &registry),
+            Err(Error::InvalidOperation(_))
+        ));
+
+        // Nested container splices its children into the parent
+        layout.unwrap_container(&column, &registry).unwrap();
+        assert!(layout.get_widget(&column).is_none());
+        assert_eq!(layout.get_widget(&row).unwrap().children, vec![a, b, c]);
+        assert_eq!(layout.get_widget(&b).unwrap().parent, Some(row));
+
+        // Root container promotes its children to roots
+        layout.unwrap_container(&row, &registry).unwrap();
+        assert_eq!(layout.root_widgets(), &[first, a, b, c]);
+        assert_eq!(layout.get_widget(&c).unwrap().parent, None);
+        assert!(layout.to_serialized().validate().is_ok());
+    }
+
+    #[test]
+    fn test_move_widget() {
+        let mut layout = Layout::new();
+        let a = WidgetId::new_v4();
+        let b = WidgetId::new_v4();
+        let row = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+
+        layout.add_root_widget(a, WidgetConfig::new("test"));
+        layout.add_root_widget(b, WidgetConfig::new("test"));
+        layout.add_root_widget(row, WidgetConfig::new("container.row"));
+        layout
+            .add_child_widget(row, child, WidgetConfig::new("test"))
+            .unwrap();
+
+        // Dropping after the next sibling within the same list
+        layout.move_widget(&a, None, 2).unwrap();
+        assert_eq!(layout.root_widgets(), &[b, a, row]);
+
+        // Moving into a container updates the parent link
+        layout.move_widget(&b, Some(row), 0).unwrap();
+        assert_eq!(layout.root_widgets(), &[a, row]);
+        assert_eq!(layout.get_widget(&row).unwrap().children, vec![b, child]);
+        assert_eq!(layout.get_widget(&b).unwrap().parent, Some(row));
+
+        // Moving out to the root list
+        layout.move_widget(&child, None, 0).unwrap();
+        assert_eq!(layout.root_widgets(), &[child, a, row]);
+        assert_eq!(layout.get_widget(&child).unwrap().parent, None);
+
+        assert!(matches!(
+            layout.move_widget(&row, Some(row), 0),
+            Err(Error::InvalidOperation(_))
+        ));
+        assert!(layout.move_widget(&row, Some(b), 0).is_err());
+        assert!(layout.to_serialized().validate().is_ok());
+    }
+
+    #[test]
+    fn test_slots() {
+        let slots = [
+            SlotSpec::new("media", "Media"),
+            SlotSpec::new("text", "Text"),
+        ];
+        let mut layout = Layout::new();
+        let parent = WidgetId::new_v4();
+        let text = WidgetId::new_v4();
+        let media = WidgetId::new_v4();
+        let loose = WidgetId::new_v4();
+        layout.add_root_widget(parent, WidgetConfig::new("test.media_text"));
+        layout.add_root_widget(loose, WidgetConfig::new("test"));
+
+        // Children are kept in slot order whatever order the slots are filled in
+        layout
+            .add_widget_to_slot(parent, text, WidgetConfig::new("test"), &slots, "text")
+            .unwrap();
+        layout
+            .add_widget_to_slot(parent, media, WidgetConfig::new("test"), &slots, "media")
+            .unwrap();
+        assert_eq!(
+            layout.get_widget(&parent).unwrap().children,
+            vec![media, text]
+        );
+        assert_eq!(layout.slot_child(&parent, "text"), Some(text));
+
+        // Each slot takes a single child
+        assert!(matches!(
+            layout.move_widget_to_slot(&loose, parent, &slots, "media"),
+            Err(Error::InvalidOperation(_))
+        ));
+        assert!(layout
+            .move_widget_to_slot(&loose, parent, &slots, "caption")
+            .is_err());
+
+        // Moving out of the parent frees the slot
+        layout.move_widget(&media, None, 0).unwrap();
+        assert_eq!(layout.get_widget(&media).unwrap().slot(), None);
+        layout
+            .move_widget_to_slot(&loose, parent, &slots, "media")
+            .unwrap();
+        assert_eq!(
+            layout.get_widget(&parent).unwrap().children,
+            vec![loose, text]
+        );
+        assert!(layout.to_serialized().validate().is_ok());
+    }
+
+    #[cfg(feature = "standard-widgets")]
+    #[test]
+    fn test_to_yew_source() {
+        let mut layout = Layout::new();
+        let row = WidgetId::new_v4();
+        layout.add_root_widget(row, WidgetConfig::new("container.row").with_class("hero"));
+        layout
+            .add_child_widget(
+                row,
+                WidgetId::new_v4(),
+                WidgetConfig::new("text.heading")
+                    .with_property("content", serde_json::json!("Hello"))
+                    .with_property("level", serde_json::json!(2))
+                    .with_property(ELEMENT_ID, serde_json::json!("intro")),
+            )
+            .unwrap();
+        layout
+            .add_child_widget(row, WidgetId::new_v4(), WidgetConfig::new("custom.map"))
+            .unwrap();
+
+        let source = layout
+            .to_yew_source(&WidgetRegistry::with_standard_widgets())
+            .unwrap();
+        assert_eq!(
+            source,
+            "html! {\n    <div class={\"hero\"}>\n        <h2 id={\"intro\"}>\n            { \"Hello\" }\n        </h2>\n        <>\n            // Unsupported widget: custom.map\n        </>\n    </div>\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_to_yew_source_grid_placement() {
+        let mut layout = Layout::new();
+        layout.add_root_widget(
+            WidgetId::new_v4(),
+            WidgetConfig::new("custom.map")
+                .with_property(GRID_COLUMN_SPAN, serde_json::json!(2))
+                .with_property(GRID_ROW_SPAN, serde_json::json!(1))
+                .with_property(GRID_AREA, serde_json::json!("main")),
+        );
+
+        assert_eq!(
+            layout.to_yew_source(&WidgetRegistry::new()).unwrap(),
+            "html! {\n    <div style={\"grid-column: span 2; grid-area: main;\"}>\n        <>\n            // Unsupported widget: custom.map\n        </>\n    </div>\n}\n"
+        );
+    }
+
+    #[cfg(feature = "standard-widgets")]
+    #[test]
+    fn test_to_yew_source_skips_edit_only() {
+        use crate::core::widget::{Visibility, VISIBILITY};
+
+        let mut layout = Layout::new();
+        let card = WidgetId::new_v4();
+        layout.add_root_widget(
+            WidgetId::new_v4(),
+            WidgetConfig::new("basic.divider")
+                .with_property(VISIBILITY, serde_json::json!(Visibility::EditOnly)),
+        );
+        layout.add_root_widget(card, WidgetConfig::new("container.column"));
+        layout
+            .add_child_widget(
+                card,
+                WidgetId::new_v4(),
+                WidgetConfig::new("text.paragraph")
+                    .with_property("content", serde_json::json!("Draft note"))
+                    .with_property(VISIBILITY, serde_json::json!(Visibility::EditOnly)),
+            )
+            .unwrap();
+        layout
+            .add_child_widget(
+                card,
+                WidgetId::new_v4(),
+                WidgetConfig::new("text.paragraph")
+                    .with_property("content", serde_json::json!("Launch banner"))
+                    .with_property(VISIBILITY, serde_json::json!(Visibility::PreviewOnly)),
+            )
+            .unwrap();
+
+        let source = layout
+            .to_yew_source(&WidgetRegistry::with_standard_widgets())
+            .unwrap();
+        assert!(!source.contains("Draft note"));
+        assert!(!source.contains("<hr"));
+        assert!(source.contains("Launch banner"));
+    }
+
+    #[test]
+    fn test_insert_relative() {
+        let mut layout = Layout::new();
+        let a = WidgetId::new_v4();
+        let b = WidgetId::new_v4();
+        let row = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+        layout.add_root_widget(a, WidgetConfig::new("test"));
+        layout.add_root_widget(row, WidgetConfig::new("container.row"));
+        layout
+            .add_child_widget(row, child, WidgetConfig::new("test"))
+            .unwrap();
+
+        // Root-level references
+        layout
+            .insert_after(&a, b, WidgetConfig::new("test"))
+            .unwrap();
+        let first = WidgetId::new_v4();
+        layout
+            .insert_before(&a, first, WidgetConfig::new("test"))
+            .unwrap();
+        assert_eq!(layout.root_widgets(), &[first, a, b, row]);
+        assert_eq!(layout.get_widget(&b).unwrap().parent, None);
+
+        // Nested references
+        let before = WidgetId::new_v4();
+        let after = WidgetId::new_v4();
+        layout
+            .insert_before(&child, before, WidgetConfig::new("test"))
+            .unwrap();
+        layout
+            .insert_after(&child, after, WidgetConfig::new("test"))
+            .unwrap();
+        assert_eq!(
+            layout.get_widget(&row).unwrap().children,
+            vec![before, child, after]
+        );
+        assert_eq!(layout.get_widget(&after).unwrap().parent, Some(row));
+
+        assert!(matches!(
+            layout.insert_after(
+                &WidgetId::new_v4(),
+                WidgetId::new_v4(),
+                WidgetConfig::new("test")
+            ),
+            Err(Error::WidgetNotFound(_))
+        ));
+        assert!(layout.to_serialized().validate().is_ok());
+    }
+
+    #[test]
+    fn test_version_check() {
+        let json = |version: &str| {
+            format!(
+                r#"{{"version": "{}", "root_nodes": [], "nodes": {{}}}}"#,
+                version
+            )
+        };
+
+        assert!(Layout::from_json(&json(CURRENT_VERSION)).is_ok());
+        assert!(Layout::from_json(&json("0.9")).is_ok());
+        assert!(Layout::from_json(&json("1.7")).is_ok());
+        assert!(matches!(
+            Layout::from_json(&json("2.0")),
+            Err(Error::DeserializationError(_))
+        ));
+        assert!(Layout::from_json(&json("next")).is_err());
+
+        let mut serialized = SerializedLayout::new();
+        serialized.version = "3.1".to_string();
+        assert!(serialized.validate().is_err());
+    }
+
+    #[test]
+    fn test_element_id_uniqueness() {
+        let mut layout = Layout::new();
+        let first = WidgetId::new_v4();
+        let second = WidgetId::new_v4();
+        layout.add_root_widget(
+            first,
+            WidgetConfig::new("test").with_property(ELEMENT_ID, serde_json::json!("intro")),
+        );
+        layout.add_root_widget(second, WidgetConfig::new("test"));
+
+        assert!(layout.is_element_id_available("intro", &first));
+        assert!(!layout.is_element_id_available("intro", &second));
+        assert!(layout.is_element_id_available("pricing", &second));
+        assert!(is_valid_element_id("section-2"));
+        assert!(!is_valid_element_id("section 2"));
+        assert!(!is_valid_element_id(""));
+    }
+
+    #[test]
+    fn test_duplicate_widget() {
+        let mut layout = Layout::new();
+        let card = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+        let last = WidgetId::new_v4();
+        let row = WidgetId::new_v4();
+
+        layout.add_root_widget(
+            card,
+            WidgetConfig::new("container.card")
+                .with_property(ELEMENT_ID, serde_json::json!("intro")),
+        );
+        layout
+            .add_child_widget(card, child, WidgetConfig::new("test"))
+            .unwrap();
+        layout.add_root_widget(last, WidgetConfig::new("test"));
+        layout.add_root_widget(row, WidgetConfig::new("container.row"));
+
+        // The copy lands right after the original, with fresh ids throughout
+        let copy = layout.duplicate_widget(&card).unwrap();
+        assert_eq!(layout.root_widgets(), &[card, copy, last, row]);
+        let copy_node = layout.get_widget(&copy).unwrap();
+        assert_eq!(copy_node.children.len(), 1);
+        assert_ne!(copy_node.children[0], child);
+        assert_eq!(
+            layout.get_widget(&copy_node.children[0]).unwrap().parent,
+            Some(copy)
+        );
+        assert_eq!(copy_node.config.element_id(), None);
+
+        let nested = layout.duplicate_widget_into(&card, Some(row), 0).unwrap();
+        assert_eq!(layout.get_widget(&row).unwrap().children, vec![nested]);
+        assert_eq!(layout.get_widget(&nested).unwrap().parent, Some(row));
+        assert_eq!(layout.to_serialized().nodes.len(), 8);
+        assert!(layout.to_serialized().validate().is_ok());
+
+        layout.set_locked(&row, true).unwrap();
+        assert!(layout.duplicate_widget_into(&last, Some(row), 0).is_err());
+    }
+
+    #[test]
+    fn test_merge_add_add() {
+        let shared = WidgetId::new_v4();
+        let mut base = Layout::new();
+        base.add_root_widget(shared, WidgetConfig::new("container"));
+
+        let ours_id = WidgetId::new_v4();
+        let mut ours = base.clone();
+        ours.add_child_widget(shared, ours_id, WidgetConfig::new("test"))
+            .unwrap();
+
+        let theirs_id = WidgetId::new_v4();
+        let theirs_root = WidgetId::new_v4();
+        let mut theirs = base.clone();
+        theirs
+            .add_child_widget(shared, theirs_id, WidgetConfig::new("test"))
+            .unwrap();
+        theirs.add_root_widget(theirs_root, WidgetConfig::new("test"));
+
+        let result = ours.merge(&theirs);
+        assert!(result.conflicts.is_empty());
+        assert_eq!(result.layout.root_widgets(), &[shared, theirs_root]);
+        let container = result.layout.get_widget(&shared).unwrap();
+        // Their child had no known preceding sibling, so it goes first
+        assert_eq!(container.children, vec![theirs_id, ours_id]);
+        assert_eq!(
+            result.layout.get_widget(&theirs_id).unwrap().parent,
+            Some(shared)
+        );
+        // Merging is deterministic
+        assert_eq!(ours.merge(&theirs), result);
+    }
+
+    #[test]
+    fn test_merge_edit_edit() {
+        let id = WidgetId::new_v4();
+        let mut ours = Layout::new();
+        ours.add_root_widget(id, WidgetConfig::new("test").with_style("color", "red"));
+        let mut theirs = Layout::new();
+        theirs.add_root_widget(id, WidgetConfig::new("test").with_style("color", "blue"));
+
+        let result = ours.merge(&theirs);
+        assert_eq!(result.conflicts.len(), 1);
+        let conflict = &result.conflicts[0];
+        assert_eq!(conflict.id, id);
+        assert_eq!(conflict.ours.inline_styles["color"], "red");
+        assert_eq!(conflict.theirs.inline_styles["color"], "blue");
+        // Our configuration is kept until the conflict is resolved
+        assert_eq!(result.layout.get_widget(&id).unwrap().config, conflict.ours);
+    }
+
+    #[test]
+    fn test_merge_delete_edit() {
+        let kept = WidgetId::new_v4();
+        let edited = WidgetId::new_v4();
+        let mut base = Layout::new();
+        base.add_root_widget(kept, WidgetConfig::new("test"));
+        base.add_root_widget(edited, WidgetConfig::new("test"));
+
+        let mut ours = base.clone();
+        ours.remove_widget(&edited).unwrap();
+
+        let mut theirs = base.clone();
+        theirs
+            .to_serialized_mut()
+            .get_node_mut(&edited)
+            .unwrap()
+            .config
+            .set_property("text", serde_json::json!("edited"));
+
+        // Without a base the deletion can't be told apart from their addition,
+        // so the edited widget is kept in its original position
+        let result = ours.merge(&theirs);
+        assert!(result.conflicts.is_empty());
+        assert_eq!(result.layout.root_widgets(), &[kept, edited]);
+        assert_eq!(
+            result
+                .layout
+                .get_widget(&edited)
+                .unwrap()
+                .config
+                .get_property("text"),
+            Some(&serde_json::json!("edited"))
+        );
+    }
+
+    #[test]
+    fn test_descendants() {
+        let mut layout = Layout::new();
+        let root = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+        let grandchild = WidgetId::new_v4();
+        let sibling = WidgetId::new_v4();
+
+        layout.add_root_widget(root, WidgetConfig::new("container"));
+        layout
+            .add_child_widget(root, child, WidgetConfig::new("container"))
+            .unwrap();
+        layout
+            .add_child_widget(child, grandchild, WidgetConfig::new("test"))
+            .unwrap();
+        layout
+            .add_child_widget(root, sibling, WidgetConfig::new("test"))
+            .unwrap();
+
+        assert_eq!(layout.descendants(&root), vec![child, grandchild, sibling]);
+        assert!(layout.descendants(&grandchild).is_empty());
+    }
+
+    #[test]
+    fn test_structurally_eq() {
+        let build = |heading: &str| {
+            let mut builder = LayoutBuilder::new();
+            builder
+                .widget(
+                    WidgetConfig::new("heading")
+                        .with_property("content", serde_json::json!(heading)),
+                )
+                .container("row", |row| {
+                    row.widget("text").widget("spacer");
+                });
+            builder.build().0
+        };
+
+        let a = build("Title");
+        let b = build("Title");
+        assert_ne!(a, b);
+        assert!(a.structurally_eq(&b));
+        assert!(!a.structurally_eq(&build("Other")));
+
+        // Same configs in a different shape
+        let mut builder = LayoutBuilder::new();
+        builder
+            .widget(
+                WidgetConfig::new("heading").with_property("content", serde_json::json!("Title")),
+            )
+            .container("row", |row| {
+                row.widget("text");
+            })
+            .widget("spacer");
+        assert!(!a.structurally_eq(&builder.build().0));
+
+        // A duplicate has fresh IDs but the same structure
+        let mut duplicated = a.clone();
+        let row = duplicated.root_widgets()[1];
+        let copy = duplicated.duplicate_widget(&row).unwrap();
+        duplicated.remove_widget(&row).unwrap();
+        assert_ne!(copy, row);
+        assert!(duplicated.structurally_eq(&a));
+    }
+
+    #[test]
+    fn test_check_max_depth() {
+        let mut builder = LayoutBuilder::new();
+        builder.named_container("row", "row", |row| {
+            row.named_container("column", "column", |column| {
+                column.named_widget("text", "text");
+            });
+        });
+        let (layout, names) = builder.build();
+
+        assert_eq!(layout.depth_of(&names["row"]), 1);
+        assert_eq!(layout.depth_of(&names["text"]), 3);
+        assert_eq!(layout.depth_of(&WidgetId::new_v4()), 0);
+        assert_eq!(layout.subtree_height(&names["row"]), 3);
+        assert_eq!(layout.subtree_height(&names["text"]), 1);
+
+        assert!(layout.check_max_depth(Some(&names["column"]), 1, 3).is_ok());
+        assert!(matches!(
+            layout.check_max_depth(Some(&names["text"]), 1, 3),
+            Err(Error::InvalidOperation(_))
+        ));
+        // Moving the whole row under the column would make it five levels deep
+        assert!(layout
+            .check_max_depth(
+                Some(&names["column"]),
+                layout.subtree_height(&names["row"]),
+                4
+            )
+            .is_err());
+        assert!(layout.check_max_depth(None, 3, 3).is_ok());
+
+        // With a limit set, the layout refuses inserts, moves, and wraps past it
+        let mut layout = layout;
+        layout.set_max_depth(Some(3));
+        assert!(matches!(
+            layout.add_child_widget(names["text"], WidgetId::new_v4(), WidgetConfig::new("text")),
+            Err(Error::InvalidOperation(_))
+        ));
+        assert!(layout
+            .insert_child_widget(
+                names["column"],
+                WidgetId::new_v4(),
+                WidgetConfig::new("text"),
+                0
+            )
+            .is_ok());
+        assert!(layout
+            .wrap_widget(&names["text"], WidgetConfig::new("container"))
+            .is_err());
+        let other = WidgetId::new_v4();
+        layout.add_root_widget(other, WidgetConfig::new("container"));
+        assert!(layout.move_widget(&names["row"], Some(other), 0).is_err());
+        assert!(layout.move_widget(&names["text"], Some(other), 0).is_ok());
+
+        // Appended widgets are held to the limit as well
+        let mut deep = LayoutBuilder::new();
+        deep.container("row", |row| {
+            row.container("column", |column| {
+                column.container("card", |card| {
+                    card.widget("text");
+                });
+            });
+        });
+        let (deep, _) = deep.build();
+        let before = layout.clone();
+        assert!(layout.append(deep).is_err());
+        assert_eq!(layout, before);
+
+        // The limit is runtime configuration, not part of the layout's content
+        let mut unlimited = layout.clone();
+        unlimited.set_max_depth(None);
+        assert_eq!(unlimited, layout);
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Layout::new().stats(), LayoutStats::default());
+
+        let mut layout = Layout::new();
+        let root = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+        layout.add_root_widget(root, WidgetConfig::new("container"));
+        layout.add_root_widget(WidgetId::new_v4(), WidgetConfig::new("text"));
+        layout
+            .add_child_widget(root, child, WidgetConfig::new("container"))
+            .unwrap();
+        layout
+            .add_child_widget(child, WidgetId::new_v4(), WidgetConfig::new("text"))
+            .unwrap();
+
+        // An orphaned node isn't rendered, so it isn't counted
+        layout.to_serialized_mut().add_node(
+            WidgetId::new_v4(),
+            LayoutNode::new(WidgetConfig::new("text")),
+        );
+
+        let stats = layout.stats();
+        assert_eq!(stats.total_widgets, 4);
+        assert_eq!(stats.root_count, 2);
+        assert_eq!(stats.max_depth, 3);
+        assert_eq!(stats.widgets_by_type["container"], 2);
+        assert_eq!(stats.widgets_by_type["text"], 2);
+    }
+
+    #[test]
+    fn test_prune_orphans() {
+        let mut layout = Layout::new();
+        let root = WidgetId::new_v4();
+        let child = WidgetId::new_v4();
+        layout.add_root_widget(root, WidgetConfig::new("container"));
+        layout
+            .add_child_widget(root, child, WidgetConfig::new("text"))
+            .unwrap();
+        assert_eq!(layout.prune_orphans(), 0);
+
+        // An orphaned subtree: a detached parent still referencing its own child
+        let orphan = WidgetId::new_v4();
+        let orphan_child = WidgetId::new_v4();
+        let mut orphan_node = LayoutNode::new(WidgetConfig::new("container"));
+        orphan_node.add_child(orphan_child);
+        let serialized = layout.to_serialized_mut();
+        serialized.add_node(orphan, orphan_node);
+        serialized.add_node(orphan_child, LayoutNode::new(WidgetConfig::new("text")));
+
+        let json = layout.to_json().unwrap();
+        let mut loaded = Layout::from_json(&json).unwrap();
+        assert_eq!(loaded.to_serialized().nodes.len(), 4);
+
+        assert_eq!(loaded.prune_orphans(), 2);
+        assert!(loaded.get_widget(&orphan).is_none());
+        assert!(loaded.get_widget(&orphan_child).is_none());
+        assert_eq!(loaded.widgets_in_order(), vec![root, child]);
+    }
+
+    #[test]
+    fn test_cyclic_layouts_are_rejected() {
+        let mut layout = Layout::new();
+        let root = WidgetId::new_v4();
+        let a = WidgetId::new_v4();
+        let b = WidgetId::new_v4();
+        layout.add_root_widget(root, WidgetConfig::new("container"));
+        layout
+            .add_child_widget(root, a, WidgetConfig::new("container"))
+            .unwrap();
+        layout
+            .add_child_widget(a, b, WidgetConfig::new("container"))
+            .unwrap();
+
+        // `b` lists its own ancestor as a child
+        layout
+            .to_serialized_mut()
+            .get_node_mut(&b)
+            .unwrap()
+            .add_child(a);
+
+        // Tree walks still finish on the in-memory layout
+        assert_eq!(layout.descendants(&root), vec![a, b]);
+        assert_eq!(layout.widgets_in_order(), vec![root, a, b]);
+        assert_eq!(layout.subtree_height(&a), 2);
+        assert_eq!(layout.stats().total_widgets, 3);
+        assert_eq!(layout.prune_orphans(), 0);
+
+        let json = layout.to_json().unwrap();
+        assert!(matches!(
+            Layout::from_json(&json),
+            Err(Error::InvalidOperation(_))
+        ));
+
+        // Two detached nodes containing each other, and a child listed twice
+        let mut serialized = SerializedLayout::new();
+        let mut first = LayoutNode::new(WidgetConfig::new("container"));
+        first.add_child(b);
+        let mut second = LayoutNode::new(WidgetConfig::new("container"));
+        second.add_child(a);
+        serialized.add_node(a, first);
+        serialized.add_node(b, second);
+        assert!(serialized.validate().is_err());
+
+        let mut serialized = SerializedLayout::new();
+        let mut parent = LayoutNode::new(WidgetConfig::new("container"));
+        parent.add_child(b);
+        serialized.root_nodes.push(a);
+        serialized.root_nodes.push(b);
+        serialized.add_node(a, parent);
+        serialized.add_node(b, LayoutNode::new(WidgetConfig::new("text")));
+        assert!(serialized.validate().is_err());
+    }
+
+    #[test]
+    fn test_set_widget_config() {
+        let mut layout = Layout::new();
+        let id = WidgetId::new_v4();
+        layout.add_root_widget(id, WidgetConfig::new("basic.text"));
+
+        let config = WidgetConfig::new("basic.text").with_style("color", "red");
+        layout.set_widget_config(&id, config.clone()).unwrap();
+        assert_eq!(layout.get_widget(&id).unwrap().config, config);
+
+        assert!(matches!(
+            layout.set_widget_config(&id, WidgetConfig::new("basic.image")),
+            Err(Error::InvalidConfig(_))
+        ));
+        assert_eq!(layout.get_widget(&id).unwrap().config, config);
+
+        layout.set_locked(&id, true).unwrap();
+        assert!(layout
+            .set_widget_config(&id, WidgetConfig::new("basic.text"))
+            .is_err());
+        assert!(matches!(
+            layout.set_widget_config(&WidgetId::new_v4(), WidgetConfig::new("basic.text")),
+            Err(Error::WidgetNotFound(_))
+        ));
+    }
+
+    #[cfg(feature = "standard-widgets")]
+    #[test]
+    fn test_convert_widget() {
+        let registry = WidgetRegistry::with_standard_widgets();
+        let mut layout = Layout::new();
+        let container = WidgetId::new_v4();
+        let text = WidgetId::new_v4();
+        layout.add_root_widget(container, WidgetConfig::new("container.column"));
+        layout
+            .add_child_widget(
+                container,
+                text,
+                WidgetConfig::new("text.paragraph")
+                    .with_property("content", serde_json::json!("Hello"))
+                    .with_property("unrelated", serde_json::json!(true))
+                    .with_property(ELEMENT_ID, serde_json::json!("intro"))
+                    .with_style("color", "red")
+                    .with_class("lead"),
+            )
+            .unwrap();
+
+        layout
+            .convert_widget(&text, "text.heading", &registry)
+            .unwrap();
+        let config = &layout.get_widget(&text).unwrap().config;
+        assert_eq!(config.widget_type, "text.heading");
+        assert_eq!(config.get_str("content", ""), "Hello");
+        assert_eq!(config.get_str(ELEMENT_ID, ""), "intro");
+        assert!(!config.properties.contains_key("unrelated"));
+        assert_eq!(
+            config.inline_styles.get("color").map(String::as_str),
+            Some("red")
+        );
+        assert_eq!(config.css_classes, vec!["lead".to_string()]);
+        assert_eq!(layout.get_widget(&text).unwrap().parent, Some(container));
+
+        assert!(matches!(
+            layout.convert_widget(&container, "text.heading", &registry),
+            Err(Error::InvalidOperation(_))
+        ));
+        assert!(layout
+            .convert_widget(&container, "container.row", &registry)
+            .is_ok());
+        assert!(layout
+            .convert_widget(&text, "no.such.widget", &registry)
+            .is_err());
+    }
+
+    #[test]
+    fn test_append() {
+        let mut layout = Layout::new();
+        let existing = WidgetId::new_v4();
+        layout.add_root_widget(existing, WidgetConfig::new("text"));
+
+        let mut builder = LayoutBuilder::new();
+        builder.named_container("row", "container.row", |row| {
+            row.named_widget("text", "text");
+        });
+        let (other, names) = builder.build();
+
+        let roots = layout.append(other.clone()).unwrap();
+        assert_eq!(roots, vec![names["row"]]);
+        assert_eq!(
+            layout.widgets_in_order(),
+            vec![existing, names["row"], names["text"]]
+        );
+
+        // Appending the same widgets twice would clash
+        assert!(matches!(
+            layout.append(other),
+            Err(Error::InvalidOperation(_))
+        ));
+        assert_eq!(layout.widgets_in_order().len(), 3);
+    }
 }
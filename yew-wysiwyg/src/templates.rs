@@ -0,0 +1,182 @@
+//! Built-in starter layouts
+//!
+//! Templates only use the standard widgets, so they render in any editor whose
+//! registry includes them. Each call builds a fresh layout with new widget IDs.
+
+use serde_json::json;
+
+use crate::core::widget::{Widget, WidgetConfig};
+use crate::serialization::{Layout, LayoutBuilder};
+use crate::widgets::{basic, container, text};
+
+/// Names and English display titles of the templates available from [`Layout::template`]
+///
+/// The editor's Templates menu takes its titles from `EditorStrings` instead.
+pub const TEMPLATES: &[(&str, &str)] = &[
+    ("hero", "Hero Section"),
+    ("contact-form", "Contact Form"),
+    ("pricing", "Pricing Cards"),
+];
+
+impl Layout {
+    /// Build the starter template called `name`, or `None` if there is no such template
+    ///
+    /// See [`TEMPLATES`] for the available names.
+    pub fn template(name: &str) -> Option<Layout> {
+        let mut builder = LayoutBuilder::new();
+        match name {
+            "hero" => hero(&mut builder),
+            "contact-form" => contact_form(&mut builder),
+            "pricing" => pricing(&mut builder),
+            _ => return None,
+        }
+        Some(builder.build().0)
+    }
+}
+
+/// Default config of a standard widget, so templates pick up its usual styling
+fn defaults<W: Widget + Default>() -> WidgetConfig {
+    W::default().default_config()
+}
+
+fn heading(content: &str, level: u8) -> WidgetConfig {
+    defaults::<text::HeadingWidget>()
+        .with_property("content", json!(content))
+        .with_property("level", json!(level))
+}
+
+fn paragraph(content: &str) -> WidgetConfig {
+    defaults::<text::ParagraphWidget>().with_property("content", json!(content))
+}
+
+fn button(label: &str, variant: &str) -> WidgetConfig {
+    defaults::<basic::Button>()
+        .with_property("text", json!(label))
+        .with_property("variant", json!(variant))
+}
+
+fn hero(builder: &mut LayoutBuilder) {
+    builder.container(
+        defaults::<container::ColumnContainer>()
+            .with_style("align-items", "center")
+            .with_style("text-align", "center")
+            .with_style("padding", "64px 24px"),
+        |hero| {
+            hero.widget(heading("Build beautiful pages in minutes", 1))
+                .widget(paragraph(
+                    "Drag widgets onto the canvas, tweak them in the config panel, and export the result.",
+                ))
+                .container(
+                    defaults::<container::RowContainer>().with_style("justify-content", "center"),
+                    |actions| {
+                        actions
+                            .widget(button("Get Started", "primary"))
+                            .widget(button("Learn More", "secondary"));
+                    },
+                );
+        },
+    );
+}
+
+fn contact_form(builder: &mut LayoutBuilder) {
+    builder.container(
+        defaults::<container::Card>()
+            .with_property("title", json!("Contact Us"))
+            .with_style("max-width", "480px"),
+        |card| {
+            card.container(defaults::<container::ColumnContainer>(), |form| {
+                form.widget(paragraph(
+                    "Send us a message and we'll get back to you within a day.",
+                ))
+                .widget(
+                    defaults::<basic::TextInput>()
+                        .with_property("label", json!("Name"))
+                        .with_property("placeholder", json!("Your name")),
+                )
+                .widget(
+                    defaults::<basic::TextInput>()
+                        .with_property("label", json!("Email"))
+                        .with_property("placeholder", json!("you@example.com"))
+                        .with_property("type", json!("email")),
+                )
+                .widget(
+                    defaults::<basic::TextArea>()
+                        .with_property("label", json!("Message"))
+                        .with_property("placeholder", json!("How can we help?"))
+                        .with_property("rows", json!(5)),
+                )
+                .widget(
+                    defaults::<basic::Checkbox>()
+                        .with_property("label", json!("Subscribe to updates")),
+                )
+                .widget(button("Send Message", "primary"));
+            });
+        },
+    );
+}
+
+fn pricing(builder: &mut LayoutBuilder) {
+    let plans = [
+        (
+            "Basic",
+            "$0 / month",
+            "One project and community support.",
+            "secondary",
+        ),
+        (
+            "Pro",
+            "$19 / month",
+            "Unlimited projects and email support.",
+            "primary",
+        ),
+        (
+            "Team",
+            "$49 / month",
+            "Shared workspaces and priority support.",
+            "secondary",
+        ),
+    ];
+
+    builder.container(defaults::<container::ColumnContainer>(), |section| {
+        section
+            .widget(heading("Pricing", 2).with_style("text-align", "center"))
+            .container(defaults::<container::GridContainer>(), |grid| {
+                for (name, price, features, variant) in plans {
+                    grid.container(
+                        defaults::<container::Card>().with_property("title", json!(name)),
+                        |card| {
+                            card.widget(heading(price, 3))
+                                .widget(paragraph(features))
+                                .widget(button("Choose Plan", variant));
+                        },
+                    );
+                }
+            });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::registry::WidgetRegistry;
+
+    #[test]
+    fn test_templates() {
+        let registry = WidgetRegistry::with_standard_widgets();
+        for (name, _) in TEMPLATES {
+            let layout = Layout::template(name).unwrap();
+            assert!(!layout.root_widgets().is_empty(), "{} is empty", name);
+            for id in layout.widgets_in_order() {
+                let widget_type = &layout.get_widget(&id).unwrap().config.widget_type;
+                assert!(
+                    registry.has_widget(widget_type),
+                    "{} uses {}",
+                    name,
+                    widget_type
+                );
+            }
+            assert!(layout.to_serialized().validate().is_ok());
+        }
+        assert!(Layout::template("missing").is_none());
+    }
+}
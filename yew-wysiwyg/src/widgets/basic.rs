@@ -2,7 +2,10 @@
 
 use yew::prelude::*;
 
+use crate::core::config_ui::{get_number, number_property, NumberProperty};
+use crate::core::style::build_style;
 use crate::core::widget::{SimpleWidgetFactory, Widget, WidgetConfig, WidgetProps};
+use crate::core::yew_source;
 
 /// Button widget
 #[derive(Default)]
@@ -44,32 +47,13 @@ impl Widget for Button {
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let text = props
-            .config
-            .properties
-            .get("text")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Click me");
+        let text = props.config.get_str("text", "Click me");
+        let variant = props.config.get_str("variant", "primary");
 
-        let variant = props
-            .config
-            .properties
-            .get("variant")
-            .and_then(|v| v.as_str())
-            .unwrap_or("primary");
-
-        let bg_color = match variant {
-            "primary" => "#3b82f6",
-            "secondary" => "#6b7280",
-            "success" => "#10b981",
-            "danger" => "#ef4444",
-            _ => "#3b82f6",
-        };
+        let bg_color = button_background(&variant);
 
         let mut style = format!("background: {}; color: white; ", bg_color);
-        for (k, v) in &props.config.inline_styles {
-            style.push_str(&format!("{}: {}; ", k, v));
-        }
+        style.push_str(&build_style(&props.config));
 
         let class = props.config.css_classes.join(" ");
 
@@ -80,20 +64,21 @@ impl Widget for Button {
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let text = config.get_str("text", "Click me");
+        let variant = config.get_str("variant", "primary");
+        let base_style = format!("background: {}; color: white;", button_background(&variant));
+
+        Some(yew_source::element(
+            "button",
+            &yew_source::config_attributes(config, &base_style),
+            &[yew_source::text(&text)],
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let text = config
-            .properties
-            .get("text")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Click me")
-            .to_string();
-
-        let variant = config
-            .properties
-            .get("variant")
-            .and_then(|v| v.as_str())
-            .unwrap_or("primary")
-            .to_string();
+        let text = config.get_str("text", "Click me");
+        let variant = config.get_str("variant", "primary");
 
         let config_clone = config.clone();
         let on_text_change = {
@@ -154,7 +139,13 @@ impl Widget for Button {
     }
 }
 
+/// Placeholder shown when an image and its fallback both fail to load
+const BROKEN_IMAGE_PLACEHOLDER: &str = "data:image/svg+xml,%3Csvg xmlns='http://www.w3.org/2000/svg' width='400' height='300' viewBox='0 0 400 300'%3E%3Crect width='400' height='300' fill='%23f3f4f6'/%3E%3Ctext x='200' y='156' font-family='sans-serif' font-size='16' fill='%239ca3af' text-anchor='middle'%3EImage unavailable%3C/text%3E%3C/svg%3E";
+
 /// Image widget
+///
+/// If `src` fails to load, the image switches to `fallback_src` and then to a
+/// built-in placeholder, each tried at most once.
 #[derive(Default)]
 pub struct Image;
 
@@ -196,46 +187,70 @@ impl Widget for Image {
     fn render(&self, props: &WidgetProps) -> Html {
         let src = props
             .config
-            .properties
-            .get("src")
-            .and_then(|v| v.as_str())
-            .unwrap_or("https://via.placeholder.com/400x300")
-            .to_string();
+            .get_str("src", "https://via.placeholder.com/400x300");
 
-        let alt = props
-            .config
-            .properties
-            .get("alt")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Image")
-            .to_string();
-
-        let mut style = String::new();
-        for (k, v) in &props.config.inline_styles {
-            style.push_str(&format!("{}: {}; ", k, v));
-        }
+        let alt = props.config.get_str("alt", "Image");
+        let fallback_src = props.config.get_str("fallback_src", "");
+
+        let style = build_style(&props.config);
 
         let class = props.config.css_classes.join(" ");
 
+        // Step from the source to the fallback to the placeholder; the image's
+        // current src says which one failed, so a failing placeholder stops the loop
+        let onerror = {
+            let src = src.clone();
+            Callback::from(move |e: Event| {
+                let img: web_sys::Element = e.target_unchecked_into();
+                let failed = img.get_attribute("src").unwrap_or_default();
+                let next = if failed == src && !fallback_src.is_empty() && fallback_src != src {
+                    fallback_src.as_str()
+                } else if failed != BROKEN_IMAGE_PLACEHOLDER {
+                    BROKEN_IMAGE_PLACEHOLDER
+                } else {
+                    return;
+                };
+                let _ = img.set_attribute("src", next);
+            })
+        };
+
         html! {
-            <img {src} {alt} {class} {style} />
+            <img {src} {alt} {class} {style} {onerror} />
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let src = config.get_str("src", "https://via.placeholder.com/400x300");
+        let alt = config.get_str("alt", "Image");
+
+        let mut attributes = vec![
+            ("src", yew_source::literal(&src)),
+            ("alt", yew_source::literal(&alt)),
+        ];
+        attributes.extend(yew_source::config_attributes(config, ""));
+        Some(yew_source::element("img", &attributes, &[]))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let src = config
-            .properties
-            .get("src")
-            .and_then(|v| v.as_str())
-            .unwrap_or("https://via.placeholder.com/400x300")
-            .to_string();
-
-        let alt = config
-            .properties
-            .get("alt")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Image")
-            .to_string();
+        let src = config.get_str("src", "https://via.placeholder.com/400x300");
+        let alt = config.get_str("alt", "Image");
+        let fallback_src = config.get_str("fallback_src", "");
+
+        let config_clone = config.clone();
+        let on_fallback_change = {
+            let on_change = on_change.clone();
+            Callback::from(move |e: InputEvent| {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let mut new_config = config_clone.clone();
+                let value = input.value();
+                if value.trim().is_empty() {
+                    new_config.properties.remove("fallback_src");
+                } else {
+                    new_config.set_property("fallback_src", serde_json::json!(value.trim()));
+                }
+                on_change.emit(new_config);
+            })
+        };
 
         let config_clone = config.clone();
         let on_src_change = {
@@ -276,6 +291,18 @@ impl Widget for Image {
                         placeholder="https://example.com/image.jpg"
                     />
                 </div>
+                <div style="margin-bottom: 12px;">
+                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                        { "Fallback URL:" }
+                    </label>
+                    <input
+                        type="text"
+                        value={fallback_src}
+                        oninput={on_fallback_change}
+                        style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                        placeholder="Shown if the image fails to load"
+                    />
+                </div>
                 <div style="margin-bottom: 12px;">
                     <label style="display: block; margin-bottom: 4px; font-weight: 500;">
                         { "Alt Text:" }
@@ -328,33 +355,17 @@ impl Widget for Link {
         WidgetConfig::new(self.widget_type())
             .with_property("href", serde_json::json!("https://example.com"))
             .with_property("target", serde_json::json!("_self"))
-            .with_style("color", "#3b82f6")
+            .with_style("color", "var(--wysiwyg-primary, #3b82f6)")
             .with_style("text-decoration", "none")
             .with_style("cursor", "pointer")
             .with_style("display", "inline-block")
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let href = props
-            .config
-            .properties
-            .get("href")
-            .and_then(|v| v.as_str())
-            .unwrap_or("https://example.com")
-            .to_string();
+        let href = props.config.get_str("href", "https://example.com");
+        let target = props.config.get_str("target", "_self");
 
-        let target = props
-            .config
-            .properties
-            .get("target")
-            .and_then(|v| v.as_str())
-            .unwrap_or("_self")
-            .to_string();
-
-        let mut style = String::new();
-        for (k, v) in &props.config.inline_styles {
-            style.push_str(&format!("{}: {}; ", k, v));
-        }
+        let style = build_style(&props.config);
 
         let class = props.config.css_classes.join(" ");
 
@@ -365,20 +376,21 @@ impl Widget for Link {
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, children: &[String]) -> Option<String> {
+        let href = config.get_str("href", "https://example.com");
+        let target = config.get_str("target", "_self");
+
+        let mut attributes = vec![
+            ("href", yew_source::literal(&href)),
+            ("target", yew_source::literal(&target)),
+        ];
+        attributes.extend(yew_source::config_attributes(config, ""));
+        Some(yew_source::element("a", &attributes, children))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let href = config
-            .properties
-            .get("href")
-            .and_then(|v| v.as_str())
-            .unwrap_or("https://example.com")
-            .to_string();
-
-        let target = config
-            .properties
-            .get("target")
-            .and_then(|v| v.as_str())
-            .unwrap_or("_self")
-            .to_string();
+        let href = config.get_str("href", "https://example.com");
+        let target = config.get_str("target", "_self");
 
         let config_clone = config.clone();
         let on_href_change = {
@@ -469,33 +481,23 @@ impl Widget for Divider {
 
     fn default_config(&self) -> WidgetConfig {
         WidgetConfig::new(self.widget_type())
-            .with_property("thickness", serde_json::json!("1"))
-            .with_property("color", serde_json::json!("#e5e7eb"))
+            .with_property("thickness", serde_json::json!(1))
             .with_style("margin", "16px 0")
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let thickness = props
-            .config
-            .properties
-            .get("thickness")
-            .and_then(|v| v.as_str())
-            .unwrap_or("1");
+        let thickness = get_number(&props.config, "thickness").unwrap_or(1.0);
 
+        // Follow the theme until a color is picked
         let color = props
             .config
-            .properties
-            .get("color")
-            .and_then(|v| v.as_str())
-            .unwrap_or("#e5e7eb");
+            .get_str("color", "var(--wysiwyg-border, #e5e7eb)");
 
         let mut style = format!(
             "border: none; border-top: {}px solid {}; ",
             thickness, color
         );
-        for (k, v) in &props.config.inline_styles {
-            style.push_str(&format!("{}: {}; ", k, v));
-        }
+        style.push_str(&build_style(&props.config));
 
         let class = props.config.css_classes.join(" ");
 
@@ -504,36 +506,24 @@ impl Widget for Divider {
         }
     }
 
-    fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let thickness = config
-            .properties
-            .get("thickness")
-            .and_then(|v| v.as_str())
-            .and_then(|v| v.parse::<i32>().ok())
-            .unwrap_or(1);
-
-        let color = config
-            .properties
-            .get("color")
-            .and_then(|v| v.as_str())
-            .unwrap_or("#e5e7eb")
-            .to_string();
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let thickness = get_number(config, "thickness").unwrap_or(1.0);
+        let color = config.get_str("color", "var(--wysiwyg-border, #e5e7eb)");
+        let base_style = format!("border: none; border-top: {}px solid {};", thickness, color);
 
-        let config_clone = config.clone();
-        let on_thickness_change = {
-            let on_change = on_change.clone();
-            Callback::from(move |e: InputEvent| {
-                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                let mut new_config = config_clone.clone();
-                new_config
-                    .properties
-                    .insert("thickness".to_string(), serde_json::json!(input.value()));
-                on_change.emit(new_config);
-            })
-        };
+        Some(yew_source::element(
+            "hr",
+            &yew_source::config_attributes(config, &base_style),
+            &[],
+        ))
+    }
+
+    fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
+        let color = config.get_str("color", "#e5e7eb");
 
         let config_clone = config.clone();
         let on_color_change = {
+            let on_change = on_change.clone();
             Callback::from(move |e: InputEvent| {
                 let input: web_sys::HtmlInputElement = e.target_unchecked_into();
                 let mut new_config = config_clone.clone();
@@ -546,19 +536,16 @@ impl Widget for Divider {
 
         html! {
             <div>
-                <div style="margin-bottom: 12px;">
-                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
-                        { "Thickness (px):" }
-                    </label>
-                    <input
-                        type="number"
-                        value={thickness.to_string()}
-                        oninput={on_thickness_change}
-                        min="1"
-                        max="10"
-                        style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
-                    />
-                </div>
+                {
+                    number_property(
+                        config,
+                        on_change,
+                        NumberProperty::new("thickness", "Thickness:", 1.0)
+                            .range(1.0, 10.0)
+                            .step(1.0)
+                            .unit("px"),
+                    )
+                }
                 <div style="margin-bottom: 12px;">
                     <label style="display: block; margin-bottom: 4px; font-weight: 500;">
                         { "Color:" }
@@ -608,47 +595,24 @@ impl Widget for TextInput {
             .with_property("type", serde_json::json!("text"))
             .with_style("width", "100%")
             .with_style("padding", "8px 12px")
-            .with_style("border", "1px solid #d1d5db")
+            .with_style("border", "1px solid var(--wysiwyg-border, #d1d5db)")
             .with_style("border-radius", "4px")
             .with_style("font-size", "14px")
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let placeholder = props
-            .config
-            .properties
-            .get("placeholder")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Enter text...")
-            .to_string();
-
-        let label = props
-            .config
-            .properties
-            .get("label")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
+        let placeholder = props.config.get_str("placeholder", "Enter text...");
+        let label = props.config.get_str("label", "");
+        let input_type = props.config.get_str("type", "text");
 
-        let input_type = props
-            .config
-            .properties
-            .get("type")
-            .and_then(|v| v.as_str())
-            .unwrap_or("text")
-            .to_string();
-
-        let mut style = String::new();
-        for (k, v) in &props.config.inline_styles {
-            style.push_str(&format!("{}: {}; ", k, v));
-        }
+        let style = build_style(&props.config);
 
         let class = props.config.css_classes.join(" ");
 
         html! {
             <div style="display: flex; flex-direction: column; gap: 4px;">
                 if !label.is_empty() {
-                    <label style="font-weight: 500; font-size: 14px; color: #374151;">
+                    <label style="font-weight: 500; font-size: 14px; color: var(--wysiwyg-text, #374151);">
                         { label }
                     </label>
                 }
@@ -662,27 +626,26 @@ impl Widget for TextInput {
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let placeholder = config.get_str("placeholder", "Enter text...");
+        let label = config.get_str("label", "");
+        let input_type = config.get_str("type", "text");
+
+        let mut attributes = vec![
+            ("type", yew_source::literal(&input_type)),
+            ("placeholder", yew_source::literal(&placeholder)),
+        ];
+        attributes.extend(yew_source::config_attributes(config, ""));
+        Some(labeled_field_source(
+            &label,
+            yew_source::element("input", &attributes, &[]),
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let placeholder = config
-            .properties
-            .get("placeholder")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Enter text...")
-            .to_string();
-
-        let label = config
-            .properties
-            .get("label")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-
-        let input_type = config
-            .properties
-            .get("type")
-            .and_then(|v| v.as_str())
-            .unwrap_or("text")
-            .to_string();
+        let placeholder = config.get_str("placeholder", "Enter text...");
+        let label = config.get_str("label", "");
+        let input_type = config.get_str("type", "text");
 
         let config_clone = config.clone();
         let on_placeholder_change = {
@@ -799,7 +762,7 @@ impl Widget for TextArea {
             .with_property("rows", serde_json::json!(4))
             .with_style("width", "100%")
             .with_style("padding", "8px 12px")
-            .with_style("border", "1px solid #d1d5db")
+            .with_style("border", "1px solid var(--wysiwyg-border, #d1d5db)")
             .with_style("border-radius", "4px")
             .with_style("font-size", "14px")
             .with_style("font-family", "inherit")
@@ -807,41 +770,19 @@ impl Widget for TextArea {
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let placeholder = props
-            .config
-            .properties
-            .get("placeholder")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Enter text...")
-            .to_string();
+        let placeholder = props.config.get_str("placeholder", "Enter text...");
+        let label = props.config.get_str("label", "");
 
-        let label = props
-            .config
-            .properties
-            .get("label")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
+        let rows = props.config.get_i64("rows", 4).to_string();
 
-        let rows = props
-            .config
-            .properties
-            .get("rows")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(4)
-            .to_string();
-
-        let mut style = String::new();
-        for (k, v) in &props.config.inline_styles {
-            style.push_str(&format!("{}: {}; ", k, v));
-        }
+        let style = build_style(&props.config);
 
         let class = props.config.css_classes.join(" ");
 
         html! {
             <div style="display: flex; flex-direction: column; gap: 4px;">
                 if !label.is_empty() {
-                    <label style="font-weight: 500; font-size: 14px; color: #374151;">
+                    <label style="font-weight: 500; font-size: 14px; color: var(--wysiwyg-text, #374151);">
                         { label }
                     </label>
                 }
@@ -855,26 +796,27 @@ impl Widget for TextArea {
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let placeholder = config.get_str("placeholder", "Enter text...");
+        let label = config.get_str("label", "");
+        let rows = config.get_i64("rows", 4);
+
+        let mut attributes = vec![
+            ("placeholder", yew_source::literal(&placeholder)),
+            ("rows", yew_source::literal(&rows.to_string())),
+        ];
+        attributes.extend(yew_source::config_attributes(config, ""));
+        Some(labeled_field_source(
+            &label,
+            yew_source::element("textarea", &attributes, &[]),
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let placeholder = config
-            .properties
-            .get("placeholder")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Enter text...")
-            .to_string();
-
-        let label = config
-            .properties
-            .get("label")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-
-        let rows = config
-            .properties
-            .get("rows")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(4);
+        let placeholder = config.get_str("placeholder", "Enter text...");
+        let label = config.get_str("label", "");
+
+        let rows = config.get_i64("rows", 4);
 
         let config_clone = config.clone();
         let on_placeholder_change = {
@@ -989,19 +931,8 @@ impl Widget for Checkbox {
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let label = props
-            .config
-            .properties
-            .get("label")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Check me");
-
-        let checked = props
-            .config
-            .properties
-            .get("checked")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let label = props.config.get_str("label", "Check me");
+        let checked = props.config.get_bool("checked", false);
 
         html! {
             <div style="display: flex; align-items: center; gap: 8px;">
@@ -1010,26 +941,52 @@ impl Widget for Checkbox {
                     checked={checked}
                     style="width: 16px; height: 16px; cursor: pointer;"
                 />
-                <label style="font-size: 14px; color: #374151; cursor: pointer;">
+                <label style="font-size: 14px; color: var(--wysiwyg-text, #374151); cursor: pointer;">
                     { label }
                 </label>
             </div>
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let label = config.get_str("label", "Check me");
+        let checked = config.get_bool("checked", false);
+
+        let input = yew_source::element(
+            "input",
+            &[
+                ("type", yew_source::literal("checkbox")),
+                ("checked", checked.to_string()),
+                (
+                    "style",
+                    yew_source::literal("width: 16px; height: 16px; cursor: pointer;"),
+                ),
+            ],
+            &[],
+        );
+        let label = yew_source::element(
+            "label",
+            &[(
+                "style",
+                yew_source::literal(
+                    "font-size: 14px; color: var(--wysiwyg-text, #374151); cursor: pointer;",
+                ),
+            )],
+            &[yew_source::text(&label)],
+        );
+        Some(yew_source::element(
+            "div",
+            &[(
+                "style",
+                yew_source::literal("display: flex; align-items: center; gap: 8px;"),
+            )],
+            &[input, label],
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let label = config
-            .properties
-            .get("label")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Check me")
-            .to_string();
-
-        let checked = config
-            .properties
-            .get("checked")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let label = config.get_str("label", "Check me");
+        let checked = config.get_bool("checked", false);
 
         let config_clone = config.clone();
         let on_label_change = {
@@ -1083,6 +1040,463 @@ impl Widget for Checkbox {
     }
 }
 
+/// Date Picker widget
+#[derive(Default)]
+pub struct DatePicker;
+
+impl DatePicker {
+    pub fn factory() -> SimpleWidgetFactory<Self> {
+        SimpleWidgetFactory::new()
+    }
+}
+
+impl Widget for DatePicker {
+    fn widget_type(&self) -> &'static str {
+        "form.date"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Date Picker"
+    }
+
+    fn description(&self) -> &'static str {
+        "Date, date and time, or time input field"
+    }
+
+    fn icon(&self) -> Html {
+        html! { <span>{ "📅" }</span> }
+    }
+
+    fn default_config(&self) -> WidgetConfig {
+        WidgetConfig::new(self.widget_type())
+            .with_property("label", serde_json::json!(""))
+            .with_property("mode", serde_json::json!("date"))
+            .with_style("padding", "8px 12px")
+            .with_style("border", "1px solid var(--wysiwyg-border, #d1d5db)")
+            .with_style("border-radius", "4px")
+            .with_style("font-size", "14px")
+    }
+
+    fn render(&self, props: &WidgetProps) -> Html {
+        let label = props.config.get_str("label", "");
+
+        let min = date_bound(&props.config, "min");
+        let max = date_bound(&props.config, "max");
+
+        let style = build_style(&props.config);
+
+        let class = props.config.css_classes.join(" ");
+
+        html! {
+            <div style="display: flex; flex-direction: column; gap: 4px;">
+                if !label.is_empty() {
+                    <label style="font-weight: 500; font-size: 14px; color: var(--wysiwyg-text, #374151);">
+                        { label }
+                    </label>
+                }
+                <input
+                    type={date_mode(&props.config)}
+                    {min}
+                    {max}
+                    {class}
+                    {style}
+                />
+            </div>
+        }
+    }
+
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let label = config.get_str("label", "");
+
+        let mut attributes = vec![("type", yew_source::literal(date_mode(config)))];
+        for key in ["min", "max"] {
+            if let Some(bound) = date_bound(config, key) {
+                attributes.push((key, yew_source::literal(&bound)));
+            }
+        }
+        attributes.extend(yew_source::config_attributes(config, ""));
+        Some(labeled_field_source(
+            &label,
+            yew_source::element("input", &attributes, &[]),
+        ))
+    }
+
+    fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
+        let label = config.get_str("label", "");
+
+        let mode = date_mode(config);
+
+        let config_clone = config.clone();
+        let on_label_change = {
+            let on_change = on_change.clone();
+            Callback::from(move |e: InputEvent| {
+                if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                    let mut new_config = config_clone.clone();
+                    new_config.set_property("label", serde_json::json!(input.value()));
+                    on_change.emit(new_config);
+                }
+            })
+        };
+
+        let config_clone = config.clone();
+        let on_mode_change = {
+            let on_change = on_change.clone();
+            Callback::from(move |e: Event| {
+                if let Some(select) = e.target_dyn_into::<web_sys::HtmlSelectElement>() {
+                    let mut new_config = config_clone.clone();
+                    new_config.set_property("mode", serde_json::json!(select.value()));
+                    // Bounds are written in the old mode's format, so they no longer apply
+                    new_config.properties.remove("min");
+                    new_config.properties.remove("max");
+                    on_change.emit(new_config);
+                }
+            })
+        };
+
+        let bound_input = |key: &'static str, label: &'static str| {
+            let value = date_bound(config, key).unwrap_or_default();
+            let config_clone = config.clone();
+            let on_change = on_change.clone();
+            let onchange = Callback::from(move |e: Event| {
+                if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                    let mut new_config = config_clone.clone();
+                    let value = input.value();
+                    if value.is_empty() {
+                        new_config.properties.remove(key);
+                    } else {
+                        new_config.set_property(key, serde_json::json!(value));
+                    }
+                    on_change.emit(new_config);
+                }
+            });
+
+            html! {
+                <div style="margin-bottom: 12px;">
+                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                        { label }
+                    </label>
+                    <input
+                        type={mode}
+                        {value}
+                        {onchange}
+                        style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                    />
+                </div>
+            }
+        };
+
+        html! {
+            <div>
+                <div style="margin-bottom: 12px;">
+                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                        { "Label:" }
+                    </label>
+                    <input
+                        type="text"
+                        value={label}
+                        oninput={on_label_change}
+                        style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                    />
+                </div>
+                <div style="margin-bottom: 12px;">
+                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                        { "Mode:" }
+                    </label>
+                    <select
+                        value={mode}
+                        onchange={on_mode_change}
+                        style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                    >
+                        <option value="date" selected={mode == "date"}>{ "Date" }</option>
+                        <option value="datetime-local" selected={mode == "datetime-local"}>
+                            { "Date and Time" }
+                        </option>
+                        <option value="time" selected={mode == "time"}>{ "Time" }</option>
+                    </select>
+                </div>
+                { bound_input("min", "Earliest:") }
+                { bound_input("max", "Latest:") }
+            </div>
+        }
+    }
+}
+
+/// Number Input widget
+#[derive(Default)]
+pub struct NumberInput;
+
+impl NumberInput {
+    pub fn factory() -> SimpleWidgetFactory<Self> {
+        SimpleWidgetFactory::new()
+    }
+}
+
+impl Widget for NumberInput {
+    fn widget_type(&self) -> &'static str {
+        "form.number"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Number Input"
+    }
+
+    fn description(&self) -> &'static str {
+        "Number field or slider with bounds and step"
+    }
+
+    fn icon(&self) -> Html {
+        html! { <span>{ "🔢" }</span> }
+    }
+
+    fn default_config(&self) -> WidgetConfig {
+        WidgetConfig::new(self.widget_type())
+            .with_property("label", serde_json::json!(""))
+            .with_property("min", serde_json::json!(0))
+            .with_property("max", serde_json::json!(100))
+            .with_property("step", serde_json::json!(1))
+            .with_property("value", serde_json::json!(50))
+            .with_property("display", serde_json::json!("box"))
+    }
+
+    fn render(&self, props: &WidgetProps) -> Html {
+        let label = props.config.get_str("label", "");
+
+        let bounds = NumberBounds::from_config(&props.config);
+
+        let style = build_style(&props.config);
+
+        let class = props.config.css_classes.join(" ");
+
+        html! {
+            <div style="display: flex; flex-direction: column; gap: 4px;">
+                if !label.is_empty() {
+                    <label style="font-weight: 500; font-size: 14px; color: var(--wysiwyg-text, #374151);">
+                        { label }
+                    </label>
+                }
+                if is_slider(&props.config) {
+                    <RangeField {bounds} {class} {style} />
+                } else {
+                    <input
+                        type="number"
+                        value={bounds.value.to_string()}
+                        min={bounds.min.to_string()}
+                        max={bounds.max.to_string()}
+                        step={bounds.step.to_string()}
+                        {class}
+                        style={format!(
+                            "padding: 8px 12px; border: 1px solid var(--wysiwyg-border, #d1d5db); border-radius: 4px; font-size: 14px; {}",
+                            style
+                        )}
+                    />
+                }
+            </div>
+        }
+    }
+
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let label = config.get_str("label", "");
+        let bounds = NumberBounds::from_config(config);
+
+        // The exported slider shows its initial value; wire up state to make it live
+        let (input_type, base_style) = if is_slider(config) {
+            ("range", "")
+        } else {
+            (
+                "number",
+                "padding: 8px 12px; border: 1px solid var(--wysiwyg-border, #d1d5db); border-radius: 4px; font-size: 14px;",
+            )
+        };
+        let mut attributes = vec![
+            ("type", yew_source::literal(input_type)),
+            ("value", yew_source::literal(&bounds.value.to_string())),
+            ("min", yew_source::literal(&bounds.min.to_string())),
+            ("max", yew_source::literal(&bounds.max.to_string())),
+            ("step", yew_source::literal(&bounds.step.to_string())),
+        ];
+        attributes.extend(yew_source::config_attributes(config, base_style));
+        let input = yew_source::element("input", &attributes, &[]);
+
+        let control = if is_slider(config) {
+            let value = yew_source::element(
+                "span",
+                &[(
+                    "style",
+                    yew_source::literal(
+                        "min-width: 40px; font-size: 14px; color: var(--wysiwyg-text, #374151);",
+                    ),
+                )],
+                &[yew_source::text(&bounds.value.to_string())],
+            );
+            yew_source::element(
+                "div",
+                &[(
+                    "style",
+                    yew_source::literal("display: flex; align-items: center; gap: 8px;"),
+                )],
+                &[input, value],
+            )
+        } else {
+            input
+        };
+        Some(labeled_field_source(&label, control))
+    }
+
+    fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
+        let label = config.get_str("label", "");
+
+        let display = if is_slider(config) { "slider" } else { "box" };
+
+        let config_clone = config.clone();
+        let on_label_change = {
+            let on_change = on_change.clone();
+            Callback::from(move |e: InputEvent| {
+                if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                    let mut new_config = config_clone.clone();
+                    new_config.set_property("label", serde_json::json!(input.value()));
+                    on_change.emit(new_config);
+                }
+            })
+        };
+
+        let config_clone = config.clone();
+        let on_display_change = {
+            let on_change = on_change.clone();
+            Callback::from(move |e: Event| {
+                if let Some(select) = e.target_dyn_into::<web_sys::HtmlSelectElement>() {
+                    let mut new_config = config_clone.clone();
+                    new_config.set_property("display", serde_json::json!(select.value()));
+                    on_change.emit(new_config);
+                }
+            })
+        };
+
+        let bounds = NumberBounds::from_config(config);
+
+        html! {
+            <div>
+                <div style="margin-bottom: 12px;">
+                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                        { "Label:" }
+                    </label>
+                    <input
+                        type="text"
+                        value={label}
+                        oninput={on_label_change}
+                        style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                    />
+                </div>
+                <div style="margin-bottom: 12px;">
+                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                        { "Display:" }
+                    </label>
+                    <select
+                        onchange={on_display_change}
+                        style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                    >
+                        <option value="box" selected={display == "box"}>{ "Number Box" }</option>
+                        <option value="slider" selected={display == "slider"}>{ "Slider" }</option>
+                    </select>
+                </div>
+                { number_property(config, on_change.clone(), NumberProperty::new("min", "Minimum:", 0.0)) }
+                { number_property(config, on_change.clone(), NumberProperty::new("max", "Maximum:", 100.0)) }
+                // Steps of zero or less fall back to 1 when rendering
+                { number_property(config, on_change.clone(), NumberProperty::new("step", "Step:", 1.0)) }
+                {
+                    number_property(
+                        config,
+                        on_change,
+                        NumberProperty::new("value", "Initial Value:", bounds.value)
+                            .range(bounds.min, bounds.max)
+                            .step(bounds.step),
+                    )
+                }
+            </div>
+        }
+    }
+}
+
+/// Bounds, step, and initial value of a Number Input
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NumberBounds {
+    min: f64,
+    max: f64,
+    step: f64,
+    value: f64,
+}
+
+impl NumberBounds {
+    /// Read the bounds, swapping an inverted range and clamping the value into it
+    fn from_config(config: &WidgetConfig) -> Self {
+        let min = get_number(config, "min").unwrap_or(0.0);
+        let max = get_number(config, "max").unwrap_or(100.0);
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        let step = get_number(config, "step")
+            .filter(|step| *step > 0.0)
+            .unwrap_or(1.0);
+        let value = get_number(config, "value")
+            .unwrap_or((min + max) / 2.0)
+            .clamp(min, max);
+        Self {
+            min,
+            max,
+            step,
+            value,
+        }
+    }
+}
+
+/// Properties for the RangeField component
+#[derive(Properties, PartialEq)]
+struct RangeFieldProps {
+    bounds: NumberBounds,
+    class: String,
+    style: String,
+}
+
+/// Range slider with a label that follows the current value
+#[function_component(RangeField)]
+fn range_field(props: &RangeFieldProps) -> Html {
+    let current = use_state(|| props.bounds.value);
+
+    // Start over from the configured value when it changes in the editor
+    {
+        let current = current.clone();
+        use_effect_with(props.bounds.value, move |value| {
+            current.set(*value);
+            || ()
+        });
+    }
+
+    let oninput = {
+        let current = current.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<f64>() {
+                current.set(value);
+            }
+        })
+    };
+
+    html! {
+        <div style="display: flex; align-items: center; gap: 8px;">
+            <input
+                type="range"
+                value={current.to_string()}
+                min={props.bounds.min.to_string()}
+                max={props.bounds.max.to_string()}
+                step={props.bounds.step.to_string()}
+                {oninput}
+                class={props.class.clone()}
+                style={props.style.clone()}
+            />
+            <span style="min-width: 40px; font-size: 14px; color: var(--wysiwyg-text, #374151);">
+                { current.to_string() }
+            </span>
+        </div>
+    }
+}
+
 /// Spacer widget for layout control
 #[derive(Default)]
 pub struct Spacer;
@@ -1112,22 +1526,16 @@ impl Widget for Spacer {
 
     fn default_config(&self) -> WidgetConfig {
         WidgetConfig::new(self.widget_type())
+            .with_property("orientation", serde_json::json!("vertical"))
             .with_property("height", serde_json::json!(20))
+            .with_property("width", serde_json::json!(20))
             .with_style("width", "100%")
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let height = props
-            .config
-            .properties
-            .get("height")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(20);
-
-        let mut style = format!("height: {}px; ", height);
-        for (k, v) in &props.config.inline_styles {
-            style.push_str(&format!("{}: {}; ", k, v));
-        }
+        let mut style = spacer_base_style(&props.config);
+        style.push(' ');
+        style.push_str(&build_style(&props.config));
 
         let class = props.config.css_classes.join(" ");
 
@@ -1136,22 +1544,32 @@ impl Widget for Spacer {
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        Some(yew_source::element(
+            "div",
+            &yew_source::config_attributes(config, &spacer_base_style(config)),
+            &[],
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let height = config
-            .properties
-            .get("height")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(20);
+        let horizontal = is_horizontal_spacer(config);
 
-        let config_clone = config.clone();
-        let on_height_change = {
-            Callback::from(move |e: InputEvent| {
-                if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
-                    if let Ok(height) = input.value().parse::<u64>() {
-                        let mut new_config = config_clone.clone();
-                        new_config.set_property("height", serde_json::json!(height));
-                        on_change.emit(new_config);
+        let on_orientation_change = {
+            let config = config.clone();
+            let on_change = on_change.clone();
+            Callback::from(move |e: Event| {
+                if let Some(select) = e.target_dyn_into::<web_sys::HtmlSelectElement>() {
+                    let mut new_config = config.clone();
+                    let value = select.value();
+                    // A full-width spacer would fill the whole row instead of a fixed gap
+                    if value == "horizontal"
+                        && new_config.inline_styles.get("width").map(String::as_str) == Some("100%")
+                    {
+                        new_config.inline_styles.shift_remove("width");
                     }
+                    new_config.set_property("orientation", serde_json::json!(value));
+                    on_change.emit(new_config);
                 }
             })
         };
@@ -1160,18 +1578,115 @@ impl Widget for Spacer {
             <div>
                 <div style="margin-bottom: 12px;">
                     <label style="display: block; margin-bottom: 4px; font-weight: 500;">
-                        { "Height (px):" }
+                        { "Orientation:" }
                     </label>
-                    <input
-                        type="number"
-                        value={height.to_string()}
-                        oninput={on_height_change}
-                        min="0"
-                        max="500"
+                    <select
+                        onchange={on_orientation_change}
                         style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
-                    />
+                    >
+                        <option value="vertical" selected={!horizontal}>{ "Vertical" }</option>
+                        <option value="horizontal" selected={horizontal}>{ "Horizontal" }</option>
+                    </select>
                 </div>
+                if horizontal {
+                    {
+                        number_property(
+                            config,
+                            on_change,
+                            NumberProperty::new("width", "Width:", 20.0)
+                                .range(0.0, 500.0)
+                                .step(1.0)
+                                .unit("px"),
+                        )
+                    }
+                } else {
+                    {
+                        number_property(
+                            config,
+                            on_change,
+                            NumberProperty::new("height", "Height:", 20.0)
+                                .range(0.0, 500.0)
+                                .step(1.0)
+                                .unit("px"),
+                        )
+                    }
+                }
             </div>
         }
     }
 }
+
+// Helper functions
+
+/// Background for a button variant: a theme variable with the default palette as fallback
+fn button_background(variant: &str) -> &'static str {
+    match variant {
+        "secondary" => "var(--wysiwyg-secondary, #64748b)",
+        "success" => "var(--wysiwyg-success, #10b981)",
+        "danger" => "var(--wysiwyg-danger, #ef4444)",
+        _ => "var(--wysiwyg-primary, #3b82f6)",
+    }
+}
+
+/// Input type for a Date Picker's `mode`, falling back to `date` for unknown values
+fn date_mode(config: &WidgetConfig) -> &'static str {
+    match config.get_str("mode", "date").as_str() {
+        "datetime-local" => "datetime-local",
+        "time" => "time",
+        _ => "date",
+    }
+}
+
+/// A Date Picker's `min` or `max` bound, if set
+fn date_bound(config: &WidgetConfig, key: &str) -> Option<String> {
+    Some(config.get_str(key, "")).filter(|v| !v.is_empty())
+}
+
+/// Whether a Spacer adds horizontal space (e.g. between Row children) rather than vertical
+fn is_horizontal_spacer(config: &WidgetConfig) -> bool {
+    config.get_str("orientation", "vertical") == "horizontal"
+}
+
+/// Size styles of a Spacer: a fixed height, or a fixed width spanning the row's height
+fn spacer_base_style(config: &WidgetConfig) -> String {
+    let size = |key: &str| get_number(config, key).unwrap_or(20.0);
+    if is_horizontal_spacer(config) {
+        format!(
+            "width: {}px; flex-shrink: 0; align-self: stretch;",
+            size("width")
+        )
+    } else {
+        format!("height: {}px;", size("height"))
+    }
+}
+
+/// Whether a Number Input is shown as a slider rather than a number box
+fn is_slider(config: &WidgetConfig) -> bool {
+    config.get_str("display", "box") == "slider"
+}
+
+/// Source for a form control with an optional label above it
+fn labeled_field_source(label: &str, control: String) -> String {
+    let mut items = Vec::new();
+    if !label.is_empty() {
+        items.push(yew_source::element(
+            "label",
+            &[(
+                "style",
+                yew_source::literal(
+                    "font-weight: 500; font-size: 14px; color: var(--wysiwyg-text, #374151);",
+                ),
+            )],
+            &[yew_source::text(label)],
+        ));
+    }
+    items.push(control);
+    yew_source::element(
+        "div",
+        &[(
+            "style",
+            yew_source::literal("display: flex; flex-direction: column; gap: 4px;"),
+        )],
+        &items,
+    )
+}
@@ -2,7 +2,28 @@
 
 use yew::prelude::*;
 
-use crate::core::widget::{SimpleWidgetFactory, Widget, WidgetConfig, WidgetProps};
+use crate::core::style::build_style;
+use crate::core::widget::{
+    grid_template_areas_css, parse_grid_template_areas, SimpleWidgetFactory, Widget, WidgetConfig,
+    WidgetProps, GRID_TEMPLATE_AREAS,
+};
+use crate::core::yew_source;
+
+/// Card shadow presets: (key, label, box-shadow value)
+const CARD_SHADOWS: &[(&str, &str, Option<&str>)] = &[
+    ("none", "None", None),
+    ("sm", "Small", Some("0 1px 3px rgba(0,0,0,0.1)")),
+    (
+        "md",
+        "Medium",
+        Some("0 4px 6px -1px rgba(0,0,0,0.1), 0 2px 4px -2px rgba(0,0,0,0.1)"),
+    ),
+    (
+        "lg",
+        "Large (elevated)",
+        Some("0 10px 15px -3px rgba(0,0,0,0.1), 0 4px 6px -4px rgba(0,0,0,0.1)"),
+    ),
+];
 
 /// Row container - arranges children horizontally
 #[derive(Default)]
@@ -53,6 +74,14 @@ impl Widget for RowContainer {
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, children: &[String]) -> Option<String> {
+        Some(yew_source::element(
+            "div",
+            &yew_source::config_attributes(config, ""),
+            children,
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
         let gap = config
             .inline_styles
@@ -86,6 +115,7 @@ impl Widget for RowContainer {
                         max="100"
                     />
                 </label>
+                { render_container_layout_ui(config, on_change) }
             </div>
         }
     }
@@ -139,6 +169,14 @@ impl Widget for ColumnContainer {
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, children: &[String]) -> Option<String> {
+        Some(yew_source::element(
+            "div",
+            &yew_source::config_attributes(config, ""),
+            children,
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
         let gap = config
             .inline_styles
@@ -172,6 +210,7 @@ impl Widget for ColumnContainer {
                         max="100"
                     />
                 </label>
+                { render_container_layout_ui(config, on_change) }
             </div>
         }
     }
@@ -219,7 +258,8 @@ impl Widget for GridContainer {
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let style = build_style(&props.config);
+        let mut style = build_style(&props.config);
+        style.push_str(&grid_areas_style(&props.config));
         let class = build_class(&props.config);
 
         html! {
@@ -228,7 +268,34 @@ impl Widget for GridContainer {
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, children: &[String]) -> Option<String> {
+        Some(yew_source::element(
+            "div",
+            &yew_source::config_attributes(config, &grid_areas_style(config)),
+            children,
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
+        let areas = config.get_str(GRID_TEMPLATE_AREAS, "");
+        let areas_error = parse_grid_template_areas(&areas).err();
+
+        let config_clone = config.clone();
+        let on_areas_change = {
+            let on_change = on_change.clone();
+            Callback::from(move |e: InputEvent| {
+                let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                let mut new_config = config_clone.clone();
+                // Kept while invalid so it can be typed, but only applied once it parses
+                if input.value().trim().is_empty() {
+                    new_config.properties.remove(GRID_TEMPLATE_AREAS);
+                } else {
+                    new_config.set_property(GRID_TEMPLATE_AREAS, serde_json::json!(input.value()));
+                }
+                on_change.emit(new_config);
+            })
+        };
+
         let columns = config
             .inline_styles
             .get("grid-template-columns")
@@ -262,6 +329,24 @@ impl Widget for GridContainer {
                 <small style="color: #666;">
                     { "e.g., 'repeat(3, 1fr)' or '200px 1fr'" }
                 </small>
+                <label style="display: block; margin-top: 12px;">
+                    { "Grid Template Areas: " }
+                    <textarea
+                        value={areas}
+                        oninput={on_areas_change}
+                        rows="3"
+                        placeholder={"header header\nsidebar main"}
+                        style="width: 100%; font-family: monospace;"
+                    />
+                </label>
+                if let Some(error) = areas_error {
+                    <small style="color: #dc2626;">{ error.to_string() }</small>
+                } else {
+                    <small style="color: #666;">
+                        { "One row per line; use '.' for an empty cell" }
+                    </small>
+                }
+                { render_container_layout_ui(config, on_change) }
             </div>
         }
     }
@@ -301,20 +386,15 @@ impl Widget for Card {
     fn default_config(&self) -> WidgetConfig {
         WidgetConfig::new(self.widget_type())
             .with_property("title", serde_json::json!(""))
-            .with_style("border", "1px solid #e5e7eb")
+            .with_style("border", "1px solid var(--wysiwyg-border, #e5e7eb)")
             .with_style("border-radius", "8px")
             .with_style("padding", "16px")
-            .with_style("background", "#ffffff")
+            .with_style("background", "var(--wysiwyg-background, #ffffff)")
             .with_style("box-shadow", "0 1px 3px rgba(0,0,0,0.1)")
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let title = props
-            .config
-            .properties
-            .get("title")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let title = props.config.get_str("title", "");
 
         let style = build_style(&props.config);
         let class = build_class(&props.config);
@@ -322,7 +402,7 @@ impl Widget for Card {
         html! {
             <div {class} {style}>
                 if !title.is_empty() {
-                    <div style="font-size: 18px; font-weight: 600; margin-bottom: 12px; color: #111827;">
+                    <div style="font-size: 18px; font-weight: 600; margin-bottom: 12px; color: var(--wysiwyg-text, #111827);">
                         { title }
                     </div>
                 }
@@ -330,15 +410,59 @@ impl Widget for Card {
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, children: &[String]) -> Option<String> {
+        let title = config.get_str("title", "");
+
+        let mut items = Vec::new();
+        if !title.is_empty() {
+            items.push(yew_source::element(
+                "div",
+                &[(
+                    "style",
+                    yew_source::literal(
+                        "font-size: 18px; font-weight: 600; margin-bottom: 12px; color: var(--wysiwyg-text, #111827);",
+                    ),
+                )],
+                &[yew_source::text(&title)],
+            ));
+        }
+        items.extend_from_slice(children);
+        Some(yew_source::element(
+            "div",
+            &yew_source::config_attributes(config, ""),
+            &items,
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let title = config
-            .properties
-            .get("title")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
+        let title = config.get_str("title", "");
+
+        // Match the current box-shadow against the presets
+        let shadow = match config.inline_styles.get("box-shadow") {
+            None => "none",
+            Some(value) => CARD_SHADOWS
+                .iter()
+                .find(|(_, _, css)| *css == Some(value.as_str()))
+                .map(|(key, _, _)| *key)
+                .unwrap_or("custom"),
+        };
+
+        let background = config
+            .inline_styles
+            .get("background")
+            .filter(|v| v.len() == 7 && v.starts_with('#'))
+            .cloned()
+            .unwrap_or_else(|| "#ffffff".to_string());
+
+        let opacity = config
+            .inline_styles
+            .get("opacity")
+            .and_then(|v| v.parse::<f64>().ok())
+            .map(|v| (v * 100.0).round() as u32)
+            .unwrap_or(100);
 
         let config_clone = config.clone();
+        let on_change_clone = on_change.clone();
         let on_title_change = {
             Callback::from(move |e: InputEvent| {
                 let input: web_sys::HtmlInputElement = e.target_unchecked_into();
@@ -346,10 +470,61 @@ impl Widget for Card {
                 new_config
                     .properties
                     .insert("title".to_string(), serde_json::json!(input.value()));
-                on_change.emit(new_config);
+                on_change_clone.emit(new_config);
             })
         };
 
+        let config_clone = config.clone();
+        let on_change_clone = on_change.clone();
+        let on_shadow_change = Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let Some((_, _, css)) = CARD_SHADOWS
+                .iter()
+                .find(|(key, _, _)| *key == select.value())
+            else {
+                return;
+            };
+            let mut new_config = config_clone.clone();
+            match css {
+                Some(css) => {
+                    new_config
+                        .inline_styles
+                        .insert("box-shadow".to_string(), css.to_string());
+                }
+                None => {
+                    new_config.inline_styles.shift_remove("box-shadow");
+                }
+            }
+            on_change_clone.emit(new_config);
+        });
+
+        let config_clone = config.clone();
+        let on_change_clone = on_change.clone();
+        let on_background_change = Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut new_config = config_clone.clone();
+            new_config
+                .inline_styles
+                .insert("background".to_string(), input.value());
+            on_change_clone.emit(new_config);
+        });
+
+        let config_clone = config.clone();
+        let on_opacity_change = Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(percent) = input.value().parse::<u32>() {
+                let mut new_config = config_clone.clone();
+                if percent >= 100 {
+                    new_config.inline_styles.shift_remove("opacity");
+                } else {
+                    new_config
+                        .inline_styles
+                        .insert("opacity".to_string(), format!("{}", percent as f64 / 100.0));
+                }
+                on_change.emit(new_config);
+            }
+        });
+
         html! {
             <div>
                 <div style="margin-bottom: 12px;">
@@ -364,6 +539,52 @@ impl Widget for Card {
                         placeholder="Leave empty for no title"
                     />
                 </div>
+
+                <div style="margin-bottom: 12px;">
+                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                        { "Shadow:" }
+                    </label>
+                    <select
+                        onchange={on_shadow_change}
+                        style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                    >
+                        {
+                            for CARD_SHADOWS.iter().map(|(key, label, _)| html! {
+                                <option value={*key} selected={shadow == *key}>{ *label }</option>
+                            })
+                        }
+                        if shadow == "custom" {
+                            <option value="custom" selected=true disabled=true>{ "Custom" }</option>
+                        }
+                    </select>
+                </div>
+
+                <div style="margin-bottom: 12px;">
+                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                        { "Background:" }
+                    </label>
+                    <input
+                        type="color"
+                        value={background}
+                        oninput={on_background_change}
+                        style="width: 100%; height: 32px; padding: 0; border: 1px solid #ddd; border-radius: 4px;"
+                    />
+                </div>
+
+                <div style="margin-bottom: 12px;">
+                    <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                        { format!("Opacity: {}%", opacity) }
+                    </label>
+                    <input
+                        type="range"
+                        min="0"
+                        max="100"
+                        step="5"
+                        value={opacity.to_string()}
+                        oninput={on_opacity_change}
+                        style="width: 100%;"
+                    />
+                </div>
             </div>
         }
     }
@@ -371,13 +592,213 @@ impl Widget for Card {
 
 // Helper functions
 
-fn build_style(config: &WidgetConfig) -> String {
-    config
+/// Units offered for a container's max width
+const MAX_WIDTH_UNITS: &[&str] = &["px", "%", "rem", "ch"];
+
+/// Values offered for `align-items`
+const ALIGN_ITEMS: &[(&str, &str)] = &[
+    ("", "Default"),
+    ("stretch", "Stretch"),
+    ("flex-start", "Start"),
+    ("center", "Center"),
+    ("flex-end", "End"),
+    ("baseline", "Baseline"),
+];
+
+/// Values offered for `justify-content`
+const JUSTIFY_CONTENT: &[(&str, &str)] = &[
+    ("", "Default"),
+    ("flex-start", "Start"),
+    ("center", "Center"),
+    ("flex-end", "End"),
+    ("space-between", "Space between"),
+    ("space-around", "Space around"),
+    ("space-evenly", "Space evenly"),
+];
+
+/// Split a CSS length like `960px` into its number and one of `MAX_WIDTH_UNITS`
+fn split_length(value: &str) -> Option<(f64, &'static str)> {
+    MAX_WIDTH_UNITS.iter().find_map(|unit| {
+        value
+            .strip_suffix(unit)
+            .and_then(|number| number.trim().parse().ok())
+            .map(|number| (number, *unit))
+    })
+}
+
+/// Properties for the MaxWidthField component
+#[derive(Properties, PartialEq)]
+struct MaxWidthFieldProps {
+    config: WidgetConfig,
+    on_change: Callback<WidgetConfig>,
+}
+
+/// Max width input with a unit picker; the unit is remembered while the width is empty
+#[function_component(MaxWidthField)]
+fn max_width_field(props: &MaxWidthFieldProps) -> Html {
+    let stored = props
+        .config
         .inline_styles
-        .iter()
-        .map(|(k, v)| format!("{}: {};", k, v))
-        .collect::<Vec<_>>()
-        .join(" ")
+        .get("max-width")
+        .and_then(|v| split_length(v));
+    let max_width = stored
+        .map(|(number, _)| number.to_string())
+        .unwrap_or_default();
+    let unit = use_state(|| stored.map_or("px", |(_, unit)| unit));
+
+    // Follow the stored unit when the config changes elsewhere (undo, another widget)
+    {
+        let unit = unit.clone();
+        use_effect_with(stored.map(|(_, unit)| unit), move |stored_unit| {
+            if let Some(stored_unit) = stored_unit {
+                unit.set(stored_unit);
+            }
+            || ()
+        });
+    }
+
+    let on_max_width_change = {
+        let config = props.config.clone();
+        let on_change = props.on_change.clone();
+        let unit = *unit;
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let value = input.value();
+            let value = value.trim();
+            let mut new_config = config.clone();
+            new_config.set_style(
+                None,
+                "max-width",
+                (!value.is_empty()).then(|| format!("{}{}", value, unit)),
+            );
+            on_change.emit(new_config);
+        })
+    };
+
+    let on_unit_change = {
+        let config = props.config.clone();
+        let on_change = props.on_change.clone();
+        let unit = unit.clone();
+        let max_width = max_width.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let Some(selected) = MAX_WIDTH_UNITS.iter().find(|u| **u == select.value()) else {
+                return;
+            };
+            unit.set(selected);
+            if !max_width.is_empty() {
+                let mut new_config = config.clone();
+                new_config.set_style(
+                    None,
+                    "max-width",
+                    Some(format!("{}{}", max_width, selected)),
+                );
+                on_change.emit(new_config);
+            }
+        })
+    };
+
+    html! {
+        <div style="margin-top: 12px;">
+            <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                { "Max Width:" }
+            </label>
+            <div style="display: flex; gap: 4px;">
+                <input
+                    type="number"
+                    min="0"
+                    value={max_width}
+                    oninput={on_max_width_change}
+                    placeholder="None"
+                    style="flex: 1; min-width: 0; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                />
+                <select
+                    onchange={on_unit_change}
+                    style="padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                >
+                    {
+                        for MAX_WIDTH_UNITS.iter().map(|u| html! {
+                            <option value={*u} selected={*unit == *u}>{ *u }</option>
+                        })
+                    }
+                </select>
+            </div>
+        </div>
+    }
+}
+
+/// Max width, centering, and flex alignment controls shared by the layout containers
+fn render_container_layout_ui(config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
+    let centered = config.inline_styles.get("margin-left").map(String::as_str) == Some("auto")
+        && config.inline_styles.get("margin-right").map(String::as_str) == Some("auto");
+
+    let on_center_change = {
+        let config = config.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let value = input.checked().then(|| "auto".to_string());
+            let mut new_config = config.clone();
+            new_config.set_style(None, "margin-left", value.clone());
+            new_config.set_style(None, "margin-right", value);
+            on_change.emit(new_config);
+        })
+    };
+
+    let select_for = |key: &'static str,
+                      label: &'static str,
+                      options: &'static [(&'static str, &'static str)]| {
+        let current = config.inline_styles.get(key).cloned().unwrap_or_default();
+        let config = config.clone();
+        let on_change = on_change.clone();
+        let onchange = Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let value = select.value();
+            let mut new_config = config.clone();
+            new_config.set_style(None, key, (!value.is_empty()).then_some(value));
+            on_change.emit(new_config);
+        });
+
+        html! {
+            <div style="margin-top: 12px;">
+                <label style="display: block; margin-bottom: 4px; font-weight: 500;">
+                    { label }
+                </label>
+                <select
+                    {onchange}
+                    style="width: 100%; padding: 6px; border: 1px solid #ddd; border-radius: 4px;"
+                >
+                    {
+                        for options.iter().map(|(value, text)| html! {
+                            <option value={*value} selected={current == *value}>{ *text }</option>
+                        })
+                    }
+                </select>
+            </div>
+        }
+    };
+
+    html! {
+        <>
+            <MaxWidthField config={config.clone()} on_change={on_change.clone()} />
+            <label style="display: flex; align-items: center; gap: 8px; margin-top: 8px;">
+                <input type="checkbox" checked={centered} onchange={on_center_change} />
+                { "Center horizontally" }
+            </label>
+            { select_for("align-items", "Align Items:", ALIGN_ITEMS) }
+            { select_for("justify-content", "Justify Content:", JUSTIFY_CONTENT) }
+        </>
+    }
+}
+
+/// Build the `grid-template-areas` declaration from a Grid's areas, if they parse
+fn grid_areas_style(config: &WidgetConfig) -> String {
+    match parse_grid_template_areas(&config.get_str(GRID_TEMPLATE_AREAS, "")) {
+        Ok(rows) if !rows.is_empty() => {
+            format!(" grid-template-areas: {};", grid_template_areas_css(&rows))
+        }
+        _ => String::new(),
+    }
 }
 
 fn build_class(config: &WidgetConfig) -> String {
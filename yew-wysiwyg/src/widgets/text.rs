@@ -1,17 +1,13 @@
 //! Text-based widgets
 
-use pulldown_cmark::{html, Parser};
 use yew::prelude::*;
 
+use crate::core::config_ui::RichTextField;
+use crate::core::markdown::render_markdown;
+use crate::core::rich_text::{plain_text_to_rich, rich_text_to_plain, sanitize_rich_text};
+use crate::core::style::build_style;
 use crate::core::widget::{SimpleWidgetFactory, Widget, WidgetConfig, WidgetProps};
-
-/// Convert markdown to HTML
-fn markdown_to_html(markdown: &str) -> String {
-    let parser = Parser::new(markdown);
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
-    html_output
-}
+use crate::core::yew_source;
 
 /// Generic text widget with rich text support
 #[derive(Default)]
@@ -46,32 +42,17 @@ impl Widget for TextWidget {
             .with_property("bold", serde_json::json!(false))
             .with_property("italic", serde_json::json!(false))
             .with_property("underline", serde_json::json!(false))
+            .with_property("markdown", serde_json::json!(false))
+            .with_property("rich_text", serde_json::json!(false))
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let content = props
-            .config
-            .get_property("content")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Enter text here...");
-
-        let bold = props
-            .config
-            .get_property("bold")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
-
-        let italic = props
-            .config
-            .get_property("italic")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
-
-        let underline = props
-            .config
-            .get_property("underline")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let content = props.config.get_str("content", "Enter text here...");
+        let bold = props.config.get_bool("bold", false);
+        let italic = props.config.get_bool("italic", false);
+        let underline = props.config.get_bool("underline", false);
+        let markdown = props.config.get_bool("markdown", false);
+        let rich_text = props.config.get_bool("rich_text", false);
 
         let mut style = build_style(&props.config);
         if bold {
@@ -86,40 +67,136 @@ impl Widget for TextWidget {
 
         let class = build_class(&props.config);
 
-        html! {
-            <span {class} {style}>{ content }</span>
+        if markdown {
+            // The outer span carries the bold/italic/underline styles
+            style.push_str("display: inline-block;");
+            let inner_html = Html::from_html_unchecked(render_markdown(&content).into());
+            html! {
+                <span {class} {style}>{ inner_html }</span>
+            }
+        } else if rich_text {
+            // Content is sanitized again here since layouts can be edited outside the editor
+            let inner_html = Html::from_html_unchecked(sanitize_rich_text(&content).into());
+            html! {
+                <span {class} {style}>{ inner_html }</span>
+            }
+        } else {
+            html! {
+                <span {class} {style}>{ content }</span>
+            }
         }
     }
 
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let content = config.get_str("content", "Enter text here...");
+        let flag = |key: &str| config.get_bool(key, false);
+
+        // Formatting flags override matching inline styles, as in `render`
+        let mut config = config.clone();
+        if flag("bold") {
+            config
+                .inline_styles
+                .insert("font-weight".to_string(), "bold".to_string());
+        }
+        if flag("italic") {
+            config
+                .inline_styles
+                .insert("font-style".to_string(), "italic".to_string());
+        }
+        if flag("underline") {
+            config
+                .inline_styles
+                .insert("text-decoration".to_string(), "underline".to_string());
+        }
+
+        let content = if flag("markdown") {
+            config
+                .inline_styles
+                .insert("display".to_string(), "inline-block".to_string());
+            yew_source::raw_html(&render_markdown(&content))
+        } else if flag("rich_text") {
+            yew_source::raw_html(&sanitize_rich_text(&content))
+        } else {
+            yew_source::text(&content)
+        };
+        Some(yew_source::element(
+            "span",
+            &yew_source::config_attributes(&config, ""),
+            &[content],
+        ))
+    }
+
     fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let content = config
-            .get_property("content")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-
-        let bold = config
-            .get_property("bold")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
-
-        let italic = config
-            .get_property("italic")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
-
-        let underline = config
-            .get_property("underline")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let content = config.get_str("content", "");
+        let bold = config.get_bool("bold", false);
+        let italic = config.get_bool("italic", false);
+        let underline = config.get_bool("underline", false);
+        let markdown = config.get_bool("markdown", false);
+        let rich_text = config.get_bool("rich_text", false);
+
+        let config_clone = config.clone();
+        let on_rich_content_change = {
+            let on_change = on_change.clone();
+            Callback::from(move |value: String| {
+                let mut new_config = config_clone.clone();
+                new_config.set_property("content", serde_json::json!(value));
+                on_change.emit(new_config);
+            })
+        };
 
         let config_clone = config.clone();
         let on_content_change = {
             let on_change = on_change.clone();
             Callback::from(move |e: InputEvent| {
+                // Either a single-line input or a textarea, depending on the markdown toggle
+                let Some(value) = e
+                    .target_dyn_into::<web_sys::HtmlInputElement>()
+                    .map(|input| input.value())
+                    .or_else(|| {
+                        e.target_dyn_into::<web_sys::HtmlTextAreaElement>()
+                            .map(|textarea| textarea.value())
+                    })
+                else {
+                    return;
+                };
+                let mut new_config = config_clone.clone();
+                new_config.set_property("content", serde_json::json!(value));
+                on_change.emit(new_config);
+            })
+        };
+
+        let config_clone = config.clone();
+        let on_markdown_change = {
+            let on_change = on_change.clone();
+            Callback::from(move |e: Event| {
                 let input: web_sys::HtmlInputElement = e.target_unchecked_into();
                 let mut new_config = config_clone.clone();
-                new_config.set_property("content", serde_json::json!(input.value()));
+                if input.checked() && new_config.get_bool("rich_text", false) {
+                    let plain = rich_text_to_plain(&new_config.get_str("content", ""));
+                    new_config.set_property("content", serde_json::json!(plain));
+                    new_config.set_property("rich_text", serde_json::json!(false));
+                }
+                new_config.set_property("markdown", serde_json::json!(input.checked()));
+                on_change.emit(new_config);
+            })
+        };
+
+        let config_clone = config.clone();
+        let on_rich_text_change = {
+            let on_change = on_change.clone();
+            Callback::from(move |e: Event| {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let mut new_config = config_clone.clone();
+                let content = new_config.get_str("content", "");
+                // Convert the content so it reads the same in the new mode
+                let content = if input.checked() {
+                    new_config.set_property("markdown", serde_json::json!(false));
+                    plain_text_to_rich(&content)
+                } else {
+                    rich_text_to_plain(&content)
+                };
+                new_config.set_property("content", serde_json::json!(content));
+                new_config.set_property("rich_text", serde_json::json!(input.checked()));
                 on_change.emit(new_config);
             })
         };
@@ -159,14 +236,39 @@ impl Widget for TextWidget {
         html! {
             <div style="display: flex; flex-direction: column; gap: 8px;">
                 <label>
-                    { "Content: " }
-                    <input
-                        type="text"
-                        value={content}
-                        oninput={on_content_change}
-                        style="width: 100%;"
-                    />
+                    <input type="checkbox" checked={markdown} onchange={on_markdown_change} />
+                    { " Enable Markdown" }
+                </label>
+                <label>
+                    <input type="checkbox" checked={rich_text} onchange={on_rich_text_change} />
+                    { " Rich Text" }
                 </label>
+                if rich_text {
+                    // Not wrapped in a label, which would forward clicks to the format buttons
+                    <div>
+                        { "Content: " }
+                        <RichTextField value={content.clone()} on_change={on_rich_content_change} />
+                    </div>
+                } else {
+                    <label>
+                        { "Content: " }
+                        if markdown {
+                            <textarea
+                                value={content}
+                                oninput={on_content_change}
+                                style="width: 100%; min-height: 80px; font-family: monospace;"
+                                rows="4"
+                            />
+                        } else {
+                            <input
+                                type="text"
+                                value={content}
+                                oninput={on_content_change}
+                                style="width: 100%;"
+                            />
+                        }
+                    </label>
+                }
                 <div style="display: flex; gap: 12px;">
                     <label>
                         <input type="checkbox" checked={bold} onchange={on_bold_change} />
@@ -220,18 +322,8 @@ impl Widget for HeadingWidget {
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let content = props
-            .config
-            .get_property("content")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Heading");
-
-        let level = props
-            .config
-            .get_property("level")
-            .and_then(|v| v.as_i64())
-            .unwrap_or(1)
-            .clamp(1, 6);
+        let content = props.config.get_str("content", "Heading");
+        let level = props.config.get_i64("level", 1).clamp(1, 6);
 
         let style = build_style(&props.config);
         let class = build_class(&props.config);
@@ -246,17 +338,20 @@ impl Widget for HeadingWidget {
         }
     }
 
-    fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let content = config
-            .get_property("content")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let content = config.get_str("content", "Heading");
+        let level = config.get_i64("level", 1).clamp(1, 6);
 
-        let level = config
-            .get_property("level")
-            .and_then(|v| v.as_i64())
-            .unwrap_or(1);
+        Some(yew_source::element(
+            &format!("h{}", level),
+            &yew_source::config_attributes(config, ""),
+            &[yew_source::text(&content)],
+        ))
+    }
+
+    fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
+        let content = config.get_str("content", "");
+        let level = config.get_i64("level", 1);
 
         let config_clone = config.clone();
         let on_content_change = {
@@ -347,23 +442,14 @@ impl Widget for ParagraphWidget {
     }
 
     fn render(&self, props: &WidgetProps) -> Html {
-        let content = props
-            .config
-            .get_property("content")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Paragraph text");
-
-        let markdown = props
-            .config
-            .get_property("markdown")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let content = props.config.get_str("content", "Paragraph text");
+        let markdown = props.config.get_bool("markdown", false);
 
         let style = build_style(&props.config);
         let class = build_class(&props.config);
 
         if markdown {
-            let html_content = markdown_to_html(content);
+            let html_content = render_markdown(&content);
             // Use Html::from_html_unchecked to render the markdown HTML
             let inner_html = Html::from_html_unchecked(html_content.into());
             html! {
@@ -376,17 +462,25 @@ impl Widget for ParagraphWidget {
         }
     }
 
-    fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
-        let content = config
-            .get_property("content")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
+    fn to_yew_source(&self, config: &WidgetConfig, _children: &[String]) -> Option<String> {
+        let content = config.get_str("content", "Paragraph text");
+        let markdown = config.get_bool("markdown", false);
 
-        let markdown = config
-            .get_property("markdown")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let attributes = yew_source::config_attributes(config, "");
+        Some(if markdown {
+            yew_source::element(
+                "div",
+                &attributes,
+                &[yew_source::raw_html(&render_markdown(&content))],
+            )
+        } else {
+            yew_source::element("p", &attributes, &[yew_source::text(&content)])
+        })
+    }
+
+    fn render_config_ui(&self, config: &WidgetConfig, on_change: Callback<WidgetConfig>) -> Html {
+        let content = config.get_str("content", "");
+        let markdown = config.get_bool("markdown", false);
 
         let config_clone = config.clone();
         let on_content_change = {
@@ -450,15 +544,6 @@ impl Widget for ParagraphWidget {
 
 // Helper functions
 
-fn build_style(config: &WidgetConfig) -> String {
-    config
-        .inline_styles
-        .iter()
-        .map(|(k, v)| format!("{}: {};", k, v))
-        .collect::<Vec<_>>()
-        .join(" ")
-}
-
 fn build_class(config: &WidgetConfig) -> String {
     config.css_classes.join(" ")
 }